@@ -38,27 +38,72 @@ impl<K> Meter<K, (PathBuf, u64)> for FileSize {
     }
 }
 
+/// The name of the file that marks a directory in the cache as a single entry. Its
+/// last-modified time is used as the last-modified time of the directory.
+pub const DIR_MARKER: &'static str = ".lru-dir";
+
 /// Return an iterator of `(path, size)` of files under `path` sorted by ascending last-modified
-/// time, such that the oldest modified file is returned first.
+/// time, such that the oldest modified file is returned first. Marked directories are returned
+/// as a whole, with the total size of their files.
 fn get_all_files<P: AsRef<Path>>(path: P) -> Box<Iterator<Item=(PathBuf, u64)>> {
-    let mut files: Vec<_> = WalkDir::new(path.as_ref())
-        .into_iter()
-        .filter_map(|e| e.ok()
-                    .and_then(|f| {
-                        // Only look at files
-                        if f.file_type().is_file() {
-                            // Get the last-modified time, size, and the full path.
-                            f.metadata().ok().and_then(|m| m.modified().ok().map(|mtime| (mtime, f.path().to_owned(), m.len())))
-                        } else {
-                            None
-                        }
-                    }))
-        .collect();
+    let mut files = vec!();
+    let mut walker = WalkDir::new(path.as_ref()).into_iter();
+    while let Some(entry) = walker.next() {
+        let f = match entry {
+            Ok(f) => f,
+            Err(_) => continue,
+        };
+        if f.file_type().is_file() {
+            // Get the last-modified time, size, and the full path.
+            if let Some(file) = f.metadata().ok().and_then(|m| m.modified().ok().map(|mtime| (mtime, f.path().to_owned(), m.len()))) {
+                files.push(file);
+            }
+        } else if f.file_type().is_dir() {
+            if let Ok(m) = fs::metadata(f.path().join(DIR_MARKER)) {
+                // Don't look at the files in the directory individually.
+                walker.skip_current_dir();
+                if let (Ok(mtime), Ok(size)) = (m.modified(), dir_size(f.path())) {
+                    files.push((mtime, f.path().to_owned(), size));
+                }
+            }
+        }
+    }
     // Sort by last-modified-time, so oldest file first.
     files.sort_by_key(|k| k.0);
     Box::new(files.into_iter().map(|(_mtime, path, size)| (path, size)))
 }
 
+/// Return the total size of the files under `path`.
+fn dir_size(path: &Path) -> io::Result<u64> {
+    let mut size = 0;
+    for entry in WalkDir::new(path) {
+        let entry = try!(entry.map_err(|e| io::Error::new(io::ErrorKind::Other, e)));
+        if entry.file_type().is_file() {
+            size += try!(entry.metadata().map_err(|e| io::Error::new(io::ErrorKind::Other, e))).len();
+        }
+    }
+    Ok(size)
+}
+
+/// Remove the file or directory at `path`.
+fn remove_entry(path: &Path) -> io::Result<()> {
+    if try!(fs::symlink_metadata(path)).is_dir() {
+        fs::remove_dir_all(path)
+    } else {
+        fs::remove_file(path)
+    }
+}
+
+/// Copy the files in the directory `from` to a new directory `to`.
+fn copy_dir(from: &Path, to: &Path) -> io::Result<()> {
+    try!(fs::create_dir(to));
+    for entry in try!(fs::read_dir(from)) {
+        let entry = try!(entry);
+        try!(fs::copy(entry.path(), to.join(entry.file_name())));
+    }
+    Ok(())
+}
+
 /// An LRU cache of files on disk.
 pub struct LruDiskCache<S: BuildHasher = RandomState> {
     lru: LruCache<OsString, (PathBuf, u64), S, FileSize>,
@@ -150,7 +195,7 @@ impl LruDiskCache {
         try!(fs::create_dir_all(&self.root));
         for (file, size) in get_all_files(&self.root) {
             if !self.can_store(size) {
-                fs::remove_file(file).unwrap_or_else(|e| error!("Error removing file `{}` which is too large for the cache ({} bytes)", e, size));
+                remove_entry(&file).unwrap_or_else(|e| error!("Error removing file `{}` which is too large for the cache ({} bytes)", e, size));
             } else {
                 self.add_file(file, None, size)
                     .unwrap_or_else(|e| error!("Error adding file: {}", e));
@@ -179,7 +224,7 @@ impl LruDiskCache {
             let (_, (remove_path, _)) = self.lru.remove_lru().expect("Unexpectedly empty cache!");
             //TODO: check that files are removable during `init`, so that this is only
             // due to outside interference.
-            fs::remove_file(&remove_path).unwrap_or_else(|e| panic!("Error removing file from cache: `{:?}`: {}", remove_path, e));
+            remove_entry(&remove_path).unwrap_or_else(|e| panic!("Error removing file from cache: `{:?}`: {}", remove_path, e));
         }
         self.lru.insert(rel_path, (path, size));
         Ok(())
@@ -196,7 +241,7 @@ impl LruDiskCache {
         self.add_file(path, Some(rel_path), size)
             .or_else(|e| {
                 error!("Failed to insert file `{}`: {}", rel_path.to_string_lossy(), e);
-                fs::remove_file(&self.root.join(rel_path)).expect("Failed to remove file we just created!");
+                remove_entry(&self.root.join(rel_path)).expect("Failed to remove file we just created!");
                 Err(e)
             })
     }
@@ -225,22 +270,57 @@ impl LruDiskCache {
         })
     }
 
+    /// Add an existing directory at `path` to the cache at path `key`, as a single entry.
+    ///
+    /// The files in the directory are counted towards the size of the cache together, and
+    /// removed together. They shouldn't be changed while they're in the cache.
+    pub fn insert_dir<K: AsRef<OsStr>, P: AsRef<Path>>(&mut self, key: K, path: P) -> Result<()> {
+        let path = path.as_ref();
+        try!(File::create(path.join(DIR_MARKER)));
+        let size = try!(dir_size(path));
+        // A directory can't be renamed over another one, so remove any old entry first.
+        if let Some((old_path, _)) = self.lru.remove(key.as_ref()) {
+            try!(remove_entry(&old_path));
+        }
+        self.insert_by(key, size, |new_path| {
+            fs::rename(path, new_path)
+                .or_else(|_| {
+                    warn!("fs::rename failed, falling back to copy!");
+                    try!(copy_dir(path, new_path));
+                    fs::remove_dir_all(path)
+                        .unwrap_or_else(|e| error!("Failed to remove original directory in insert_dir: {}", e));
+                    Ok(())
+                })
+        })
+    }
+
     /// Return `true` if a file with path `key` is in the cache.
     pub fn contains_key<K: AsRef<OsStr>>(&self, key: K) -> bool {
         self.lru.contains_key(key.as_ref())
     }
 
-    /// Get an opened readable and seekable handle to the file at `key`, if one exists and can
-    /// be opened. Updates the LRU state of the file if present.
-    pub fn get<K: AsRef<OsStr>>(&mut self, key: K) -> Result<Box<ReadSeek>> {
+    /// Get the path of the file or directory at `key`, if one exists. Updates the LRU state of
+    /// the entry if present.
+    pub fn get_path<K: AsRef<OsStr>>(&mut self, key: K) -> Result<PathBuf> {
         self.lru.get(key.as_ref())
             .ok_or(Error::FileNotInCache)
             .and_then(|&(ref path, _)| {
                 let t = filetime_now();
-                try!(set_file_times(path, t, t));
-                Ok(Box::new(try!(File::open(path))) as Box<ReadSeek>)
+                if path.is_dir() {
+                    try!(set_file_times(path.join(DIR_MARKER), t, t));
+                } else {
+                    try!(set_file_times(path, t, t));
+                }
+                Ok(path.clone())
             })
     }
+
+    /// Get an opened readable and seekable handle to the file at `key`, if one exists and can
+    /// be opened. Updates the LRU state of the file if present.
+    pub fn get<K: AsRef<OsStr>>(&mut self, key: K) -> Result<Box<ReadSeek>> {
+        let path = try!(self.get_path(key));
+        Ok(Box::new(try!(File::open(path))) as Box<ReadSeek>)
+    }
 }
 
 #[cfg(test)]
@@ -440,4 +520,29 @@ mod tests {
         assert!(!p2.exists());
         assert!(!p3.exists());
     }
+
+    #[test]
+    fn test_insert_dir() {
+        let f = TestFixture::new();
+        f.create_file("dir1/a", 10);
+        f.create_file("dir1/b", 5);
+        f.create_file("dir2/a", 10);
+        {
+            let mut c = LruDiskCache::new(f.tmp().join("cache"), 20).unwrap();
+            c.insert_dir("x/dir1", f.tmp().join("dir1")).unwrap();
+            assert_eq!(c.size(), 15);
+            assert!(!f.tmp().join("dir1").exists());
+            let path = c.get_path("x/dir1").unwrap();
+            assert_eq!(read_all(&mut File::open(path.join("b")).unwrap()).unwrap(), vec![0u8; 5]);
+        }
+        // Directories are read back as single entries.
+        let mut c = LruDiskCache::new(f.tmp().join("cache"), 20).unwrap();
+        assert!(c.contains_key("x/dir1"));
+        assert_eq!(c.size(), 15);
+        // Adding this directory should remove the whole of the other one.
+        c.insert_dir("x/dir2", f.tmp().join("dir2")).unwrap();
+        assert_eq!(c.size(), 10);
+        assert!(!c.contains_key("x/dir1"));
+        assert!(!f.tmp().join("cache/x/dir1").exists());
+    }
 }
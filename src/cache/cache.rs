@@ -14,42 +14,46 @@
 
 use app_dirs::{
     AppDataType,
-    AppInfo,
     app_dir,
 };
 use cache::disk::DiskCache;
+use cache::format::{ArchiveReader, ArchiveWriter, Codec, is_archive};
+use cache::http::{HttpAuth, HttpCache};
+use cache::memcached::MemcachedCache;
+use cache::mode::ModeCache;
+use cache::redis::RedisCache;
 use cache::s3::S3Cache;
+use cache::tiered::TieredCache;
 use compiler::Compiler;
+use config::{APP_INFO, Config};
+use filetime::{FileTime, set_file_times};
 use futures_cpupool::CpuPool;
+#[cfg(target_os = "linux")]
+use libc;
+use lru_disk_cache::DIR_MARKER;
 use regex::Regex;
 use sha1;
+use std::collections::BTreeMap;
 use std::env;
 use std::fmt;
+use std::fs::{self, File};
 use std::io::{
     self,
     Read,
     Seek,
+    SeekFrom,
     Write,
 };
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tempdir::TempDir;
 use tokio_core::reactor::Handle;
-use zip::{
-    CompressionMethod,
-    ZipArchive,
-    ZipWriter,
-};
+use zip::ZipArchive;
 
 use errors::*;
 
-//TODO: might need to put this somewhere more central
-const APP_INFO: AppInfo = AppInfo {
-    name: "sccache",
-    author: "Mozilla",
-};
-
 const TEN_GIGS: usize = 10 * 1024 * 1024 * 1024;
 
 /// Result of a cache lookup.
@@ -72,64 +76,379 @@ impl fmt::Debug for Cache {
     }
 }
 
+/// Whether the cache is read from, written to, or both.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum CacheMode {
+    /// Look up compile results, and store new ones.
+    ReadWrite,
+    /// Look up compile results, but never store new ones.
+    ReadOnly,
+    /// Store new compile results, but never look them up.
+    WriteOnly,
+}
+
+impl CacheMode {
+    /// Whether compile results should be looked up in the cache.
+    pub fn can_read(&self) -> bool {
+        *self != CacheMode::WriteOnly
+    }
+
+    /// Whether compile results should be stored in the cache.
+    pub fn can_write(&self) -> bool {
+        *self != CacheMode::ReadOnly
+    }
+}
+
 /// Trait objects can't be bounded by more than one non-builtin trait.
 pub trait ReadSeek : Read + Seek + Send {}
 
 impl<T: Read + Seek + Send> ReadSeek for T {}
 
+/// Where the objects in a cache entry are kept.
+enum Objects {
+    /// In an archive.
+    Archive(ArchiveReader<Box<ReadSeek>>),
+    /// Compressed, in a zip archive, as older versions wrote entries.
+    Zip(ZipArchive<Box<ReadSeek>>),
+    /// Uncompressed, each in a file named after it in a directory.
+    ///
+    /// The files are held open, so they can still be read if the entry is
+    /// evicted from the cache before they're extracted.
+    Dir {
+        path: PathBuf,
+        files: BTreeMap<String, File>,
+        /// Whether objects may be hard linked to the paths they're
+        /// extracted to.
+        hard_link: bool,
+    },
+}
+
 /// Data stored in the compiler cache.
 pub struct CacheRead {
-    zip: ZipArchive<Box<ReadSeek>>,
+    objects: Objects,
 }
 
 impl CacheRead {
-    /// Create a cache entry from `reader`.
+    /// Create a cache entry from `reader`, which may hold an archive or a
+    /// zip archive.
     pub fn from<R: ReadSeek + 'static>(reader: R) -> Result<CacheRead> {
-        let z = ZipArchive::new(Box::new(reader) as Box<ReadSeek>).chain_err(|| {
-            "Failed to parse cache entry"
-        })?;
+        let mut reader = Box::new(reader) as Box<ReadSeek>;
+        let objects = if is_archive(&mut reader)? {
+            Objects::Archive(ArchiveReader::new(reader).chain_err(|| {
+                "Failed to parse cache entry"
+            })?)
+        } else {
+            Objects::Zip(ZipArchive::new(reader).chain_err(|| {
+                "Failed to parse cache entry"
+            })?)
+        };
+        Ok(CacheRead {
+            objects: objects,
+        })
+    }
+
+    /// Create a cache entry from the object files in the directory `path`.
+    ///
+    /// If `hard_link` is set, objects are extracted by hard linking them
+    /// when the filesystem can't make copy-on-write copies of them.
+    pub fn from_dir(path: PathBuf, hard_link: bool) -> Result<CacheRead> {
+        let mut files = BTreeMap::new();
+        for file in fs::read_dir(&path)? {
+            let file = file?;
+            if !file.file_type()?.is_file() {
+                continue
+            }
+            match file.file_name().into_string() {
+                Ok(ref name) if name == DIR_MARKER => {}
+                Ok(name) => {
+                    files.insert(name, File::open(file.path())?);
+                }
+                Err(name) => bail!("Unexpected file in cache entry: {:?}", name),
+            }
+        }
         Ok(CacheRead {
-            zip: z,
+            objects: Objects::Dir {
+                path: path,
+                files: files,
+                hard_link: hard_link,
+            },
         })
     }
 
     /// Get an object from this cache entry at `name` and write it to `to`.
     pub fn get_object<T: Write>(&mut self, name: &str, to: &mut T) -> Result<()> {
-        let mut file = self.zip.by_name(name).chain_err(|| {
-            "Failed to read object from cache entry"
-        })?;
-        io::copy(&mut file, to)?;
+        match self.objects {
+            Objects::Archive(ref mut archive) => {
+                let mut object = archive.object(name)?;
+                io::copy(&mut object, to)?;
+            }
+            Objects::Zip(ref mut zip) => {
+                let mut file = zip.by_name(name).chain_err(|| {
+                    "Failed to read object from cache entry"
+                })?;
+                io::copy(&mut file, to)?;
+            }
+            Objects::Dir { ref mut files, .. } => {
+                let file = files.get_mut(name).ok_or("Failed to read object from cache entry")?;
+                file.seek(SeekFrom::Start(0))?;
+                io::copy(file, to)?;
+            }
+        }
         Ok(())
     }
+
+    /// Write the object in this cache entry at `name` to a new file at
+    /// `path`, replacing any file that's already there.
+    ///
+    /// Objects kept in files are linked into place where possible, rather
+    /// than copied.
+    pub fn extract_object(&mut self, name: &str, path: &Path) -> Result<()> {
+        // Never write to an existing file, which might be a hard link to
+        // an object in the cache.
+        match fs::remove_file(path) {
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => {}
+            res => res?,
+        }
+        match self.objects {
+            Objects::Archive(_) | Objects::Zip(_) => {}
+            Objects::Dir { path: ref dir, ref mut files, hard_link } => {
+                let file = files.get_mut(name).ok_or("Failed to read object from cache entry")?;
+                return link_or_copy(&dir.join(name), file, path, hard_link).chain_err(|| {
+                    "Failed to read object from cache entry"
+                })
+            }
+        }
+        let mut file = File::create(path)?;
+        self.get_object(name, &mut file)
+    }
+
+    /// Copy all of the objects in this cache entry to `entry`.
+    pub fn copy_to(&mut self, entry: &mut CacheWrite) -> Result<()> {
+        match self.objects {
+            Objects::Archive(ref mut archive) => {
+                for name in archive.names() {
+                    entry.put_object(&name, &mut archive.object(&name)?)?;
+                }
+            }
+            Objects::Zip(ref mut zip) => {
+                for i in 0..zip.len() {
+                    let mut file = zip.by_index(i).chain_err(|| {
+                        "Failed to read object from cache entry"
+                    })?;
+                    let name = file.name().to_owned();
+                    entry.put_object(&name, &mut file)?;
+                }
+            }
+            Objects::Dir { ref mut files, .. } => {
+                for (name, file) in files.iter_mut() {
+                    file.seek(SeekFrom::Start(0))?;
+                    entry.put_object(name, file)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Make the file `to` a copy of `file`, the open file at `from`, as cheaply
+/// as possible: a copy-on-write clone where the filesystem supports them,
+/// or a hard link if `hard_link` is set, or else a plain copy.
+///
+/// `from` may no longer exist, if its entry was evicted from the cache, in
+/// which case `file` is copied.
+fn link_or_copy(from: &Path, file: &mut File, to: &Path, hard_link: bool) -> io::Result<()> {
+    if reflink(file, to).is_ok() {
+        return Ok(())
+    }
+    if hard_link && fs::hard_link(from, to).is_ok() {
+        // The link shares the modification time of the object in the
+        // cache, which build tools would take to mean it's out of date.
+        let d = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or(Duration::new(0, 0));
+        let now = FileTime::from_seconds_since_1970(d.as_secs(), d.subsec_nanos());
+        return set_file_times(to, now, now)
+    }
+    file.seek(SeekFrom::Start(0))?;
+    io::copy(file, &mut File::create(to)?).map(|_| ())
+}
+
+/// Make the new file `to` a copy-on-write clone of `from`.
+#[cfg(target_os = "linux")]
+fn reflink(from: &File, to: &Path) -> io::Result<()> {
+    use std::os::unix::io::AsRawFd;
+
+    /// `_IOW(0x94, 9, int)`, from `linux/fs.h`.
+    const FICLONE: u32 = 0x40049409;
+
+    let dest = File::create(to)?;
+    if unsafe { libc::ioctl(dest.as_raw_fd(), FICLONE as _, from.as_raw_fd()) } == 0 {
+        return Ok(())
+    }
+    let e = io::Error::last_os_error();
+    drop(dest);
+    fs::remove_file(to)?;
+    Err(e)
+}
+
+/// Make the new file `to` a copy-on-write clone of `from`.
+#[cfg(not(target_os = "linux"))]
+fn reflink(_from: &File, _to: &Path) -> io::Result<()> {
+    Err(io::Error::new(io::ErrorKind::Other, "copy-on-write clones are not supported"))
 }
 
 /// Data to be stored in the compiler cache.
+///
+/// Objects are written to a temporary file as they're added, so entries
+/// don't have to fit in memory.
 pub struct CacheWrite {
-    zip: ZipWriter<io::Cursor<Vec<u8>>>,
+    /// The archive the objects are written to, or `None` if they're
+    /// written to files in the directory at `path` instead.
+    archive: Option<ArchiveWriter<File>>,
+    /// The directory holding the entry.
+    dir: TempDir,
+    path: PathBuf,
+    /// The total size of the objects written so far, before compression.
+    size: u64,
 }
 
 impl CacheWrite {
-    /// Create a new, empty cache entry.
-    pub fn new() -> CacheWrite {
-        CacheWrite {
-            zip: ZipWriter::new(io::Cursor::new(vec!())),
-        }
+    /// Create a new, empty cache entry in the system's temporary directory,
+    /// with objects compressed by `codec`.
+    pub fn new(codec: Codec) -> Result<CacheWrite> {
+        CacheWrite::new_in(&env::temp_dir(), codec)
+    }
+
+    /// Create a new, empty cache entry in a file under `dir`, with objects
+    /// compressed by `codec`.
+    ///
+    /// Storage that keeps entries in files should put them on the same
+    /// filesystem, so they can be moved into place when they're finished.
+    pub fn new_in(dir: &Path, codec: Codec) -> Result<CacheWrite> {
+        fs::create_dir_all(dir)?;
+        let dir = TempDir::new_in(dir, "sccache-entry")?;
+        let path = dir.path().join("entry");
+        let file = File::create(&path)?;
+        Ok(CacheWrite {
+            archive: Some(ArchiveWriter::new(file, codec)?),
+            dir: dir,
+            path: path,
+            size: 0,
+        })
+    }
+
+    /// Create a new, empty cache entry in a directory under `dir`, with
+    /// each object written uncompressed to a file named after it.
+    ///
+    /// This is for storage that can hand out the objects' files directly
+    /// on a cache hit.
+    pub fn new_dir_in(dir: &Path) -> Result<CacheWrite> {
+        fs::create_dir_all(dir)?;
+        let dir = TempDir::new_in(dir, "sccache-entry")?;
+        let path = dir.path().join("entry");
+        fs::create_dir(&path)?;
+        Ok(CacheWrite {
+            archive: None,
+            dir: dir,
+            path: path,
+            size: 0,
+        })
     }
 
     /// Add an object containing the contents of `from` to this cache entry at `name`.
     pub fn put_object<T: Read>(&mut self, name: &str, from: &mut T) -> Result<()> {
-        self.zip.start_file(name, CompressionMethod::Deflated).chain_err(|| {
-            "Failed to start cache entry object"
-        })?;
-        io::copy(from, &mut self.zip)?;
+        let size = match self.archive {
+            Some(ref mut archive) => {
+                archive.put_object(name, from).chain_err(|| {
+                    "Failed to write cache entry object"
+                })?
+            }
+            None => {
+                let mut file = File::create(self.path.join(name)).chain_err(|| {
+                    "Failed to start cache entry object"
+                })?;
+                io::copy(from, &mut file)?
+            }
+        };
+        self.size += size;
         Ok(())
     }
 
-    /// Finish writing data to the cache entry writer, and return the data.
-    pub fn finish(self) -> Result<Vec<u8>> {
-        let CacheWrite { mut zip } = self;
-        let cur = zip.finish().chain_err(|| "Failed to finish cache entry zip")?;
-        Ok(cur.into_inner())
+    /// Finish writing data to the cache entry writer, and return the file
+    /// or directory holding it.
+    pub fn finish(self) -> Result<CacheEntryFile> {
+        let CacheWrite { archive, dir, path, size } = self;
+        let stored_size = match archive {
+            Some(archive) => {
+                let file = archive.finish().chain_err(|| "Failed to finish cache entry archive")?;
+                file.metadata()?.len()
+            }
+            None => size,
+        };
+        Ok(CacheEntryFile {
+            dir: dir,
+            path: path,
+            size: size,
+            stored_size: stored_size,
+        })
+    }
+}
+
+/// A finished cache entry, in a temporary file, or a temporary directory
+/// of object files.
+///
+/// The entry is removed when this is dropped, unless storage has moved it
+/// somewhere else by then.
+pub struct CacheEntryFile {
+    /// The directory holding the entry.
+    dir: TempDir,
+    path: PathBuf,
+    /// The total size of the objects, before compression.
+    size: u64,
+    /// The size of the entry as it's stored.
+    stored_size: u64,
+}
+
+impl CacheEntryFile {
+    /// The path of the file, or of the directory of object files.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Whether the entry is a directory of object files, rather than an
+    /// archive.
+    pub fn is_dir(&self) -> bool {
+        self.path.is_dir()
+    }
+
+    /// The total size of the objects in the entry, before compression.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// The size of the entry as it's stored, in bytes.
+    pub fn stored_size(&self) -> u64 {
+        self.stored_size
+    }
+
+    /// Open the file for reading.
+    pub fn open(&self) -> Result<File> {
+        Ok(File::open(&self.path)?)
+    }
+
+    /// Read the objects in the entry, to copy them to another entry.
+    pub fn read(&self) -> Result<CacheRead> {
+        if self.is_dir() {
+            CacheRead::from_dir(self.path.clone(), false)
+        } else {
+            CacheRead::from(self.open()?)
+        }
+    }
+
+    /// Read the whole entry into memory, for storage that can only take
+    /// it in one piece. The entry must be an archive.
+    pub fn into_bytes(self) -> Result<Vec<u8>> {
+        let mut data = Vec::with_capacity(self.stored_size as usize);
+        self.open()?.read_to_end(&mut data)?;
+        Ok(data)
     }
 }
 
@@ -147,23 +466,31 @@ pub trait Storage {
     /// Get a cache entry for `key` that can be filled with data.
     fn start_put(&self, key: &str) -> Result<CacheWrite>;
 
-    /// Put `entry` in the cache under `key`.
+    /// Put the finished cache entry `entry` in the cache under `key`.
     ///
     /// Returns a `Future` that will provide the result or error when the put is
     /// finished.
-    fn finish_put(&self, key: &str, entry: CacheWrite) -> SFuture<Duration>;
+    fn finish_put(&self, key: &str, entry: CacheEntryFile) -> SFuture<Duration>;
 
     /// Get the storage location.
     fn location(&self) -> String;
 
+    /// Get the name of the kind of storage, such as `disk` or `s3`.
+    fn backend(&self) -> &'static str;
+
     /// Get the current storage usage, if applicable.
     fn current_size(&self) -> Option<usize>;
 
     /// Get the maximum storage size, if applicable.
     fn max_size(&self) -> Option<usize>;
+
+    /// Get the number of cache hits served by each tier of the storage, if
+    /// it has more than one.
+    fn tier_hits(&self) -> Vec<(&'static str, u64)> { vec!() }
 }
 
-fn parse_size(val: &str) -> Option<usize> {
+/// Parse a size such as `10G` into a number of bytes.
+pub fn parse_size(val: &str) -> Option<usize> {
     let re = Regex::new(r"^(\d+)([KMGT])$").unwrap();
     re.captures(val)
         .and_then(|caps| caps.at(1).and_then(|size| usize::from_str(size).ok()).and_then(|size| Some((size, caps.at(2)))))
@@ -178,42 +505,156 @@ fn parse_size(val: &str) -> Option<usize> {
         })
 }
 
-/// Get a suitable `Storage` implementation from the environment.
-pub fn storage_from_environment(pool: &CpuPool, handle: &Handle) -> Arc<Storage> {
-    if let Ok(bucket) = env::var("SCCACHE_BUCKET") {
-        let endpoint = match env::var("SCCACHE_ENDPOINT") {
-            Ok(endpoint) => format!("{}/{}", endpoint, bucket),
-            _ => match env::var("SCCACHE_REGION") {
-                Ok(ref region) if region != "us-east-1" =>
+/// Get a suitable `Storage` implementation from `config`.
+///
+/// If a remote storage is configured and `SCCACHE_TIERED` is set, a local
+/// disk cache is checked before the remote storage. The storage only reads
+/// or only writes if `SCCACHE_CACHE_MODE` says so.
+pub fn storage_from_config(config: &Config, pool: &CpuPool, handle: &Handle) -> Arc<Storage> {
+    let storage = tiers_from_config(config, pool, handle);
+    match cache_mode_from_config(config) {
+        CacheMode::ReadWrite => storage,
+        mode => {
+            trace!("Using ModeCache({:?})", mode);
+            Arc::new(ModeCache::new(storage, mode))
+        }
+    }
+}
+
+/// Get the remote storage, the disk cache, or both, from `config`.
+fn tiers_from_config(config: &Config, pool: &CpuPool, handle: &Handle) -> Arc<Storage> {
+    match remote_storage_from_config(config, pool, handle) {
+        Some(remote) => {
+            if config.is_set("SCCACHE_TIERED") {
+                trace!("Using TieredCache");
+                Arc::new(TieredCache::new(disk_cache_from_config(config, pool), remote, pool))
+            } else {
+                remote
+            }
+        }
+        None => Arc::new(disk_cache_from_config(config, pool)),
+    }
+}
+
+/// Get a remote `Storage` implementation from `config`, if one is configured.
+fn remote_storage_from_config(config: &Config, pool: &CpuPool, handle: &Handle) -> Option<Arc<Storage>> {
+    let codec = codec_from_config(config);
+    if let Some(bucket) = config.get("SCCACHE_BUCKET") {
+        let endpoint = match config.get("SCCACHE_ENDPOINT") {
+            Some(endpoint) => format!("{}/{}", endpoint, bucket),
+            _ => match config.get("SCCACHE_REGION") {
+                Some(ref region) if region != "us-east-1" =>
                     format!("{}.s3-{}.amazonaws.com", bucket, region),
                 _ => format!("{}.s3.amazonaws.com", bucket),
             },
         };
         debug!("Trying S3Cache({})", endpoint);
-        match S3Cache::new(&bucket, &endpoint, handle) {
+        match S3Cache::new(&bucket, &endpoint, codec, pool, handle) {
             Ok(s) => {
                 trace!("Using S3Cache");
-                return Arc::new(s);
+                return Some(Arc::new(s));
             }
             Err(e) => warn!("Failed to create S3Cache: {:?}", e),
         }
     }
-    let d = env::var_os("SCCACHE_DIR")
+    if let Some(url) = config.get("SCCACHE_REDIS") {
+        debug!("Trying RedisCache({})", url);
+        match RedisCache::new(&url, codec, handle) {
+            Ok(s) => {
+                trace!("Using RedisCache");
+                return Some(Arc::new(s));
+            }
+            Err(e) => warn!("Failed to create RedisCache: {:?}", e),
+        }
+    }
+    if let Some(servers) = config.get("SCCACHE_MEMCACHED") {
+        debug!("Trying MemcachedCache({})", servers);
+        match MemcachedCache::new(&servers, codec, handle) {
+            Ok(s) => {
+                trace!("Using MemcachedCache");
+                return Some(Arc::new(s));
+            }
+            Err(e) => warn!("Failed to create MemcachedCache: {:?}", e),
+        }
+    }
+    if let Some(url) = config.get("SCCACHE_HTTP_URL") {
+        let auth = match (config.get("SCCACHE_HTTP_USERNAME"), config.get("SCCACHE_HTTP_PASSWORD"), config.get("SCCACHE_HTTP_TOKEN")) {
+            (Some(username), Some(password), _) => Some(HttpAuth::Basic {
+                username: username,
+                password: password,
+            }),
+            (_, _, Some(token)) => Some(HttpAuth::Bearer(token)),
+            _ => None,
+        };
+        debug!("Trying HttpCache({})", url);
+        match HttpCache::new(&url, auth, codec, handle) {
+            Ok(s) => {
+                trace!("Using HttpCache");
+                return Some(Arc::new(s));
+            }
+            Err(e) => warn!("Failed to create HttpCache: {:?}", e),
+        }
+    }
+    None
+}
+
+/// Get the directory of the disk cache from `config`.
+pub fn disk_cache_dir(config: &Config) -> PathBuf {
+    config.get("SCCACHE_DIR")
         .map(|p| PathBuf::from(p))
         .or_else(|| app_dir(AppDataType::UserCache, &APP_INFO, "").ok())
         // Fall back to something, even if it's not very good.
-        .unwrap_or(env::temp_dir().join("sccache_cache"));
+        .unwrap_or(env::temp_dir().join("sccache_cache"))
+}
+
+/// Get a `DiskCache` from `config`.
+fn disk_cache_from_config(config: &Config, pool: &CpuPool) -> DiskCache {
+    let d = disk_cache_dir(config);
     trace!("Using DiskCache({:?})", d);
-    let cache_size = env::var("SCCACHE_CACHE_SIZE")
-        .ok()
+    let cache_size = config.get("SCCACHE_CACHE_SIZE")
         .and_then(|v| parse_size(&v))
         .unwrap_or(TEN_GIGS);
     trace!("DiskCache size: {}", cache_size);
-    Arc::new(DiskCache::new(&d, cache_size, pool))
+    let mut disk = DiskCache::new(&d, cache_size, pool);
+    disk.set_hard_links(config.is_set("SCCACHE_HARD_LINK"));
+    disk
 }
 
-/// The cache is versioned by the inputs to `hash_key`.
-pub const CACHE_VERSION : &'static [u8] = b"3";
+fn parse_cache_mode(val: &str) -> Option<CacheMode> {
+    match val {
+        "read-write" => Some(CacheMode::ReadWrite),
+        "read-only" => Some(CacheMode::ReadOnly),
+        "write-only" => Some(CacheMode::WriteOnly),
+        _ => None,
+    }
+}
+
+/// Get the `CacheMode` from the `SCCACHE_CACHE_MODE` setting in `config`.
+pub fn cache_mode_from_config(config: &Config) -> CacheMode {
+    match config.get("SCCACHE_CACHE_MODE") {
+        Some(val) => parse_cache_mode(&val).unwrap_or_else(|| {
+            warn!("Unknown SCCACHE_CACHE_MODE `{}`, using read-write", val);
+            CacheMode::ReadWrite
+        }),
+        None => CacheMode::ReadWrite,
+    }
+}
+
+/// Get the `Codec` entries in remote storage are compressed with from the
+/// `SCCACHE_COMPRESSION` setting in `config`.
+fn codec_from_config(config: &Config) -> Codec {
+    match config.get("SCCACHE_COMPRESSION") {
+        Some(val) => Codec::from_name(&val).unwrap_or_else(|| {
+            warn!("Unknown SCCACHE_COMPRESSION `{}`, using zstd", val);
+            Codec::Zstd
+        }),
+        None => Codec::Zstd,
+    }
+}
+
+/// The cache is versioned by the inputs to `hash_key` and by the layout of
+/// the entries stored under those keys.
+pub const CACHE_VERSION : &'static [u8] = b"5";
 
 /// Environment variables that are factored into the cache key.
 pub const CACHED_ENV_VARS : &'static [&'static str] = &[
@@ -242,6 +683,29 @@ pub fn hash_key(compiler: &Compiler, arguments: &str, preprocessor_output: &[u8]
     m.digest().to_string()
 }
 
+/// The inputs to `hash_key`, by name, to tell why two keys differ.
+///
+/// The preprocessor output is represented by its digest.
+pub fn hash_key_components(compiler: &Compiler,
+                           arguments: &str,
+                           preprocessor_output: &[u8])
+                           -> Vec<(String, String)> {
+    // This should cover everything `hash_key` does.
+    let mut components = vec!(
+        ("compiler digest".to_owned(), compiler.digest.clone()),
+        ("cache version".to_owned(), String::from_utf8_lossy(CACHE_VERSION).into_owned()),
+        ("arguments".to_owned(), arguments.to_owned()),
+    );
+    for var in CACHED_ENV_VARS.iter() {
+        let val = env::var(var).unwrap_or_else(|_| "(unset)".to_owned());
+        components.push((format!("${}", var), val));
+    }
+    let mut m = sha1::Sha1::new();
+    m.update(preprocessor_output);
+    components.push(("preprocessor output digest".to_owned(), m.digest().to_string()));
+    components
+}
+
 
 #[test]
 fn test_parse_size() {
@@ -253,12 +717,23 @@ fn test_parse_size() {
     assert_eq!(Some(1024 * TEN_GIGS), parse_size("10T"));
 }
 
+#[test]
+fn test_parse_cache_mode() {
+    assert_eq!(Some(CacheMode::ReadWrite), parse_cache_mode("read-write"));
+    assert_eq!(Some(CacheMode::ReadOnly), parse_cache_mode("read-only"));
+    assert_eq!(Some(CacheMode::WriteOnly), parse_cache_mode("write-only"));
+    assert_eq!(None, parse_cache_mode("readonly"));
+    assert_eq!(None, parse_cache_mode(""));
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
     use compiler::{Compiler,CompilerKind};
+    use futures::Future;
     use std::env;
-    use std::io::Write;
+    use zip::{CompressionMethod, ZipWriter};
+    use std::io::{Read, Write};
     use test::utils::*;
 
     #[test]
@@ -321,4 +796,109 @@ mod test {
             assert_neq!(h2, h3);
         }
     }
+
+    #[test]
+    fn test_cache_entry_file() {
+        let f = TestFixture::new();
+        let mut entry = CacheWrite::new_in(f.tempdir.path(), Codec::Zstd).unwrap();
+        entry.put_object("obj", &mut &vec![b'x'; 10000][..]).unwrap();
+        entry.put_object("stdout", &mut &b"hello"[..]).unwrap();
+        let file = entry.finish().unwrap();
+        assert_eq!(10005, file.size());
+        assert_eq!(fs::metadata(file.path()).unwrap().len(), file.stored_size());
+        assert!(file.stored_size() < file.size());
+        let mut contents = vec!();
+        file.read().unwrap().get_object("stdout", &mut contents).unwrap();
+        assert_eq!(b"hello".to_vec(), contents);
+        let path = file.path().to_owned();
+        let data = file.into_bytes().unwrap();
+        // The file goes away with the entry.
+        assert!(!path.exists());
+        let mut contents = vec!();
+        CacheRead::from(io::Cursor::new(data)).unwrap().get_object("obj", &mut contents).unwrap();
+        assert_eq!(vec![b'x'; 10000], contents);
+    }
+
+    #[test]
+    fn test_zip_entry() {
+        // Entries written by older versions are still read.
+        let mut zip = ZipWriter::new(io::Cursor::new(vec!()));
+        zip.start_file("obj", CompressionMethod::Deflated).unwrap();
+        zip.write_all(b"hello").unwrap();
+        let data = zip.finish().unwrap().into_inner();
+        let mut hit = CacheRead::from(io::Cursor::new(data)).unwrap();
+        let mut contents = vec!();
+        hit.get_object("obj", &mut contents).unwrap();
+        assert_eq!(b"hello".to_vec(), contents);
+        let mut copy = CacheWrite::new(Codec::Lz4).unwrap();
+        hit.copy_to(&mut copy).unwrap();
+        let mut contents = vec!();
+        copy.finish().unwrap().read().unwrap().get_object("obj", &mut contents).unwrap();
+        assert_eq!(b"hello".to_vec(), contents);
+    }
+
+    #[test]
+    fn test_cache_entry_dir() {
+        let f = TestFixture::new();
+        let mut entry = CacheWrite::new_dir_in(f.tempdir.path()).unwrap();
+        entry.put_object("obj", &mut &b"hello"[..]).unwrap();
+        entry.put_object("stdout", &mut &b"out"[..]).unwrap();
+        let file = entry.finish().unwrap();
+        assert!(file.is_dir());
+        assert_eq!(8, file.size());
+        let read = |path: &Path| {
+            let mut contents = vec!();
+            File::open(path).unwrap().read_to_end(&mut contents).unwrap();
+            contents
+        };
+        for &hard_link in &[false, true] {
+            let mut hit = CacheRead::from_dir(file.path().to_owned(), hard_link).unwrap();
+            let out = f.tempdir.path().join("out.o");
+            File::create(&out).unwrap().write_all(b"old contents").unwrap();
+            hit.extract_object("obj", &out).unwrap();
+            assert_eq!(b"hello".to_vec(), read(&out));
+            // The output is replaced, not written to, even if it's linked
+            // to the object already.
+            hit.extract_object("obj", &out).unwrap();
+            assert_eq!(b"hello".to_vec(), read(&out));
+            assert_eq!(b"hello".to_vec(), read(&file.path().join("obj")));
+        }
+        // Other storage gets the objects in an archive.
+        let mut archive = CacheWrite::new_in(f.tempdir.path(), Codec::None).unwrap();
+        file.read().unwrap().copy_to(&mut archive).unwrap();
+        let archive = archive.finish().unwrap();
+        assert!(!archive.is_dir());
+        assert_eq!(8, archive.size());
+        let mut hit = CacheRead::from(archive.open().unwrap()).unwrap();
+        let mut contents = vec!();
+        hit.get_object("stdout", &mut contents).unwrap();
+        assert_eq!(b"out".to_vec(), contents);
+    }
+    #[test]
+    fn test_disk_cache_evict_before_extract() {
+        let f = TestFixture::new();
+        let pool = CpuPool::new(1);
+        let cache = DiskCache::new(&f.tempdir.path().join("cache"), 15, &pool);
+        let put = |key: &str, contents: &[u8]| {
+            let mut entry = cache.start_put(key).unwrap();
+            entry.put_object("obj", &mut &contents[..]).unwrap();
+            cache.finish_put(key, entry.finish().unwrap()).wait().unwrap();
+        };
+        put("aaaa", b"0123456789");
+        let mut hit = match cache.get("aaaa").wait().unwrap() {
+            Cache::Hit(hit) => hit,
+            _ => panic!("Expected a cache hit"),
+        };
+        // Evict the entry before its object is extracted.
+        put("bbbb", b"abcdefghij");
+        match cache.get("aaaa").wait().unwrap() {
+            Cache::Miss => {}
+            _ => panic!("Expected the entry to be evicted"),
+        }
+        let out = f.tempdir.path().join("out.o");
+        hit.extract_object("obj", &out).unwrap();
+        let mut contents = vec!();
+        File::open(&out).unwrap().read_to_end(&mut contents).unwrap();
+        assert_eq!(b"0123456789".to_vec(), contents);
+    }
 }
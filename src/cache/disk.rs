@@ -14,6 +14,7 @@
 
 use cache::{
     Cache,
+    CacheEntryFile,
     CacheRead,
     CacheWrite,
     Storage,
@@ -23,6 +24,7 @@ use futures_cpupool::CpuPool;
 use lru_disk_cache::LruDiskCache;
 use lru_disk_cache::Error as LruError;
 use std::ffi::OsStr;
+use std::fs::File;
 use std::path::{Path,PathBuf};
 use std::sync::{Arc, Mutex};
 use std::time::{Instant, Duration};
@@ -30,10 +32,21 @@ use std::time::{Instant, Duration};
 use errors::*;
 
 /// A cache that stores entries at local disk paths.
+///
+/// Each entry is a directory holding its objects uncompressed, so that on
+/// a cache hit they can be linked into place instead of being copied.
+/// Entries written by older versions are zip archives, which can still be
+/// read.
 #[derive(Clone)]
 pub struct DiskCache {
     /// `LruDiskCache` does all the real work here.
     lru: Arc<Mutex<LruDiskCache>>,
+    /// Where entries are written before they're moved into the cache. This
+    /// is next to the cache, so it's on the same filesystem but the cache
+    /// doesn't mistake unfinished entries for its own files.
+    tmp: PathBuf,
+    /// Whether objects may be hard linked out of the cache.
+    hard_link: bool,
     /// Thread pool to execute disk I/O
     pool: CpuPool,
 }
@@ -43,12 +56,25 @@ impl DiskCache {
     pub fn new<T: AsRef<OsStr>>(root: &T,
                                 max_size: usize,
                                 pool: &CpuPool) -> DiskCache {
+        let mut tmp = root.as_ref().to_os_string();
+        tmp.push(".tmp");
         DiskCache {
             //TODO: change this function to return a Result
             lru: Arc::new(Mutex::new(LruDiskCache::new(root, max_size).expect("Couldn't instantiate disk cache!"))),
+            tmp: PathBuf::from(tmp),
+            hard_link: false,
             pool: pool.clone(),
         }
     }
+
+    /// Set whether objects may be hard linked to the outputs of cache
+    /// hits, where the filesystem can't make copy-on-write copies of them.
+    ///
+    /// Anything that writes to an output in place, rather than replacing
+    /// it, changes the object in the cache too.
+    pub fn set_hard_links(&mut self, hard_link: bool) {
+        self.hard_link = hard_link;
+    }
 }
 
 /// Make a path to the cache entry with key `key`.
@@ -61,34 +87,42 @@ impl Storage for DiskCache {
         trace!("DiskCache::get({})", key);
         let path = make_key_path(key);
         let lru = self.lru.clone();
+        let hard_link = self.hard_link;
         self.pool.spawn_fn(move || {
             let mut lru = lru.lock().unwrap();
-            let f = match lru.get(&path) {
-                Ok(f) => f,
+            let path = match lru.get_path(&path) {
+                Ok(path) => path,
                 Err(LruError::FileNotInCache) => return Ok(Cache::Miss),
                 Err(LruError::Io(e)) => return Err(e.into()),
                 Err(_) => panic!("Unexpected error!"),
             };
-            let hit = CacheRead::from(f)?;
+            // Open the entry's files while it can't be evicted.
+            let hit = if path.is_dir() {
+                CacheRead::from_dir(path, hard_link)?
+            } else {
+                CacheRead::from(File::open(&path)?)?
+            };
             Ok(Cache::Hit(hit))
         }).boxed()
     }
 
     fn start_put(&self, key: &str) -> Result<CacheWrite> {
         trace!("DiskCache::start_put({})", key);
-        Ok(CacheWrite::new())
+        CacheWrite::new_dir_in(&self.tmp)
     }
 
-    fn finish_put(&self, key: &str, entry: CacheWrite) -> SFuture<Duration> {
-        // We should probably do this on a background thread if we're going to buffer
-        // everything in memory...
+    fn finish_put(&self, key: &str, entry: CacheEntryFile) -> SFuture<Duration> {
         trace!("DiskCache::finish_put({})", key);
         let lru = self.lru.clone();
         let key = make_key_path(key);
         self.pool.spawn_fn(move || {
             let start = Instant::now();
-            let v = entry.finish()?;
-            lru.lock().unwrap().insert_bytes(key, &v)?;
+            // This moves the entry into the cache, if it was written next to it.
+            if entry.is_dir() {
+                lru.lock().unwrap().insert_dir(key, entry.path())?;
+            } else {
+                lru.lock().unwrap().insert_file(key, entry.path())?;
+            }
             Ok(start.elapsed())
         }).boxed()
     }
@@ -97,6 +131,10 @@ impl Storage for DiskCache {
         format!("Local disk: {:?}", self.lru.lock().unwrap().path())
     }
 
+    fn backend(&self) -> &'static str {
+        "disk"
+    }
+
     fn current_size(&self) -> Option<usize> { Some(self.lru.lock().unwrap().size()) }
     fn max_size(&self) -> Option<usize> { Some(self.lru.lock().unwrap().capacity()) }
 }
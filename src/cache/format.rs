@@ -0,0 +1,364 @@
+// Copyright 2017 Mozilla Foundation
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The archive format of cache entries.
+//!
+//! An archive starts with a header of `MAGIC`, the format version and the
+//! codec its objects are compressed with. The objects follow, each
+//! compressed separately so that they can be read in any order, and then an
+//! index of their names, offsets and sizes. The archive ends with the
+//! offset of the index.
+//!
+//! Numbers are little-endian. Entries written before this format existed
+//! are zip archives, which are told apart by their first bytes.
+
+use lz4;
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use zstd;
+
+use errors::*;
+
+/// The first bytes of an archive.
+const MAGIC: &'static [u8; 8] = b"\x89SCCACHE";
+
+/// The version of the format archives are written in.
+const FORMAT_VERSION: u8 = 1;
+
+/// The compression level zstd is used at.
+const ZSTD_LEVEL: i32 = 3;
+
+/// A compression algorithm for the objects in archives.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Codec {
+    /// Objects are stored as they are.
+    None,
+    /// Zstandard.
+    Zstd,
+    /// LZ4.
+    Lz4,
+}
+
+impl Codec {
+    /// The name of the codec, as it's configured.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Codec::None => "none",
+            Codec::Zstd => "zstd",
+            Codec::Lz4 => "lz4",
+        }
+    }
+
+    /// The codec with the name `name`, if there is one.
+    pub fn from_name(name: &str) -> Option<Codec> {
+        match name {
+            "none" => Some(Codec::None),
+            "zstd" => Some(Codec::Zstd),
+            "lz4" => Some(Codec::Lz4),
+            _ => None,
+        }
+    }
+
+    /// The identifier of the codec in archive headers.
+    fn id(&self) -> u8 {
+        match *self {
+            Codec::None => 0,
+            Codec::Zstd => 1,
+            Codec::Lz4 => 2,
+        }
+    }
+
+    fn from_id(id: u8) -> Option<Codec> {
+        match id {
+            0 => Some(Codec::None),
+            1 => Some(Codec::Zstd),
+            2 => Some(Codec::Lz4),
+            _ => None,
+        }
+    }
+
+    /// Compress everything read from `from` into `to`, and return the
+    /// number of bytes read.
+    fn encode<R: Read, W: Write>(&self, from: &mut R, to: W) -> io::Result<u64> {
+        match *self {
+            Codec::None => {
+                let mut to = to;
+                io::copy(from, &mut to)
+            }
+            Codec::Zstd => {
+                let mut encoder = zstd::stream::Encoder::new(to, ZSTD_LEVEL)?;
+                let size = io::copy(from, &mut encoder)?;
+                encoder.finish()?;
+                Ok(size)
+            }
+            Codec::Lz4 => {
+                let mut encoder = lz4::EncoderBuilder::new().build(to)?;
+                let size = io::copy(from, &mut encoder)?;
+                let (_, res) = encoder.finish();
+                res?;
+                Ok(size)
+            }
+        }
+    }
+
+    /// A reader of the decompressed contents of `from`.
+    fn decoder<'a, R: Read + 'a>(&self, from: R) -> io::Result<Box<Read + 'a>> {
+        Ok(match *self {
+            Codec::None => Box::new(from),
+            Codec::Zstd => Box::new(zstd::stream::Decoder::new(from)?),
+            Codec::Lz4 => Box::new(lz4::Decoder::new(from)?),
+        })
+    }
+}
+
+/// Where an object is in an archive.
+#[derive(Debug, PartialEq)]
+struct IndexEntry {
+    name: String,
+    /// The offset of the object from the start of the archive.
+    offset: u64,
+    /// The size of the object as it's stored.
+    stored_size: u64,
+    /// The size of the object once it's decompressed.
+    size: u64,
+}
+
+fn write_u16<W: Write>(to: &mut W, n: u16) -> io::Result<()> {
+    to.write_all(&[n as u8, (n >> 8) as u8])
+}
+
+fn write_u64<W: Write>(to: &mut W, n: u64) -> io::Result<()> {
+    let mut bytes = [0; 8];
+    for (i, b) in bytes.iter_mut().enumerate() {
+        *b = (n >> (i * 8)) as u8;
+    }
+    to.write_all(&bytes)
+}
+
+fn read_u16<R: Read>(from: &mut R) -> io::Result<u16> {
+    let mut bytes = [0; 2];
+    from.read_exact(&mut bytes)?;
+    Ok(bytes[0] as u16 | (bytes[1] as u16) << 8)
+}
+
+fn read_u64<R: Read>(from: &mut R) -> io::Result<u64> {
+    let mut bytes = [0; 8];
+    from.read_exact(&mut bytes)?;
+    Ok(bytes.iter().rev().fold(0, |n, &b| n << 8 | b as u64))
+}
+
+/// A writer that counts the bytes written through it.
+struct Counter<'a, W: 'a> {
+    inner: &'a mut W,
+    count: u64,
+}
+
+impl<'a, W: Write> Write for Counter<'a, W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.count += n as u64;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Writes objects to an archive.
+pub struct ArchiveWriter<W> {
+    inner: W,
+    codec: Codec,
+    index: Vec<IndexEntry>,
+    /// The offset the next object will be written at.
+    offset: u64,
+}
+
+impl<W: Write> ArchiveWriter<W> {
+    /// Start an archive in `inner`, compressing its objects with `codec`.
+    pub fn new(mut inner: W, codec: Codec) -> io::Result<ArchiveWriter<W>> {
+        inner.write_all(MAGIC)?;
+        inner.write_all(&[FORMAT_VERSION, codec.id()])?;
+        Ok(ArchiveWriter {
+            inner: inner,
+            codec: codec,
+            index: vec!(),
+            offset: MAGIC.len() as u64 + 2,
+        })
+    }
+
+    /// Add an object containing the contents of `from` at `name`, and
+    /// return its size before compression.
+    pub fn put_object<R: Read>(&mut self, name: &str, from: &mut R) -> io::Result<u64> {
+        if name.len() > u16::max_value() as usize {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "object name is too long"));
+        }
+        let mut counter = Counter {
+            inner: &mut self.inner,
+            count: 0,
+        };
+        let size = self.codec.encode(from, &mut counter)?;
+        self.index.push(IndexEntry {
+            name: name.to_owned(),
+            offset: self.offset,
+            stored_size: counter.count,
+            size: size,
+        });
+        self.offset += counter.count;
+        Ok(size)
+    }
+
+    /// Write the index, and return the writer the archive was written to.
+    pub fn finish(mut self) -> io::Result<W> {
+        let index_offset = self.offset;
+        write_u64(&mut self.inner, self.index.len() as u64)?;
+        for entry in &self.index {
+            write_u16(&mut self.inner, entry.name.len() as u16)?;
+            self.inner.write_all(entry.name.as_bytes())?;
+            write_u64(&mut self.inner, entry.offset)?;
+            write_u64(&mut self.inner, entry.stored_size)?;
+            write_u64(&mut self.inner, entry.size)?;
+        }
+        write_u64(&mut self.inner, index_offset)?;
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+}
+
+/// Whether `reader` holds an archive, rather than a zip archive.
+///
+/// The reader is left at its start.
+pub fn is_archive<R: Read + Seek>(reader: &mut R) -> io::Result<bool> {
+    let mut magic = [0; 8];
+    let mut read = 0;
+    while read < magic.len() {
+        match reader.read(&mut magic[read..])? {
+            0 => break,
+            n => read += n,
+        }
+    }
+    reader.seek(SeekFrom::Start(0))?;
+    Ok(read == magic.len() && &magic == MAGIC)
+}
+
+/// Reads objects from an archive.
+pub struct ArchiveReader<R> {
+    inner: R,
+    codec: Codec,
+    index: Vec<IndexEntry>,
+}
+
+impl<R: Read + Seek> ArchiveReader<R> {
+    /// Read the archive in `inner`.
+    pub fn new(mut inner: R) -> Result<ArchiveReader<R>> {
+        let mut header = [0; 10];
+        inner.read_exact(&mut header)?;
+        if &header[..8] != MAGIC {
+            bail!("Not a cache entry archive");
+        }
+        if header[8] != FORMAT_VERSION {
+            bail!("Unsupported cache entry format version {}", header[8]);
+        }
+        let codec = match Codec::from_id(header[9]) {
+            Some(codec) => codec,
+            None => bail!("Unknown cache entry codec {}", header[9]),
+        };
+        inner.seek(SeekFrom::End(-8))?;
+        let index_offset = read_u64(&mut inner)?;
+        inner.seek(SeekFrom::Start(index_offset))?;
+        let count = read_u64(&mut inner)?;
+        let mut index = vec!();
+        for _ in 0..count {
+            let mut name = vec![0; read_u16(&mut inner)? as usize];
+            inner.read_exact(&mut name)?;
+            let name = String::from_utf8(name).chain_err(|| "Bad object name in cache entry")?;
+            index.push(IndexEntry {
+                name: name,
+                offset: read_u64(&mut inner)?,
+                stored_size: read_u64(&mut inner)?,
+                size: read_u64(&mut inner)?,
+            });
+        }
+        Ok(ArchiveReader {
+            inner: inner,
+            codec: codec,
+            index: index,
+        })
+    }
+
+    /// The names of the objects in the archive, in the order they were
+    /// written.
+    pub fn names(&self) -> Vec<String> {
+        self.index.iter().map(|e| e.name.clone()).collect()
+    }
+
+    /// A reader of the object at `name`.
+    pub fn object<'a>(&'a mut self, name: &str) -> Result<Box<Read + 'a>> where R: 'a {
+        let (offset, stored_size) = match self.index.iter().find(|e| e.name == name) {
+            Some(entry) => (entry.offset, entry.stored_size),
+            None => bail!("No object `{}` in cache entry", name),
+        };
+        self.inner.seek(SeekFrom::Start(offset))?;
+        Ok(self.codec.decoder((&mut self.inner).take(stored_size))?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_archive() {
+        let contents = vec![b'x'; 10000];
+        for &codec in &[Codec::None, Codec::Zstd, Codec::Lz4] {
+            let mut writer = ArchiveWriter::new(Cursor::new(vec!()), codec).unwrap();
+            assert_eq!(10000, writer.put_object("obj", &mut &contents[..]).unwrap());
+            assert_eq!(5, writer.put_object("stdout", &mut &b"hello"[..]).unwrap());
+            let mut data = writer.finish().unwrap();
+            if codec != Codec::None {
+                assert!(data.get_ref().len() < 1000, "{:?} didn't compress", codec);
+            }
+            data.set_position(0);
+            assert!(is_archive(&mut data).unwrap());
+            let mut reader = ArchiveReader::new(data).unwrap();
+            assert_eq!(vec!("obj".to_owned(), "stdout".to_owned()), reader.names());
+            // Objects can be read in any order.
+            let mut stdout = vec!();
+            reader.object("stdout").unwrap().read_to_end(&mut stdout).unwrap();
+            assert_eq!(b"hello".to_vec(), stdout);
+            let mut obj = vec!();
+            reader.object("obj").unwrap().read_to_end(&mut obj).unwrap();
+            assert_eq!(contents, obj);
+            assert!(reader.object("stderr").is_err());
+        }
+    }
+
+    #[test]
+    fn test_codec_names() {
+        for &codec in &[Codec::None, Codec::Zstd, Codec::Lz4] {
+            assert_eq!(Some(codec), Codec::from_name(codec.name()));
+            assert_eq!(Some(codec), Codec::from_id(codec.id()));
+        }
+        assert_eq!(None, Codec::from_name("deflate"));
+    }
+
+    #[test]
+    fn test_is_archive() {
+        // Zip archives start with a local file header, or the end of the
+        // central directory if they're empty.
+        assert!(!is_archive(&mut Cursor::new(b"PK\x03\x04\x14\x00\x00\x00".to_vec())).unwrap());
+        assert!(!is_archive(&mut Cursor::new(b"PK\x05\x06".to_vec())).unwrap());
+        assert!(!is_archive(&mut Cursor::new(vec!())).unwrap());
+    }
+}
@@ -0,0 +1,367 @@
+// Copyright 2017 Mozilla Foundation
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use cache::{
+    Cache,
+    CacheEntryFile,
+    CacheRead,
+    CacheWrite,
+    Codec,
+    Storage,
+};
+use futures::{future, stream, Future, Stream};
+use hyper::{self, header};
+use hyper::Method;
+use hyper::client::{Client, Request, Response};
+use hyper::status::{StatusClass, StatusCode};
+use hyper_tls::HttpsConnector;
+use rustc_serialize::base64::{ToBase64, STANDARD};
+use simples3::https_client;
+use std::io;
+use std::time::{Instant, Duration};
+use tokio_core::reactor::Handle;
+
+use errors::*;
+
+/// Credentials to send with each request.
+pub enum HttpAuth {
+    /// HTTP basic authentication.
+    Basic {
+        username: String,
+        password: String,
+    },
+    /// A bearer token.
+    Bearer(String),
+}
+
+impl HttpAuth {
+    /// The value of the `Authorization` header for these credentials.
+    fn header_value(&self) -> String {
+        match *self {
+            HttpAuth::Basic { ref username, ref password } => {
+                let credentials = format!("{}:{}", username, password);
+                format!("Basic {}", credentials.as_bytes().to_base64(STANDARD))
+            }
+            HttpAuth::Bearer(ref token) => format!("Bearer {}", token),
+        }
+    }
+}
+
+/// A cache that stores entries on a plain HTTP server, like a WebDAV share,
+/// with `GET` and `PUT`.
+pub struct HttpCache {
+    /// The URL entries are stored under, ending in a slash.
+    base_url: String,
+    /// The `Authorization` header to send, if any.
+    auth: Option<String>,
+    /// How entries are compressed.
+    codec: Codec,
+    client: Client<HttpsConnector>,
+}
+
+impl HttpCache {
+    /// Create a new `HttpCache` storing entries under `base_url`,
+    /// compressed with `codec`.
+    pub fn new(base_url: &str, auth: Option<HttpAuth>, codec: Codec, handle: &Handle) -> Result<HttpCache> {
+        if !base_url.starts_with("http://") && !base_url.starts_with("https://") {
+            bail!("unsupported URL `{}`", base_url)
+        }
+        let mut base_url = base_url.to_owned();
+        if !base_url.ends_with('/') {
+            base_url.push('/');
+        }
+        Ok(HttpCache {
+            base_url: base_url,
+            auth: auth.map(|a| a.header_value()),
+            codec: codec,
+            client: https_client(handle),
+        })
+    }
+
+    /// Make a request for `path`, relative to the base URL.
+    fn request(&self, method: Method, path: &str) -> Result<Request> {
+        let url = format!("{}{}", self.base_url, path);
+        debug!("{} {}", method, url);
+        let uri = url.parse().map_err(|_| format!("invalid URL `{}`", url))?;
+        let mut request = Request::new(method, uri);
+        if let Some(ref auth) = self.auth {
+            request.headers_mut().set_raw("Authorization", vec!(auth.as_bytes().to_vec()));
+        }
+        Ok(request)
+    }
+}
+
+/// Make the path of the entry with key `key`, relative to the base URL.
+fn normalize_key(key: &str) -> String {
+    format!("{}/{}/{}/{}", &key[0..1], &key[1..2], &key[2..3], &key)
+}
+
+/// Make the paths of the collections the entry with key `key` goes in,
+/// outermost first.
+fn collections(key: &str) -> Vec<String> {
+    (1..4).map(|i| {
+        key[..i].chars().map(|c| format!("{}/", c)).collect()
+    }).collect()
+}
+
+impl Storage for HttpCache {
+    fn get(&self, key: &str) -> SFuture<Cache> {
+        let request = match self.request(Method::Get, &normalize_key(key)) {
+            Ok(request) => request,
+            Err(e) => return future::err(e).boxed(),
+        };
+        let response = self.client.request(request).chain_err(|| "failed GET");
+        let body = response.and_then(|res| -> SFuture<_> {
+            if *res.status() == StatusCode::NotFound {
+                return future::ok(None).boxed()
+            }
+            if res.status().class() != StatusClass::Success {
+                return future::err(ErrorKind::BadHTTPStatus(res.status().clone()).into()).boxed()
+            }
+            Box::new(res.body().fold(Vec::new(), |mut body, chunk| {
+                body.extend_from_slice(&chunk);
+                Ok::<_, hyper::Error>(body)
+            }).chain_err(|| {
+                "failed to read HTTP body"
+            }).map(Some))
+        });
+        Box::new(body.then(|result| {
+            match result {
+                Ok(Some(data)) => {
+                    let hit = CacheRead::from(io::Cursor::new(data))?;
+                    Ok(Cache::Hit(hit))
+                }
+                Ok(None) => Ok(Cache::Miss),
+                Err(e) => {
+                    warn!("Got HTTP error: {:?}", e);
+                    Ok(Cache::Miss)
+                }
+            }
+        }))
+    }
+
+    fn start_put(&self, _key: &str) -> Result<CacheWrite> {
+        CacheWrite::new(self.codec)
+    }
+
+    fn finish_put(&self, key: &str, entry: CacheEntryFile) -> SFuture<Duration> {
+        let start = Instant::now();
+        let data = match entry.into_bytes() {
+            Ok(data) => data,
+            Err(e) => return future::err(e).boxed(),
+        };
+        let put = |data: Vec<u8>| -> Result<Request> {
+            let mut request = self.request(Method::Put, &normalize_key(key))?;
+            request.headers_mut().set(header::ContentType("application/octet-stream".parse().unwrap()));
+            request.headers_mut().set(header::ContentLength(data.len() as u64));
+            request.set_body(data);
+            Ok(request)
+        };
+        // WebDAV servers don't create the collections an entry goes in, but
+        // say so with a 409, so create them and try again.
+        let requests = put(data.clone()).and_then(|request| {
+            let mkcols = collections(key).iter()
+                .map(|path| self.request(Method::Extension("MKCOL".to_owned()), path))
+                .collect::<Result<Vec<_>>>()?;
+            Ok((request, mkcols, put(data)?))
+        });
+        let (request, mkcols, retry) = match requests {
+            Ok(requests) => requests,
+            Err(e) => return future::err(e).boxed(),
+        };
+        let client = self.client.clone();
+        let response = self.client.request(request).and_then(move |res| -> Box<Future<Item=Response, Error=hyper::Error>> {
+            if *res.status() != StatusCode::Conflict {
+                return Box::new(future::ok(res))
+            }
+            trace!("PUT conflicted, creating collections");
+            let mkcol_client = client.clone();
+            let mkcols = stream::iter(mkcols.into_iter().map(Ok::<_, hyper::Error>)).fold((), move |(), mkcol| {
+                mkcol_client.request(mkcol).map(|res| {
+                    // The collection may already be there.
+                    if res.status().class() != StatusClass::Success && *res.status() != StatusCode::MethodNotAllowed {
+                        trace!("MKCOL failed with HTTP status: {}", res.status());
+                    }
+                })
+            });
+            Box::new(mkcols.and_then(move |()| client.request(retry)))
+        });
+        Box::new(response.then(move |result| {
+            match result {
+                Ok(res) => {
+                    if res.status().class() == StatusClass::Success {
+                        trace!("PUT succeeded");
+                        Ok(start.elapsed())
+                    } else {
+                        trace!("PUT failed with HTTP status: {}", res.status());
+                        Err(ErrorKind::BadHTTPStatus(res.status().clone()).into())
+                    }
+                }
+                Err(e) => {
+                    trace!("PUT failed with error: {:?}", e);
+                    Err(e.into())
+                }
+            }
+        }))
+    }
+
+    fn location(&self) -> String {
+        format!("HTTP: {}", self.base_url)
+    }
+
+    fn backend(&self) -> &'static str {
+        "http"
+    }
+
+    fn current_size(&self) -> Option<usize> { None }
+    fn max_size(&self) -> Option<usize> { None }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::collections::{HashMap, HashSet};
+    use std::io::{self, BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+    use tokio_core::reactor::Core;
+
+    /// Read a request from `reader`, returning its method, path and body.
+    fn read_request<R: BufRead>(reader: &mut R) -> (String, String, Vec<u8>) {
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        let (method, path) = {
+            let mut parts = line.split_whitespace();
+            (parts.next().unwrap().to_owned(), parts.next().unwrap().to_owned())
+        };
+        let mut length = 0;
+        loop {
+            let mut header = String::new();
+            reader.read_line(&mut header).unwrap();
+            let header = header.trim_right().to_lowercase();
+            if header.is_empty() {
+                break
+            }
+            if header.starts_with("content-length:") {
+                length = header["content-length:".len()..].trim().parse().unwrap();
+            }
+        }
+        let mut body = vec![0; length];
+        reader.read_exact(&mut body).unwrap();
+        (method, path, body)
+    }
+
+    /// Serve `files` like a WebDAV server would, only creating collections
+    /// on `MKCOL`, one request per connection. Return the URL it's at.
+    fn serve(files: HashMap<String, Vec<u8>>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        thread::spawn(move || {
+            let mut files = files;
+            let mut collections = HashSet::new();
+            collections.insert("/".to_owned());
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let (method, path, body) = read_request(&mut BufReader::new(stream.try_clone().unwrap()));
+                let parent = {
+                    let i = path.trim_right_matches('/').rfind('/').unwrap();
+                    path[..i + 1].to_owned()
+                };
+                let (status, body) = match method.as_str() {
+                    "GET" => match files.get(&path) {
+                        Some(contents) => ("200 OK", contents.clone()),
+                        None => ("404 Not Found", vec!()),
+                    },
+                    "PUT" if collections.contains(&parent) => {
+                        files.insert(path, body);
+                        ("201 Created", vec!())
+                    }
+                    "MKCOL" if collections.contains(&path) => ("405 Method Not Allowed", vec!()),
+                    "MKCOL" if collections.contains(&parent) => {
+                        collections.insert(path);
+                        ("201 Created", vec!())
+                    }
+                    _ => ("409 Conflict", vec!()),
+                };
+                write!(stream, "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                       status, body.len()).unwrap();
+                stream.write_all(&body).unwrap();
+            }
+        });
+        url
+    }
+
+    /// Make a cache entry holding `contents`.
+    fn entry(contents: &[u8]) -> CacheEntryFile {
+        let mut entry = CacheWrite::new(Codec::None).unwrap();
+        entry.put_object("obj", &mut io::Cursor::new(contents)).unwrap();
+        entry.finish().unwrap()
+    }
+
+    fn get(cache: &HttpCache, core: &mut Core, key: &str) -> Option<Vec<u8>> {
+        match core.run(cache.get(key)).unwrap() {
+            Cache::Hit(mut hit) => {
+                let mut contents = vec!();
+                hit.get_object("obj", &mut contents).unwrap();
+                Some(contents)
+            }
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_auth_header() {
+        let basic = HttpAuth::Basic {
+            username: "Aladdin".to_owned(),
+            password: "open sesame".to_owned(),
+        };
+        assert_eq!("Basic QWxhZGRpbjpvcGVuIHNlc2FtZQ==", basic.header_value());
+        assert_eq!("Bearer abc", HttpAuth::Bearer("abc".to_owned()).header_value());
+    }
+
+    #[test]
+    fn test_normalize_key() {
+        assert_eq!("0/1/2/0123abc", normalize_key("0123abc"));
+    }
+
+    #[test]
+    fn test_collections() {
+        assert_eq!(vec!("0/", "0/1/", "0/1/2/"), collections("0123abc"));
+    }
+
+    #[test]
+    fn test_get() {
+        let mut core = Core::new().unwrap();
+        let mut files = HashMap::new();
+        files.insert("/0/1/2/0123abc".to_owned(), entry(b"hit").into_bytes().unwrap());
+        let url = serve(files);
+        let cache = HttpCache::new(&url, None, Codec::None, &core.handle()).unwrap();
+        assert_eq!(Some(b"hit".to_vec()), get(&cache, &mut core, "0123abc"));
+        // A 404 is a miss.
+        assert_eq!(None, get(&cache, &mut core, "456def"));
+    }
+
+    #[test]
+    fn test_put() {
+        let mut core = Core::new().unwrap();
+        let url = serve(HashMap::new());
+        let cache = HttpCache::new(&url, None, Codec::Zstd, &core.handle()).unwrap();
+        // The first entry needs its collections creating, the second only
+        // some of them.
+        core.run(cache.finish_put("0123abc", entry(b"one"))).unwrap();
+        core.run(cache.finish_put("0456def", entry(b"two"))).unwrap();
+        assert_eq!(Some(b"one".to_vec()), get(&cache, &mut core, "0123abc"));
+        assert_eq!(Some(b"two".to_vec()), get(&cache, &mut core, "0456def"));
+    }
+}
@@ -0,0 +1,583 @@
+// Copyright 2017 Mozilla Foundation
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use cache::{
+    Cache,
+    CacheEntryFile,
+    CacheRead,
+    CacheWrite,
+    Codec,
+    Storage,
+};
+use futures::{future, stream, Future, Sink, Stream};
+use sha1;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::io;
+use std::mem;
+use std::net::{SocketAddr, ToSocketAddrs};
+use std::rc::Rc;
+use std::str;
+use std::time::{Instant, Duration};
+use tokio_core::io::{Codec as FrameCodec, EasyBuf, Framed, Io};
+use tokio_core::net::TcpStream;
+use tokio_core::reactor::Handle;
+use uuid::Uuid;
+
+use errors::*;
+
+/// The largest chunk of an entry stored in a single item. memcached
+/// refuses items over 1MB by default, and that includes the key and some
+/// bookkeeping.
+const CHUNK_SIZE: usize = 1024 * 1024 - 1024;
+
+/// The number of points each server gets on the hash ring.
+const POINTS_PER_SERVER: usize = 100;
+
+/// A connection to a memcached server.
+type Connection = Framed<TcpStream, MemcacheCodec>;
+
+/// A cache that stores entries in a set of memcached servers.
+///
+/// Entries are spread across servers with consistent hashing, so adding or
+/// removing a server only loses the entries that hashed to it. Entries are
+/// split into chunks of at most `CHUNK_SIZE` bytes, stored on the same server
+/// as the entry. The entry's own item holds the first chunk, along with the
+/// number of chunks and a nonce the rest are stored under, so a lookup only
+/// needs a second round trip for large entries, and never mixes up chunks
+/// from different writes.
+pub struct MemcachedCache {
+    /// The addresses of the servers.
+    servers: Vec<SocketAddr>,
+    /// Points on the hash ring, sorted, and the index of the server owning
+    /// each.
+    ring: Vec<(u32, usize)>,
+    /// How entries are compressed.
+    codec: Codec,
+    /// Connections to the servers.
+    conns: Connections,
+}
+
+/// Connections to memcached servers, which are kept open once their
+/// requests are done, and reused for later ones.
+#[derive(Clone)]
+struct Connections {
+    /// Connections that aren't running any requests, by server.
+    idle: Rc<RefCell<HashMap<SocketAddr, Vec<Connection>>>>,
+    /// Handle to the event loop that requests run on.
+    handle: Handle,
+}
+
+impl MemcachedCache {
+    /// Create a new `MemcachedCache` storing entries in `servers`, a list of
+    /// `host:port` addresses separated by commas or whitespace, compressed
+    /// with `codec`.
+    pub fn new(servers: &str, codec: Codec, handle: &Handle) -> Result<MemcachedCache> {
+        let mut addrs = vec!();
+        for server in servers.split(|c: char| c == ',' || c.is_whitespace()).filter(|s| !s.is_empty()) {
+            let addr = server.to_socket_addrs()
+                .chain_err(|| format!("failed to resolve `{}`", server))?
+                .next()
+                .ok_or_else(|| format!("no addresses found for `{}`", server))?;
+            addrs.push(addr);
+        }
+        if addrs.is_empty() {
+            bail!("no memcached servers given")
+        }
+        Ok(MemcachedCache {
+            ring: make_ring(&addrs),
+            servers: addrs,
+            codec: codec,
+            conns: Connections {
+                idle: Rc::new(RefCell::new(HashMap::new())),
+                handle: handle.clone(),
+            },
+        })
+    }
+
+    /// Return the address of the server that `key` is stored on.
+    fn server_for(&self, key: &str) -> SocketAddr {
+        self.servers[server_for(&self.ring, key)]
+    }
+}
+
+impl Connections {
+    /// Send `requests` to the server at `addr` on an idle connection, or a
+    /// new one if there isn't one, and return the responses.
+    fn request(&self, addr: SocketAddr, requests: Vec<Request>) -> SFuture<Vec<Response>> {
+        trace!("MemcachedCache: sending {} requests to {}", requests.len(), addr);
+        let handle = self.handle.clone();
+        let reused = self.idle.borrow_mut().get_mut(&addr).and_then(|conns| conns.pop());
+        let responses = match reused {
+            Some(conn) => {
+                // The server may have closed the connection while it was
+                // idle, so try a new one if it fails.
+                let retry = requests.clone();
+                Box::new(run_requests(conn, requests).or_else(move |e| {
+                    debug!("Failed to reuse memcached connection: {}", e);
+                    connect(&addr, &handle).and_then(move |conn| run_requests(conn, retry))
+                })) as Box<Future<Item=_, Error=_>>
+            }
+            None => {
+                Box::new(connect(&addr, &handle).and_then(move |conn| run_requests(conn, requests)))
+            }
+        };
+        let idle = self.idle.clone();
+        let responses = responses.chain_err(|| "failed to talk to memcached server");
+        Box::new(responses.and_then(move |(conn, responses)| -> Result<_> {
+            idle.borrow_mut().entry(addr).or_insert_with(Vec::new).push(conn);
+            for response in responses.iter() {
+                if let Response::Error(ref e) = *response {
+                    bail!("memcached error: {}", e)
+                }
+            }
+            Ok(responses)
+        }))
+    }
+}
+
+/// Connect to the server at `addr`.
+fn connect(addr: &SocketAddr, handle: &Handle) -> Box<Future<Item=Connection, Error=io::Error>> {
+    Box::new(TcpStream::connect(addr, handle).map(|sock| sock.framed(MemcacheCodec::new())))
+}
+
+/// Send `requests` on `conn`, and return it along with the responses.
+fn run_requests(conn: Connection, requests: Vec<Request>)
+                -> Box<Future<Item=(Connection, Vec<Response>), Error=io::Error>> {
+    let count = requests.len();
+    let requests = stream::iter(requests.into_iter().map(Ok::<_, io::Error>));
+    Box::new(conn.send_all(requests).and_then(move |(conn, _)| {
+        stream::iter((0..count).map(Ok::<_, io::Error>)).fold((conn, vec!()), |(conn, mut responses), _| {
+            conn.into_future().map_err(|(e, _)| e).and_then(|(response, conn)| {
+                match response {
+                    Some(response) => {
+                        responses.push(response);
+                        Ok((conn, responses))
+                    }
+                    None => Err(io::Error::new(io::ErrorKind::UnexpectedEof, "memcached server closed the connection")),
+                }
+            })
+        })
+    }))
+}
+
+/// Place `servers` on a hash ring.
+fn make_ring(servers: &[SocketAddr]) -> Vec<(u32, usize)> {
+    let mut ring = vec!();
+    for (index, server) in servers.iter().enumerate() {
+        for point in 0..POINTS_PER_SERVER {
+            ring.push((hash_point(&format!("{}-{}", server, point)), index));
+        }
+    }
+    ring.sort();
+    ring
+}
+
+/// Return the index of the server on `ring` that `key` is stored on.
+fn server_for(ring: &[(u32, usize)], key: &str) -> usize {
+    let point = hash_point(key);
+    // The first point at or after `point`, wrapping around the ring.
+    let i = match ring.binary_search_by(|&(p, _)| p.cmp(&point)) {
+        Ok(i) => i,
+        Err(i) => i % ring.len(),
+    };
+    ring[i].1
+}
+
+/// Return the position of `s` on the hash ring.
+fn hash_point(s: &str) -> u32 {
+    let mut m = sha1::Sha1::new();
+    m.update(s.as_bytes());
+    u32::from_str_radix(&m.digest().to_string()[..8], 16).unwrap()
+}
+
+/// The key of chunk `index` of the entry with key `key`, written with
+/// `nonce`.
+fn chunk_key(key: &str, nonce: &str, index: usize) -> String {
+    format!("{}:{}:{}", key, nonce, index)
+}
+
+/// Make the item stored under an entry's own key, from the nonce its other
+/// chunks are stored under, the number of chunks, and the first chunk.
+fn make_header(nonce: &str, chunks: usize, first: &[u8]) -> Vec<u8> {
+    let mut header = format!("{} {}\n", nonce, chunks).into_bytes();
+    header.extend_from_slice(first);
+    header
+}
+
+/// Parse the item stored under an entry's own key, returning the nonce,
+/// the number of chunks, and the first chunk, or `None` if it's malformed.
+fn parse_header(item: &[u8]) -> Option<(&str, usize, &[u8])> {
+    let end = match item.iter().position(|&b| b == b'\n') {
+        Some(end) => end,
+        None => return None,
+    };
+    let line = match str::from_utf8(&item[..end]) {
+        Ok(line) => line,
+        Err(_) => return None,
+    };
+    let mut parts = line.splitn(2, ' ');
+    match (parts.next(), parts.next().and_then(|n| n.parse().ok())) {
+        (Some(nonce), Some(chunks)) if !nonce.is_empty() && chunks > 0 => {
+            Some((nonce, chunks, &item[end + 1..]))
+        }
+        _ => None,
+    }
+}
+
+impl Storage for MemcachedCache {
+    fn get(&self, key: &str) -> SFuture<Cache> {
+        trace!("MemcachedCache::get({})", key);
+        // All of an entry's chunks are on the same server as the entry.
+        let addr = self.server_for(key);
+        let header = self.conns.request(addr, vec!(Request::Get(vec!(key.to_owned()))));
+        let key = key.to_owned();
+        let conns = self.conns.clone();
+        let chunks = header.and_then(move |mut responses| -> SFuture<_> {
+            let item = match responses.pop() {
+                Some(Response::Values(mut values)) => values.remove(&key),
+                _ => None,
+            };
+            let item = match item {
+                Some(item) => item,
+                None => return future::ok(None).boxed(),
+            };
+            let (keys, mut data) = match parse_header(&item) {
+                Some((nonce, chunks, first)) => {
+                    ((1..chunks).map(|i| chunk_key(&key, nonce, i)).collect::<Vec<_>>(), first.to_vec())
+                }
+                None => {
+                    debug!("Malformed memcached entry header for {}", key);
+                    return future::ok(None).boxed()
+                }
+            };
+            if keys.is_empty() {
+                return future::ok(Some(data)).boxed()
+            }
+            Box::new(conns.request(addr, vec!(Request::Get(keys.clone()))).map(move |mut responses| {
+                match responses.pop() {
+                    Some(Response::Values(mut values)) => {
+                        for key in keys.iter() {
+                            match values.remove(key) {
+                                Some(chunk) => data.extend_from_slice(&chunk),
+                                // Some chunks were evicted.
+                                None => return None,
+                            }
+                        }
+                        Some(data)
+                    }
+                    _ => None,
+                }
+            }))
+        });
+        Box::new(chunks.then(|result| {
+            match result {
+                Ok(Some(data)) => {
+                    match CacheRead::from(io::Cursor::new(data)) {
+                        Ok(hit) => Ok(Cache::Hit(hit)),
+                        Err(e) => {
+                            warn!("Corrupt memcached entry: {:?}", e);
+                            Ok(Cache::Miss)
+                        }
+                    }
+                }
+                Ok(None) => Ok(Cache::Miss),
+                Err(e) => {
+                    warn!("Got memcached error: {:?}", e);
+                    Ok(Cache::Miss)
+                }
+            }
+        }))
+    }
+
+    fn start_put(&self, key: &str) -> Result<CacheWrite> {
+        trace!("MemcachedCache::start_put({})", key);
+        CacheWrite::new(self.codec)
+    }
+
+    fn finish_put(&self, key: &str, entry: CacheEntryFile) -> SFuture<Duration> {
+        trace!("MemcachedCache::finish_put({})", key);
+        let start = Instant::now();
+        let data = match entry.into_bytes() {
+            Ok(data) => data,
+            Err(e) => return future::err(e).boxed(),
+        };
+        // Chunks are stored under a fresh nonce, so a lookup racing with
+        // another write of the same entry can't take some chunks from each.
+        let nonce = Uuid::new_v4().simple().to_string();
+        let mut chunks = data.chunks(CHUNK_SIZE);
+        let first = chunks.next().unwrap_or(&[][..]);
+        let mut requests = chunks.enumerate()
+            .map(|(i, chunk)| Request::Set(chunk_key(key, &nonce, i + 1), chunk.to_vec()))
+            .collect::<Vec<_>>();
+        // Store the header last, so the entry doesn't show up before all of
+        // its chunks.
+        let count = requests.len() + 1;
+        requests.push(Request::Set(key.to_owned(), make_header(&nonce, count, first)));
+        Box::new(self.conns.request(self.server_for(key), requests).and_then(move |responses| -> Result<_> {
+            for response in responses {
+                match response {
+                    Response::Status(ref s) if s == "STORED" => {}
+                    r => bail!("unexpected memcached response: {:?}", r),
+                }
+            }
+            Ok(start.elapsed())
+        }))
+    }
+
+    fn location(&self) -> String {
+        let servers = self.servers.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        format!("Memcached: {}", servers.join(", "))
+    }
+
+    fn backend(&self) -> &'static str {
+        "memcached"
+    }
+
+    fn current_size(&self) -> Option<usize> { None }
+    fn max_size(&self) -> Option<usize> { None }
+}
+
+/// A memcached text protocol request.
+#[derive(Clone, Debug, PartialEq)]
+enum Request {
+    /// Get the items with these keys.
+    Get(Vec<String>),
+    /// Store an item.
+    Set(String, Vec<u8>),
+}
+
+/// A memcached text protocol response.
+#[derive(Debug, PartialEq)]
+enum Response {
+    /// The items found by a `get`, by key.
+    Values(HashMap<String, Vec<u8>>),
+    /// A status line, like `STORED`.
+    Status(String),
+    /// An error line.
+    Error(String),
+}
+
+/// tokio-core `Codec` for the memcached text protocol.
+///
+/// The values in a `get` response are removed from the buffer as each one
+/// arrives, so a large response is only parsed and copied once.
+struct MemcacheCodec {
+    /// The values of the `get` response being decoded, so far.
+    values: HashMap<String, Vec<u8>>,
+}
+
+impl MemcacheCodec {
+    fn new() -> MemcacheCodec {
+        MemcacheCodec {
+            values: HashMap::new(),
+        }
+    }
+}
+
+impl FrameCodec for MemcacheCodec {
+    type In = Response;
+    type Out = Request;
+
+    fn decode(&mut self, buf: &mut EasyBuf) -> io::Result<Option<Response>> {
+        loop {
+            let (part, size) = match parse_part(buf.as_slice())? {
+                Some(part) => part,
+                None => return Ok(None),
+            };
+            buf.drain_to(size);
+            let response = match part {
+                Part::Value(key, value) => {
+                    self.values.insert(key, value);
+                    continue
+                }
+                Part::End => Response::Values(mem::replace(&mut self.values, HashMap::new())),
+                _ if !self.values.is_empty() => {
+                    return Err(invalid_data("unexpected line in get response"))
+                }
+                Part::Error(line) => Response::Error(line),
+                Part::Status(line) => Response::Status(line),
+            };
+            return Ok(Some(response))
+        }
+    }
+
+    fn encode(&mut self, request: Request, buf: &mut Vec<u8>) -> io::Result<()> {
+        match request {
+            Request::Get(keys) => {
+                buf.extend_from_slice(format!("get {}\r\n", keys.join(" ")).as_bytes());
+            }
+            Request::Set(key, data) => {
+                buf.extend_from_slice(format!("set {} 0 0 {}\r\n", key, data.len()).as_bytes());
+                buf.extend_from_slice(&data);
+                buf.extend_from_slice(b"\r\n");
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Part of a memcached text protocol response.
+#[derive(Debug, PartialEq)]
+enum Part {
+    /// An item in a `get` response.
+    Value(String, Vec<u8>),
+    /// The end of a `get` response.
+    End,
+    /// A status line, like `STORED`.
+    Status(String),
+    /// An error line.
+    Error(String),
+}
+
+fn invalid_data(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+/// Parse a part of a response from the start of `buf`, returning it and the
+/// number of bytes it took up, or `None` if `buf` doesn't hold a complete
+/// part yet.
+fn parse_part(buf: &[u8]) -> io::Result<Option<(Part, usize)>> {
+    let end = match buf.windows(2).position(|w| w == b"\r\n") {
+        Some(end) => end,
+        None => return Ok(None),
+    };
+    let line = str::from_utf8(&buf[..end]).map_err(|_| invalid_data("response is not valid utf-8"))?;
+    let size = end + 2;
+    let part = if line == "END" {
+        Part::End
+    } else if line.starts_with("VALUE ") {
+        // VALUE <key> <flags> <bytes> [<cas unique>]
+        let parts = line.split(' ').collect::<Vec<_>>();
+        if parts.len() < 4 {
+            return Err(invalid_data("malformed VALUE line"))
+        }
+        let len: usize = parts[3].parse().map_err(|_| invalid_data("malformed VALUE line"))?;
+        if buf.len() < size + len + 2 {
+            return Ok(None)
+        }
+        let value = Part::Value(parts[1].to_owned(), buf[size..size + len].to_vec());
+        return Ok(Some((value, size + len + 2)))
+    } else if line == "ERROR" || line.starts_with("CLIENT_ERROR") || line.starts_with("SERVER_ERROR") {
+        Part::Error(line.to_owned())
+    } else {
+        Part::Status(line.to_owned())
+    };
+    Ok(Some((part, size)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::collections::HashMap;
+
+    /// Decode the responses in `data` with a `MemcacheCodec`, feeding it
+    /// `step` bytes at a time, and return them and the bytes left over.
+    fn decode(data: &[u8], step: usize) -> (Vec<Response>, usize) {
+        let mut codec = MemcacheCodec::new();
+        let mut buf = EasyBuf::new();
+        let mut responses = vec!();
+        for chunk in data.chunks(step) {
+            buf.get_mut().extend_from_slice(chunk);
+            while let Some(response) = codec.decode(&mut buf).unwrap() {
+                responses.push(response);
+            }
+        }
+        (responses, buf.len())
+    }
+
+    #[test]
+    fn test_decode_response() {
+        assert_eq!((vec!(Response::Status("STORED".to_owned())), 0),
+                   decode(b"STORED\r\n", 100));
+        assert_eq!((vec!(Response::Error("SERVER_ERROR object too large for cache".to_owned())), 0),
+                   decode(b"SERVER_ERROR object too large for cache\r\n", 100));
+        assert_eq!((vec!(Response::Values(HashMap::new())), 0),
+                   decode(b"END\r\n", 100));
+        let mut values = HashMap::new();
+        values.insert("a".to_owned(), b"x\r\ny".to_vec());
+        values.insert("b".to_owned(), b"".to_vec());
+        let data = b"VALUE a 0 4\r\nx\r\ny\r\nVALUE b 0 0\r\n\r\nEND\r\nSTORED\r\n";
+        let expected = vec!(Response::Values(values), Response::Status("STORED".to_owned()));
+        assert_eq!((expected, 0), decode(data, 100));
+    }
+
+    #[test]
+    fn test_decode_response_in_pieces() {
+        let mut values = HashMap::new();
+        values.insert("a".to_owned(), b"x\r\ny".to_vec());
+        values.insert("b".to_owned(), b"z".to_vec());
+        let data = b"VALUE a 0 4\r\nx\r\ny\r\nVALUE b 0 1\r\nz\r\nEND\r\n";
+        for step in 1..data.len() {
+            assert_eq!((vec!(Response::Values(values.clone())), 0), decode(data, step));
+        }
+    }
+
+    #[test]
+    fn test_decode_response_incomplete() {
+        assert_eq!((vec!(), 0), decode(b"", 100));
+        assert_eq!((vec!(), 6), decode(b"STORED", 100));
+        assert_eq!((vec!(), 16), decode(b"VALUE a 0 4\r\nx\r\n", 100));
+        // A complete value is taken from the buffer as soon as it arrives.
+        assert_eq!((vec!(), 0), decode(b"VALUE a 0 1\r\nx\r\n", 100));
+    }
+
+    #[test]
+    fn test_decode_unexpected_line() {
+        let mut codec = MemcacheCodec::new();
+        let mut buf = EasyBuf::from(b"VALUE a 0 1\r\nx\r\nSTORED\r\n".to_vec());
+        assert!(codec.decode(&mut buf).is_err());
+    }
+
+    #[test]
+    fn test_encode_request() {
+        let mut buf = vec!();
+        MemcacheCodec::new().encode(Request::Get(vec!("a".to_owned(), "b".to_owned())), &mut buf).unwrap();
+        MemcacheCodec::new().encode(Request::Set("a".to_owned(), b"xyz".to_vec()), &mut buf).unwrap();
+        assert_eq!(&b"get a b\r\nset a 0 0 3\r\nxyz\r\n"[..], &buf[..]);
+    }
+
+    #[test]
+    fn test_parse_header() {
+        let header = make_header("abc", 3, b"first\nchunk");
+        assert_eq!(Some(("abc", 3, &b"first\nchunk"[..])), parse_header(&header));
+        assert_eq!(Some(("abc", 1, &b""[..])), parse_header(b"abc 1\n"));
+        // Entries written before chunks had nonces just hold the count.
+        assert_eq!(None, parse_header(b"3"));
+        assert_eq!(None, parse_header(b"abc 0\n"));
+        assert_eq!(None, parse_header(b"abc x\n"));
+        assert_eq!(None, parse_header(b" 1\n"));
+    }
+
+    #[test]
+    fn test_consistent_hashing() {
+        let servers = ["10.0.0.1:11211", "10.0.0.2:11211", "10.0.0.3:11211"].iter()
+            .map(|s| s.parse().unwrap())
+            .collect::<Vec<SocketAddr>>();
+        let ring = make_ring(&servers);
+        let fewer = make_ring(&servers[..2]);
+        let keys = (0..100).map(|i| format!("key{}", i)).collect::<Vec<_>>();
+        // Every server gets some keys.
+        for index in 0..servers.len() {
+            assert!(keys.iter().any(|k| server_for(&ring, k) == index));
+        }
+        // Removing a server only moves the keys that were on it.
+        for key in keys.iter() {
+            let index = server_for(&ring, key);
+            if index < 2 {
+                assert_eq!(index, server_for(&fewer, key));
+            }
+        }
+    }
+}
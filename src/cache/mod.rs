@@ -14,6 +14,13 @@
 
 pub mod cache;
 pub mod disk;
+pub mod format;
+pub mod http;
+pub mod memcached;
+pub mod mode;
+pub mod redis;
 pub mod s3;
+pub mod tiered;
 
 pub use cache::cache::*;
+pub use cache::format::Codec;
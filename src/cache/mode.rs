@@ -0,0 +1,123 @@
+// Copyright 2017 Mozilla Foundation
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use cache::{
+    Cache,
+    CacheEntryFile,
+    CacheMode,
+    CacheWrite,
+    Storage,
+};
+use futures::future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use errors::*;
+
+/// A storage that only reads or only writes, as set by `SCCACHE_CACHE_MODE`.
+///
+/// Lookups in a write-only cache always miss, and putting entries in a
+/// read-only cache fails, whoever asks for them.
+pub struct ModeCache {
+    storage: Arc<Storage>,
+    mode: CacheMode,
+}
+
+impl ModeCache {
+    /// Create a new `ModeCache` using `storage` in `mode`.
+    pub fn new(storage: Arc<Storage>, mode: CacheMode) -> ModeCache {
+        ModeCache {
+            storage: storage,
+            mode: mode,
+        }
+    }
+}
+
+impl Storage for ModeCache {
+    fn get(&self, key: &str) -> SFuture<Cache> {
+        if !self.mode.can_read() {
+            trace!("ModeCache::get({}): cache is write-only", key);
+            return Box::new(future::ok(Cache::Miss))
+        }
+        self.storage.get(key)
+    }
+
+    fn start_put(&self, key: &str) -> Result<CacheWrite> {
+        self.storage.start_put(key)
+    }
+
+    fn finish_put(&self, key: &str, entry: CacheEntryFile) -> SFuture<Duration> {
+        if !self.mode.can_write() {
+            trace!("ModeCache::finish_put({}): cache is read-only", key);
+            return Box::new(future::err("cache is read-only".into()))
+        }
+        self.storage.finish_put(key, entry)
+    }
+
+    fn location(&self) -> String {
+        self.storage.location()
+    }
+
+    fn backend(&self) -> &'static str {
+        self.storage.backend()
+    }
+
+    fn current_size(&self) -> Option<usize> { self.storage.current_size() }
+    fn max_size(&self) -> Option<usize> { self.storage.max_size() }
+
+    fn tier_hits(&self) -> Vec<(&'static str, u64)> {
+        self.storage.tier_hits()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use cache::disk::DiskCache;
+    use futures_cpupool::CpuPool;
+    use std::io;
+    use tempdir::TempDir;
+    use tokio_core::reactor::Core;
+
+    fn put(storage: &Storage, core: &mut Core, key: &str) -> Result<Duration> {
+        let mut entry = storage.start_put(key).unwrap();
+        entry.put_object("obj", &mut io::Cursor::new(b"contents")).unwrap();
+        core.run(storage.finish_put(key, entry.finish().unwrap()))
+    }
+
+    fn hit(storage: &Storage, core: &mut Core, key: &str) -> bool {
+        match core.run(storage.get(key)).unwrap() {
+            Cache::Hit(_) => true,
+            _ => false,
+        }
+    }
+
+    #[test]
+    fn test_mode_cache() {
+        let mut core = Core::new().unwrap();
+        let pool = CpuPool::new(1);
+        let dir = TempDir::new("sccache_mode").unwrap();
+        let disk = Arc::new(DiskCache::new(&dir.path(), 1024 * 1024, &pool));
+        let read_only = ModeCache::new(disk.clone(), CacheMode::ReadOnly);
+        let write_only = ModeCache::new(disk.clone(), CacheMode::WriteOnly);
+
+        assert!(put(&read_only, &mut core, "abcdef").is_err());
+        assert!(!hit(&*disk, &mut core, "abcdef"));
+
+        put(&write_only, &mut core, "abcdef").unwrap();
+        assert!(hit(&*disk, &mut core, "abcdef"));
+        assert!(!hit(&write_only, &mut core, "abcdef"));
+        assert!(hit(&read_only, &mut core, "abcdef"));
+    }
+}
@@ -0,0 +1,510 @@
+// Copyright 2017 Mozilla Foundation
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use cache::{
+    Cache,
+    CacheEntryFile,
+    CacheRead,
+    CacheWrite,
+    Codec,
+    Storage,
+};
+use futures::{future, stream, Future, Sink, Stream};
+use std::cell::{Cell, RefCell};
+use std::io;
+use std::net::{SocketAddr, ToSocketAddrs};
+use std::rc::Rc;
+use std::str;
+use std::time::{Instant, Duration};
+use tokio_core::io::{Codec as FrameCodec, EasyBuf, Framed, Io};
+use tokio_core::net::TcpStream;
+use tokio_core::reactor::Handle;
+
+use errors::*;
+
+/// The port redis listens on by default.
+const DEFAULT_PORT: u16 = 6379;
+
+/// How often to refresh the server's memory usage and limit, in seconds.
+const INFO_INTERVAL_SECS: u64 = 30;
+
+/// A connection to a redis server.
+type Connection = Framed<TcpStream, RespCodec>;
+
+/// A cache that stores entries in a Redis server.
+///
+/// Connections are kept open once their requests are done, and reused for
+/// later ones.
+pub struct RedisCache {
+    /// The URL of the server, for display.
+    url: String,
+    /// The address of the server.
+    addr: SocketAddr,
+    /// The database number to store entries in.
+    db: u32,
+    /// Connections that aren't running any requests.
+    idle: Rc<RefCell<Vec<Connection>>>,
+    /// When `INFO memory` was last requested, if it has been.
+    info_requested: Cell<Option<Instant>>,
+    /// Memory used by the server, as of the last `INFO memory` response.
+    used_memory: Rc<Cell<Option<usize>>>,
+    /// The server's memory limit, as of the last `INFO memory` response.
+    max_memory: Rc<Cell<Option<usize>>>,
+    /// How entries are compressed.
+    codec: Codec,
+    /// Handle to the event loop that requests run on.
+    handle: Handle,
+}
+
+impl RedisCache {
+    /// Create a new `RedisCache` for the server at `url`, of the form
+    /// `redis://host[:port][/db]`, storing entries compressed with `codec`.
+    pub fn new(url: &str, codec: Codec, handle: &Handle) -> Result<RedisCache> {
+        let (host, port, db) = parse_url(url)?;
+        let addr = (host.as_str(), port).to_socket_addrs()
+            .chain_err(|| format!("failed to resolve `{}`", host))?
+            .next()
+            .ok_or_else(|| format!("no addresses found for `{}`", host))?;
+        Ok(RedisCache {
+            url: url.to_owned(),
+            addr: addr,
+            db: db,
+            idle: Rc::new(RefCell::new(vec!())),
+            info_requested: Cell::new(None),
+            used_memory: Rc::new(Cell::new(None)),
+            max_memory: Rc::new(Cell::new(None)),
+            codec: codec,
+            handle: handle.clone(),
+        })
+    }
+
+    /// Run `commands` on an idle connection, or a new one if there isn't
+    /// one, and return their replies.
+    ///
+    /// `INFO memory` is run as well, if the server's memory usage hasn't
+    /// been asked for in a while.
+    fn query(&self, mut commands: Vec<Command>) -> SFuture<Vec<Value>> {
+        let info = match self.info_requested.get() {
+            Some(time) => time.elapsed() >= Duration::from_secs(INFO_INTERVAL_SECS),
+            None => true,
+        };
+        if info {
+            self.info_requested.set(Some(Instant::now()));
+            commands.push(vec!(b"INFO".to_vec(), b"memory".to_vec()));
+        }
+        let addr = self.addr;
+        let db = self.db;
+        let handle = self.handle.clone();
+        let reused = self.idle.borrow_mut().pop();
+        let replies = match reused {
+            Some(conn) => {
+                // The server may have closed the connection while it was
+                // idle, so try a new one if it fails.
+                let retry = commands.clone();
+                Box::new(run_commands(conn, commands).or_else(move |e| {
+                    debug!("Failed to reuse redis connection: {}", e);
+                    connect(&addr, db, &handle).and_then(move |conn| run_commands(conn, retry))
+                })) as Box<Future<Item=_, Error=_>>
+            }
+            None => {
+                Box::new(connect(&addr, db, &handle).and_then(move |conn| run_commands(conn, commands)))
+            }
+        };
+        let idle = self.idle.clone();
+        let used_memory = self.used_memory.clone();
+        let max_memory = self.max_memory.clone();
+        let replies = replies.chain_err(|| "failed to talk to redis server");
+        Box::new(replies.and_then(move |(conn, mut replies)| -> Result<_> {
+            idle.borrow_mut().push(conn);
+            if info {
+                if let Some(Value::Data(info)) = replies.pop() {
+                    let info = String::from_utf8_lossy(&info);
+                    used_memory.set(parse_info(&info, "used_memory"));
+                    // A `maxmemory` of zero means there is no limit.
+                    max_memory.set(parse_info(&info, "maxmemory").and_then(|m| if m == 0 { None } else { Some(m) }));
+                }
+            }
+            for reply in replies.iter() {
+                if let Value::Error(ref e) = *reply {
+                    bail!("redis error: {}", e)
+                }
+            }
+            Ok(replies)
+        }))
+    }
+}
+
+/// Connect to the server at `addr`, and select the database `db`.
+fn connect(addr: &SocketAddr, db: u32, handle: &Handle) -> Box<Future<Item=Connection, Error=io::Error>> {
+    let conn = TcpStream::connect(addr, handle).map(|sock| sock.framed(RespCodec));
+    if db == 0 {
+        return Box::new(conn)
+    }
+    Box::new(conn.and_then(move |conn| {
+        let select = vec!(b"SELECT".to_vec(), db.to_string().into_bytes());
+        run_commands(conn, vec!(select))
+    }).and_then(|(conn, replies)| {
+        match replies.into_iter().next() {
+            Some(Value::Error(e)) => Err(io::Error::new(io::ErrorKind::Other, format!("redis error: {}", e))),
+            _ => Ok(conn),
+        }
+    }))
+}
+
+/// Send `commands` on `conn`, and return it along with their replies.
+fn run_commands(conn: Connection, commands: Vec<Command>)
+                -> Box<Future<Item=(Connection, Vec<Value>), Error=io::Error>> {
+    let count = commands.len();
+    let commands = stream::iter(commands.into_iter().map(Ok::<_, io::Error>));
+    Box::new(conn.send_all(commands).and_then(move |(conn, _)| {
+        stream::iter((0..count).map(Ok::<_, io::Error>)).fold((conn, vec!()), |(conn, mut replies), _| {
+            conn.into_future().map_err(|(e, _)| e).and_then(|(reply, conn)| {
+                match reply {
+                    Some(reply) => {
+                        replies.push(reply);
+                        Ok((conn, replies))
+                    }
+                    None => Err(io::Error::new(io::ErrorKind::UnexpectedEof, "redis server closed the connection")),
+                }
+            })
+        })
+    }))
+}
+
+/// Parse a `redis://host[:port][/db]` URL.
+fn parse_url(url: &str) -> Result<(String, u16, u32)> {
+    if !url.starts_with("redis://") {
+        bail!("unsupported redis URL `{}`", url)
+    }
+    let rest = &url["redis://".len()..];
+    let (authority, db) = match rest.find('/') {
+        Some(i) => (&rest[..i], &rest[i + 1..]),
+        None => (rest, ""),
+    };
+    let (host, port) = match authority.rfind(':') {
+        Some(i) => {
+            let port = authority[i + 1..].parse().chain_err(|| format!("invalid port in `{}`", url))?;
+            (&authority[..i], port)
+        }
+        None => (authority, DEFAULT_PORT),
+    };
+    if host.is_empty() {
+        bail!("missing host in `{}`", url)
+    }
+    let db = if db.is_empty() {
+        0
+    } else {
+        db.parse().chain_err(|| format!("invalid database in `{}`", url))?
+    };
+    Ok((host.to_owned(), port, db))
+}
+
+/// Find the numeric value of `field` in the output of `INFO`.
+fn parse_info(info: &str, field: &str) -> Option<usize> {
+    info.lines()
+        .filter_map(|line| {
+            let mut parts = line.trim_right().splitn(2, ':');
+            match (parts.next(), parts.next()) {
+                (Some(name), Some(value)) if name == field => value.parse().ok(),
+                _ => None,
+            }
+        })
+        .next()
+}
+
+impl Storage for RedisCache {
+    fn get(&self, key: &str) -> SFuture<Cache> {
+        trace!("RedisCache::get({})", key);
+        let command = vec!(b"GET".to_vec(), key.as_bytes().to_vec());
+        Box::new(self.query(vec!(command)).then(|result| {
+            match result.map(|mut replies| replies.pop()) {
+                Ok(Some(Value::Data(data))) => {
+                    match CacheRead::from(io::Cursor::new(data)) {
+                        Ok(hit) => Ok(Cache::Hit(hit)),
+                        Err(e) => {
+                            warn!("Corrupt redis entry: {:?}", e);
+                            Ok(Cache::Miss)
+                        }
+                    }
+                }
+                Ok(Some(Value::Nil)) => Ok(Cache::Miss),
+                Ok(reply) => {
+                    warn!("Unexpected redis reply: {:?}", reply);
+                    Ok(Cache::Miss)
+                }
+                Err(e) => {
+                    warn!("Got redis error: {:?}", e);
+                    Ok(Cache::Miss)
+                }
+            }
+        }))
+    }
+
+    fn start_put(&self, key: &str) -> Result<CacheWrite> {
+        trace!("RedisCache::start_put({})", key);
+        CacheWrite::new(self.codec)
+    }
+
+    fn finish_put(&self, key: &str, entry: CacheEntryFile) -> SFuture<Duration> {
+        trace!("RedisCache::finish_put({})", key);
+        let start = Instant::now();
+        let data = match entry.into_bytes() {
+            Ok(data) => data,
+            Err(e) => return future::err(e).boxed(),
+        };
+        let command = vec!(b"SET".to_vec(), key.as_bytes().to_vec(), data);
+        Box::new(self.query(vec!(command)).map(move |_| start.elapsed()))
+    }
+
+    fn location(&self) -> String {
+        format!("Redis: {}", self.url)
+    }
+
+    fn backend(&self) -> &'static str {
+        "redis"
+    }
+
+    fn current_size(&self) -> Option<usize> { self.used_memory.get() }
+    fn max_size(&self) -> Option<usize> { self.max_memory.get() }
+}
+
+/// A redis command, as a list of arguments.
+type Command = Vec<Vec<u8>>;
+
+/// A value in the redis protocol (RESP).
+#[derive(Debug, PartialEq)]
+enum Value {
+    /// A null bulk string or array.
+    Nil,
+    /// A simple string.
+    Status(String),
+    /// An error message.
+    Error(String),
+    /// An integer.
+    Int(i64),
+    /// A bulk string.
+    Data(Vec<u8>),
+    /// An array.
+    Array(Vec<Value>),
+}
+
+/// tokio-core `Codec` that sends redis commands and decodes the replies.
+struct RespCodec;
+
+impl FrameCodec for RespCodec {
+    type In = Value;
+    type Out = Command;
+
+    fn decode(&mut self, buf: &mut EasyBuf) -> io::Result<Option<Value>> {
+        match parse_value(buf.as_slice())? {
+            Some((value, size)) => {
+                buf.drain_to(size);
+                Ok(Some(value))
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn encode(&mut self, command: Command, buf: &mut Vec<u8>) -> io::Result<()> {
+        buf.extend_from_slice(format!("*{}\r\n", command.len()).as_bytes());
+        for arg in command {
+            buf.extend_from_slice(format!("${}\r\n", arg.len()).as_bytes());
+            buf.extend_from_slice(&arg);
+            buf.extend_from_slice(b"\r\n");
+        }
+        Ok(())
+    }
+}
+
+fn invalid_data(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+/// Parse a RESP value from the start of `buf`, returning it and the number
+/// of bytes it took up, or `None` if `buf` doesn't hold a complete value yet.
+fn parse_value(buf: &[u8]) -> io::Result<Option<(Value, usize)>> {
+    let end = match buf.windows(2).position(|w| w == b"\r\n") {
+        Some(end) => end,
+        None => return Ok(None),
+    };
+    if end == 0 {
+        return Err(invalid_data("empty RESP line"))
+    }
+    let line = str::from_utf8(&buf[1..end]).map_err(|_| invalid_data("RESP line is not valid utf-8"))?;
+    let mut size = end + 2;
+    let value = match buf[0] {
+        b'+' => Value::Status(line.to_owned()),
+        b'-' => Value::Error(line.to_owned()),
+        b':' => Value::Int(line.parse().map_err(|_| invalid_data("invalid RESP integer"))?),
+        b'$' => {
+            let len: i64 = line.parse().map_err(|_| invalid_data("invalid RESP length"))?;
+            if len < 0 {
+                Value::Nil
+            } else {
+                let len = len as usize;
+                if buf.len() < size + len + 2 {
+                    return Ok(None)
+                }
+                let data = buf[size..size + len].to_vec();
+                size += len + 2;
+                Value::Data(data)
+            }
+        }
+        b'*' => {
+            let count: i64 = line.parse().map_err(|_| invalid_data("invalid RESP length"))?;
+            if count < 0 {
+                Value::Nil
+            } else {
+                let mut values = vec!();
+                for _ in 0..count {
+                    match parse_value(&buf[size..])? {
+                        Some((value, used)) => {
+                            values.push(value);
+                            size += used;
+                        }
+                        None => return Ok(None),
+                    }
+                }
+                Value::Array(values)
+            }
+        }
+        _ => return Err(invalid_data("unknown RESP type")),
+    };
+    Ok(Some((value, size)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::net::{self, TcpListener};
+    use std::process::{Child, Command, Stdio};
+    use std::thread;
+    use tokio_core::reactor::Core;
+
+    /// A `redis-server` process, killed when dropped.
+    struct RedisServer(Child);
+
+    impl Drop for RedisServer {
+        fn drop(&mut self) {
+            drop(self.0.kill());
+            drop(self.0.wait());
+        }
+    }
+
+    /// Start a `redis-server` on a free port, and return it and the port,
+    /// or `None` if there's no `redis-server` on the PATH.
+    fn start_redis_server() -> Option<(RedisServer, u16)> {
+        let port = TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+        let child = Command::new("redis-server")
+            .args(&["--port", &port.to_string(), "--bind", "127.0.0.1", "--save", "", "--appendonly", "no"])
+            .stdout(Stdio::null())
+            .spawn();
+        let server = match child {
+            Ok(child) => RedisServer(child),
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => return None,
+            Err(e) => panic!("Failed to run redis-server: {}", e),
+        };
+        for _ in 0..100 {
+            if net::TcpStream::connect(("127.0.0.1", port)).is_ok() {
+                return Some((server, port))
+            }
+            thread::sleep(Duration::from_millis(50));
+        }
+        panic!("redis-server didn't start listening");
+    }
+
+    #[test]
+    fn test_redis_server() {
+        let (_server, port) = match start_redis_server() {
+            Some(server) => server,
+            None => {
+                warn!("redis-server not found, skipping");
+                return
+            }
+        };
+        let mut core = Core::new().unwrap();
+        let url = format!("redis://127.0.0.1:{}/1", port);
+        let cache = RedisCache::new(&url, Codec::Zstd, &core.handle()).unwrap();
+        match core.run(cache.get("key")).unwrap() {
+            Cache::Miss => {}
+            _ => panic!("Expected a cache miss"),
+        }
+        let mut entry = cache.start_put("key").unwrap();
+        entry.put_object("obj", &mut &b"hello"[..]).unwrap();
+        core.run(cache.finish_put("key", entry.finish().unwrap())).unwrap();
+        match core.run(cache.get("key")).unwrap() {
+            Cache::Hit(mut hit) => {
+                let mut contents = vec!();
+                hit.get_object("obj", &mut contents).unwrap();
+                assert_eq!(b"hello".to_vec(), contents);
+            }
+            _ => panic!("Expected a cache hit"),
+        }
+        // A truncated or corrupt value is a miss.
+        let set = vec!(b"SET".to_vec(), b"bad".to_vec(), b"\x89SCC".to_vec());
+        core.run(cache.query(vec!(set))).unwrap();
+        match core.run(cache.get("bad")).unwrap() {
+            Cache::Miss => {}
+            _ => panic!("Expected a cache miss"),
+        }
+        // The requests all ran on one connection.
+        assert_eq!(1, cache.idle.borrow().len());
+        assert!(cache.current_size().is_some());
+    }
+
+    #[test]
+    fn test_parse_url() {
+        assert_eq!(("localhost".to_owned(), 6379, 0), parse_url("redis://localhost").unwrap());
+        assert_eq!(("10.0.0.1".to_owned(), 1234, 0), parse_url("redis://10.0.0.1:1234/").unwrap());
+        assert_eq!(("cache".to_owned(), 6379, 3), parse_url("redis://cache/3").unwrap());
+        assert!(parse_url("http://localhost").is_err());
+        assert!(parse_url("redis://localhost:port").is_err());
+        assert!(parse_url("redis:///1").is_err());
+    }
+
+    #[test]
+    fn test_parse_info() {
+        let info = "# Memory\r\nused_memory:1024\r\nused_memory_human:1.00K\r\nmaxmemory:0\r\n";
+        assert_eq!(Some(1024), parse_info(info, "used_memory"));
+        assert_eq!(Some(0), parse_info(info, "maxmemory"));
+        assert_eq!(None, parse_info(info, "used_memory_rss"));
+    }
+
+    #[test]
+    fn test_parse_value() {
+        assert_eq!(Some((Value::Status("OK".to_owned()), 5)), parse_value(b"+OK\r\n").unwrap());
+        assert_eq!(Some((Value::Error("ERR bad".to_owned()), 10)), parse_value(b"-ERR bad\r\n").unwrap());
+        assert_eq!(Some((Value::Int(-3), 5)), parse_value(b":-3\r\n").unwrap());
+        assert_eq!(Some((Value::Nil, 5)), parse_value(b"$-1\r\n").unwrap());
+        assert_eq!(Some((Value::Data(b"a\r\nb".to_vec()), 10)), parse_value(b"$4\r\na\r\nb\r\n").unwrap());
+        assert_eq!(Some((Value::Array(vec!(Value::Int(1), Value::Data(b"x".to_vec()))), 15)),
+                   parse_value(b"*2\r\n:1\r\n$1\r\nx\r\n+extra").unwrap());
+        assert!(parse_value(b"?\r\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_value_incomplete() {
+        assert_eq!(None, parse_value(b"").unwrap());
+        assert_eq!(None, parse_value(b"+OK").unwrap());
+        assert_eq!(None, parse_value(b"$4\r\nab").unwrap());
+        assert_eq!(None, parse_value(b"*2\r\n:1\r\n").unwrap());
+    }
+
+    #[test]
+    fn test_encode_command() {
+        let mut buf = vec!();
+        RespCodec.encode(vec!(b"GET".to_vec(), b"key".to_vec()), &mut buf).unwrap();
+        assert_eq!(&b"*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n"[..], &buf[..]);
+    }
+}
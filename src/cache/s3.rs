@@ -14,11 +14,15 @@
 
 use cache::{
     Cache,
+    CacheEntryFile,
     CacheRead,
     CacheWrite,
+    Codec,
     Storage,
 };
-use futures::future::{self, Future};
+use futures::{stream, Future, Sink, Stream};
+use futures_cpupool::CpuPool;
+use hyper::{self, Body};
 use simples3::{
     AutoRefreshingProvider,
     Bucket,
@@ -28,8 +32,10 @@ use simples3::{
     Ssl,
 };
 use std::env;
-use std::io;
+use std::fs::File;
+use std::io::{self, Read};
 use std::rc::Rc;
+use std::result;
 use std::time::{Instant, Duration};
 use tokio_core::reactor::Handle;
 
@@ -41,11 +47,18 @@ pub struct S3Cache {
     bucket: Rc<Bucket>,
     /// Credentials provider.
     provider: AutoRefreshingProvider<ChainProvider>,
+    /// How entries are compressed.
+    codec: Codec,
+    /// Thread pool to read entries from disk while they're uploaded.
+    pool: CpuPool,
+    /// Handle to the event loop that uploads run on.
+    handle: Handle,
 }
 
 impl S3Cache {
-    /// Create a new `S3Cache` storing data in `bucket`.
-    pub fn new(bucket: &str, endpoint: &str, handle: &Handle) -> Result<S3Cache> {
+    /// Create a new `S3Cache` storing data in `bucket`, compressed with
+    /// `codec`.
+    pub fn new(bucket: &str, endpoint: &str, codec: Codec, pool: &CpuPool, handle: &Handle) -> Result<S3Cache> {
         let home = env::home_dir().ok_or("Couldn't find home directory")?;
         let profile_providers = vec![
             ProfileProvider::with_configuration(home.join(".aws").join("credentials"), "default"),
@@ -60,10 +73,47 @@ impl S3Cache {
         Ok(S3Cache {
             bucket: bucket,
             provider: provider,
+            codec: codec,
+            pool: pool.clone(),
+            handle: handle.clone(),
         })
     }
 }
 
+/// The size of the chunks entries are uploaded in.
+const UPLOAD_CHUNK_SIZE: usize = 64 * 1024;
+
+/// A request body that streams the contents of `entry`.
+///
+/// Each chunk is read on `pool`, but sent from the event loop behind
+/// `handle`, so a slow upload doesn't tie up a thread in the pool.
+fn file_body(entry: CacheEntryFile, pool: &CpuPool, handle: &Handle) -> Body {
+    let (tx, body) = Body::pair();
+    let pool = pool.clone();
+    // The entry is kept until the upload finishes, along with the file once
+    // it's open. Reading stops at the end of the file or at an error.
+    let chunks = stream::unfold(Some((entry, None)), move |state: Option<(CacheEntryFile, Option<File>)>| {
+        state.map(|(entry, file)| pool.spawn_fn(move || -> result::Result<_, ()> {
+            let chunk = file.map_or_else(|| File::open(entry.path()), Ok).and_then(|mut file| {
+                let mut chunk = vec![0; UPLOAD_CHUNK_SIZE];
+                let n = file.read(&mut chunk)?;
+                chunk.truncate(n);
+                Ok((chunk, file))
+            });
+            Ok(match chunk {
+                Ok((ref chunk, _)) if chunk.is_empty() => (None, None),
+                Ok((chunk, file)) => (Some(Ok(chunk)), Some((entry, Some(file)))),
+                Err(e) => (Some(Err(e)), None),
+            })
+        }))
+    }).filter_map(|chunk| chunk.map(|chunk| chunk.map(|c| c.into()).map_err(hyper::Error::Io)));
+    // Sending fails if the request was dropped, and then there's no point
+    // reading any more.
+    let upload = chunks.fold(tx, |tx, chunk| tx.send(chunk).map_err(|_| ()));
+    handle.spawn(upload.map(|_| ()));
+    body
+}
+
 fn normalize_key(key: &str) -> String {
     format!("{}/{}/{}/{}", &key[0..1], &key[1..2], &key[2..3], &key)
 }
@@ -86,24 +136,23 @@ impl Storage for S3Cache {
     }
 
     fn start_put(&self, _key: &str) -> Result<CacheWrite> {
-        // Just hand back an in-memory buffer.
-        Ok(CacheWrite::new())
+        CacheWrite::new(self.codec)
     }
 
-    fn finish_put(&self, key: &str, entry: CacheWrite) -> SFuture<Duration> {
+    fn finish_put(&self, key: &str, entry: CacheEntryFile) -> SFuture<Duration> {
         let key = normalize_key(&key);
         let start = Instant::now();
-        let data = match entry.finish() {
-            Ok(data) => data,
-            Err(e) => return future::err(e.into()).boxed(),
-        };
         let credentials = self.provider.credentials().chain_err(|| {
             "failed to get AWS credentials"
         });
 
         let bucket = self.bucket.clone();
+        let pool = self.pool.clone();
+        let handle = self.handle.clone();
         let response = credentials.and_then(move |credentials| {
-            bucket.put(&key, data, &credentials).chain_err(|| {
+            // Upload straight from the file rather than reading it all in.
+            let size = entry.stored_size();
+            bucket.put(&key, file_body(entry, &pool, &handle), size, &credentials).chain_err(|| {
                 "failed to put cache entry in s3"
             })
         });
@@ -115,6 +164,10 @@ impl Storage for S3Cache {
         format!("S3, bucket: {}", self.bucket)
     }
 
+    fn backend(&self) -> &'static str {
+        "s3"
+    }
+
     fn current_size(&self) -> Option<usize> { None }
     fn max_size(&self) -> Option<usize> { None }
 }
@@ -0,0 +1,208 @@
+// Copyright 2017 Mozilla Foundation
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use cache::{
+    Cache,
+    CacheEntryFile,
+    CacheWrite,
+    Storage,
+};
+use cache::disk::DiskCache;
+use futures::{future, Future};
+use futures_cpupool::CpuPool;
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::Duration;
+
+use errors::*;
+
+/// A cache that keeps a local `DiskCache` in front of a remote storage.
+///
+/// Lookups check the disk first, and entries found in the remote storage are
+/// copied to the disk so the next lookup doesn't need a network round-trip.
+/// New entries are written to both.
+pub struct TieredCache {
+    /// The local tier.
+    disk: DiskCache,
+    /// The remote tier.
+    remote: Arc<Storage>,
+    /// Thread pool to copy entries between tiers.
+    pool: CpuPool,
+    /// The number of hits served by the local tier.
+    disk_hits: Rc<Cell<u64>>,
+    /// The number of hits served by the remote tier.
+    remote_hits: Rc<Cell<u64>>,
+}
+
+impl TieredCache {
+    /// Create a new `TieredCache` checking `disk` before `remote`.
+    pub fn new(disk: DiskCache, remote: Arc<Storage>, pool: &CpuPool) -> TieredCache {
+        TieredCache {
+            disk: disk,
+            remote: remote,
+            pool: pool.clone(),
+            disk_hits: Rc::new(Cell::new(0)),
+            remote_hits: Rc::new(Cell::new(0)),
+        }
+    }
+}
+
+impl Storage for TieredCache {
+    fn get(&self, key: &str) -> SFuture<Cache> {
+        trace!("TieredCache::get({})", key);
+        let key = key.to_owned();
+        let disk = self.disk.clone();
+        let remote = self.remote.clone();
+        let pool = self.pool.clone();
+        let disk_hits = self.disk_hits.clone();
+        let remote_hits = self.remote_hits.clone();
+        Box::new(self.disk.get(&key).then(move |result| -> SFuture<Cache> {
+            match result {
+                Ok(Cache::Hit(hit)) => {
+                    disk_hits.set(disk_hits.get() + 1);
+                    return Box::new(future::ok(Cache::Hit(hit)))
+                }
+                Ok(_) => {}
+                Err(e) => warn!("Failed to read from the local cache: {:?}", e),
+            }
+            Box::new(remote.get(&key).and_then(move |cache| -> SFuture<Cache> {
+                let mut hit = match cache {
+                    Cache::Hit(hit) => hit,
+                    cache => return Box::new(future::ok(cache)),
+                };
+                remote_hits.set(remote_hits.get() + 1);
+                let local = disk.clone();
+                let copy_key = key.clone();
+                let copy = pool.spawn_fn(move || -> Result<_> {
+                    let mut entry = local.start_put(&copy_key)?;
+                    hit.copy_to(&mut entry)?;
+                    Ok((hit, entry.finish()?))
+                });
+                Box::new(copy.and_then(move |(hit, entry)| {
+                    debug!("Copying {} to the local cache", key);
+                    disk.finish_put(&key, entry).then(move |result| {
+                        if let Err(e) = result {
+                            warn!("Failed to write to the local cache: {:?}", e);
+                        }
+                        Ok::<_, Error>(Cache::Hit(hit))
+                    })
+                }))
+            }))
+        }))
+    }
+
+    fn start_put(&self, key: &str) -> Result<CacheWrite> {
+        trace!("TieredCache::start_put({})", key);
+        // Write next to the local tier, so the entry can be moved into it.
+        self.disk.start_put(key)
+    }
+
+    fn finish_put(&self, key: &str, entry: CacheEntryFile) -> SFuture<Duration> {
+        trace!("TieredCache::finish_put({})", key);
+        // The local tier takes the entry away, and it may be a directory of
+        // objects, so give the remote tier a copy in its own format.
+        let mut copy = match self.remote.start_put(key) {
+            Ok(copy) => copy,
+            Err(e) => return Box::new(future::err(e)),
+        };
+        let copy = self.pool.spawn_fn(move || -> Result<_> {
+            entry.read()?.copy_to(&mut copy)?;
+            Ok((entry, copy.finish()?))
+        });
+        let key = key.to_owned();
+        let disk = self.disk.clone();
+        let remote = self.remote.clone();
+        Box::new(copy.and_then(move |(entry, copy)| {
+            let local = disk.finish_put(&key, entry).then(|result| {
+                if let Err(e) = result {
+                    warn!("Failed to write to the local cache: {:?}", e);
+                }
+                Ok::<_, Error>(())
+            });
+            remote.finish_put(&key, copy).join(local).map(|(duration, ())| duration)
+        }))
+    }
+
+    fn location(&self) -> String {
+        format!("{}, in front of {}", self.disk.location(), self.remote.location())
+    }
+
+    fn backend(&self) -> &'static str {
+        "tiered"
+    }
+
+    fn current_size(&self) -> Option<usize> { self.disk.current_size() }
+    fn max_size(&self) -> Option<usize> { self.disk.max_size() }
+
+    fn tier_hits(&self) -> Vec<(&'static str, u64)> {
+        vec!(("Cache hits from local disk", self.disk_hits.get()),
+             ("Cache hits from remote storage", self.remote_hits.get()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io;
+    use std::sync::Arc;
+    use tempdir::TempDir;
+    use tokio_core::reactor::Core;
+
+    fn put(storage: &Storage, core: &mut Core, key: &str, contents: &[u8]) {
+        let mut entry = storage.start_put(key).unwrap();
+        entry.put_object("obj", &mut io::Cursor::new(contents)).unwrap();
+        core.run(storage.finish_put(key, entry.finish().unwrap())).unwrap();
+    }
+
+    fn get(storage: &Storage, core: &mut Core, key: &str) -> Option<Vec<u8>> {
+        match core.run(storage.get(key)).unwrap() {
+            Cache::Hit(mut hit) => {
+                let mut contents = vec!();
+                hit.get_object("obj", &mut contents).unwrap();
+                Some(contents)
+            }
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_tiered_cache() {
+        let mut core = Core::new().unwrap();
+        let pool = CpuPool::new(1);
+        let local = TempDir::new("sccache_local").unwrap();
+        let remote_dir = TempDir::new("sccache_remote").unwrap();
+        let disk = DiskCache::new(&local.path(), 1024 * 1024, &pool);
+        let remote = Arc::new(DiskCache::new(&remote_dir.path(), 1024 * 1024, &pool));
+        let tiered = TieredCache::new(disk.clone(), remote.clone(), &pool);
+
+        // Entries written to the tiered cache end up in both tiers.
+        put(&tiered, &mut core, "abcdef", b"both");
+        assert_eq!(Some(b"both".to_vec()), get(&disk, &mut core, "abcdef"));
+        assert_eq!(Some(b"both".to_vec()), get(&*remote, &mut core, "abcdef"));
+
+        // Hits in the remote tier get copied to the local one.
+        put(&*remote, &mut core, "012345", b"remote");
+        assert_eq!(None, get(&disk, &mut core, "012345"));
+        assert_eq!(Some(b"remote".to_vec()), get(&tiered, &mut core, "012345"));
+        assert_eq!(vec!(("Cache hits from local disk", 0), ("Cache hits from remote storage", 1)),
+                   tiered.tier_hits());
+        assert_eq!(Some(b"remote".to_vec()), get(&disk, &mut core, "012345"));
+        assert_eq!(Some(b"remote".to_vec()), get(&tiered, &mut core, "012345"));
+        assert_eq!(vec!(("Cache hits from local disk", 1), ("Cache hits from remote storage", 1)),
+                   tiered.tier_hits());
+
+        assert_eq!(None, get(&tiered, &mut core, "999999"));
+    }
+}
@@ -24,6 +24,7 @@ use protocol::{
     ServerResponse,
 };
 use retry::retry;
+use std::fmt;
 use std::io::{
     self,
     BufReader,
@@ -32,23 +33,58 @@ use std::io::{
     Write,
 };
 use std::net::TcpStream;
+#[cfg(unix)]
+use std::os::unix::net::UnixStream;
+#[cfg(unix)]
+use std::path::{Path, PathBuf};
+
+/// Where an sccache server listens for connections.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ServerAddress {
+    /// A TCP port on localhost.
+    Tcp(u16),
+    /// A Unix domain socket.
+    #[cfg(unix)]
+    Unix(PathBuf),
+}
+
+impl fmt::Display for ServerAddress {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ServerAddress::Tcp(port) => write!(f, "127.0.0.1:{}", port),
+            #[cfg(unix)]
+            ServerAddress::Unix(ref path) => write!(f, "{}", path.display()),
+        }
+    }
+}
 
 /// A connection to an sccache server.
 pub struct ServerConnection {
     /// A reader for the socket connected to the server.
-    reader : BufReader<TcpStream>,
+    reader : BufReader<Box<Read>>,
     /// A writer for the socket connected to the server.
-    writer : BufWriter<TcpStream>,
+    writer : BufWriter<Box<Write>>,
 }
 
 impl ServerConnection {
     /// Create a new connection using `stream`.
     pub fn new(stream : TcpStream) -> io::Result<ServerConnection> {
         let writer = try!(stream.try_clone());
-        Ok(ServerConnection {
-            reader : BufReader::new(stream),
+        Ok(ServerConnection::from_streams(Box::new(stream), Box::new(writer)))
+    }
+
+    /// Create a new connection using the Unix socket `stream`.
+    #[cfg(unix)]
+    pub fn new_unix(stream : UnixStream) -> io::Result<ServerConnection> {
+        let writer = try!(stream.try_clone());
+        Ok(ServerConnection::from_streams(Box::new(stream), Box::new(writer)))
+    }
+
+    fn from_streams(reader : Box<Read>, writer : Box<Write>) -> ServerConnection {
+        ServerConnection {
+            reader : BufReader::new(reader),
             writer : BufWriter::new(writer),
-        })
+        }
     }
 
     /// Send `request` to the server, read and return a `ServerResponse`.
@@ -77,26 +113,95 @@ impl ServerConnection {
     }
 }
 
-/// Establish a TCP connection to an sccache server listening on `port`.
-pub fn connect_to_server(port: u16) -> io::Result<ServerConnection> {
-    trace!("connect_to_server({})", port);
-    let stream = try!(TcpStream::connect(("127.0.0.1", port)));
-    ServerConnection::new(stream)
+/// Establish a connection to an sccache server listening on `addr`.
+pub fn connect_to_server(addr: &ServerAddress) -> io::Result<ServerConnection> {
+    trace!("connect_to_server({})", addr);
+    match *addr {
+        ServerAddress::Tcp(port) => {
+            let stream = try!(TcpStream::connect(("127.0.0.1", port)));
+            ServerConnection::new(stream)
+        }
+        #[cfg(unix)]
+        ServerAddress::Unix(ref path) => {
+            let stream = try!(UnixStream::connect(path));
+            ServerConnection::new_unix(stream)
+        }
+    }
 }
 
-/// Attempt to establish a TCP connection to an sccache server listening on `port`.
+/// Attempt to establish a connection to an sccache server listening on `addr`.
 ///
 /// If the connection fails, retry a few times.
-pub fn connect_with_retry(port: u16) -> io::Result<ServerConnection> {
-    trace!("connect_with_retry({})", port);
+pub fn connect_with_retry(addr: &ServerAddress) -> io::Result<ServerConnection> {
+    trace!("connect_with_retry({})", addr);
     // TODOs:
     // * Pass the server Child in here, so we can stop retrying
     //   if the process exited.
     // * Send a pipe handle to the server process so it can notify
     //   us once it starts the server instead of us polling.
-    match retry(10, 500, || connect_to_server(port), |res| res.is_ok()) {
+    match retry(10, 500, || connect_to_server(addr), |res| res.is_ok()) {
         Ok(Ok(conn)) => Ok(conn),
         _ => Err(io::Error::new(io::ErrorKind::TimedOut,
                                 "Connection to server timed out")),
     }
 }
+
+/// The default path of the server's Unix socket, in a directory only the
+/// current user can access.
+///
+/// This is `sccache/server.sock` under `$XDG_RUNTIME_DIR` if it's set, or
+/// under a per-user directory in the temp dir otherwise.
+#[cfg(unix)]
+pub fn default_socket_path() -> io::Result<PathBuf> {
+    use libc;
+    use std::env;
+
+    let dir = match env::var_os("XDG_RUNTIME_DIR") {
+        Some(dir) => PathBuf::from(dir).join("sccache"),
+        None => env::temp_dir().join(format!("sccache-{}", unsafe { libc::getuid() })),
+    };
+    try!(create_private_dir(&dir));
+    Ok(dir.join("server.sock"))
+}
+
+/// Create the directory `dir` if it doesn't exist, accessible only by the
+/// current user, and make sure nobody else can get at it if it does.
+#[cfg(unix)]
+fn create_private_dir(dir: &Path) -> io::Result<()> {
+    use libc;
+    use std::fs::{self, DirBuilder};
+    use std::os::unix::fs::{DirBuilderExt, MetadataExt};
+
+    match DirBuilder::new().mode(0o700).create(dir) {
+        Ok(()) => {}
+        Err(ref e) if e.kind() == io::ErrorKind::AlreadyExists => {}
+        Err(e) => return Err(e),
+    }
+    let meta = try!(fs::symlink_metadata(dir));
+    if !meta.is_dir() || meta.uid() != unsafe { libc::getuid() } || meta.mode() & 0o077 != 0 {
+        return Err(io::Error::new(io::ErrorKind::PermissionDenied,
+                                  format!("{} is not a private directory", dir.display())))
+    }
+    Ok(())
+}
+
+#[cfg(all(test, unix))]
+mod test {
+    use super::*;
+    use std::fs;
+    use std::os::unix::fs::PermissionsExt;
+    use tempdir::TempDir;
+
+    #[test]
+    fn test_create_private_dir() {
+        let td = TempDir::new("sccache").unwrap();
+        let dir = td.path().join("private");
+        create_private_dir(&dir).unwrap();
+        assert_eq!(0o700, fs::metadata(&dir).unwrap().permissions().mode() & 0o777);
+        // Existing private directories are fine.
+        create_private_dir(&dir).unwrap();
+        // Directories others can get at are not.
+        fs::set_permissions(&dir, fs::Permissions::from_mode(0o755)).unwrap();
+        assert!(create_private_dir(&dir).is_err());
+    }
+}
@@ -23,18 +23,31 @@ use std::ffi::OsString;
 use std::path::PathBuf;
 use which::which_in;
 
+/// How to print cache statistics.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StatsFormat {
+    /// A table for people to read.
+    Text,
+    /// JSON, for programs to read.
+    Json,
+}
+
 /// A specific command to run.
 pub enum Command {
     /// Show cache statistics and exit.
-    ShowStats,
+    ShowStats(StatsFormat),
     /// Zero cache statistics and exit.
-    ZeroStats,
+    ZeroStats(StatsFormat),
+    /// Show the effective configuration and exit.
+    ShowConfig,
     /// Run background server.
     InternalStartServer,
     /// Start background server as a subprocess.
     StartServer,
     /// Stop background server.
-    StopServer,
+    StopServer(StatsFormat),
+    /// Have the server write the timeline of the build so far.
+    DumpTrace,
     /// Run a compiler command.
     Compile {
         /// The binary to execute.
@@ -55,7 +68,14 @@ pub fn get_app<'a, 'b>() -> App<'a, 'b> {
             "-s --show-stats 'show cache statistics'
              -z, --zero-stats 'zero statistics counters'
              --start-server  'start background server'
-             --stop-server   'stop background server'"
+             --stop-server   'stop background server'
+             --dump-trace    'write the timeline of the build to the file set by SCCACHE_TIMELINE'
+             --show-config   'show the effective configuration'
+             --config [FILE] 'read settings from FILE instead of the default config file'"
+                )
+        .arg(
+            Arg::from_usage("--stats-format [FORMAT] 'print statistics as text or json'")
+                .possible_values(&["text", "json"])
                 )
         .arg(
             Arg::with_name("cmd")
@@ -109,6 +129,17 @@ pub fn parse() -> Result<Command> {
     let start_server = matches.is_present("start-server");
     let stop_server = matches.is_present("stop-server");
     let zero_stats = matches.is_present("zero-stats");
+    let show_config = matches.is_present("show-config");
+    let dump_trace = matches.is_present("dump-trace");
+    let stats_format = match matches.value_of("stats-format") {
+        Some("json") => StatsFormat::Json,
+        _ => StatsFormat::Text,
+    };
+    // The server inherits our environment when we start it, so this is how
+    // it gets to see the config file too.
+    if let Some(config) = matches.value_of_os("config") {
+        env::set_var("SCCACHE_CONF", cwd.join(config));
+    }
     let cmd = matches.values_of_os("cmd");
     // Ensure that we've only received one command to run.
     fn is_some<T>(x : &Option<T>) -> bool {
@@ -119,6 +150,8 @@ pub fn parse() -> Result<Command> {
         show_stats,
         start_server,
         stop_server,
+        show_config,
+        dump_trace,
         is_some(&cmd),
             ].iter()
         .fold(0, |acc, &x| acc + (x as usize)) > 1 {
@@ -127,13 +160,17 @@ pub fn parse() -> Result<Command> {
     if internal_start_server {
         Ok(Command::InternalStartServer)
     } else if show_stats {
-        Ok(Command::ShowStats)
+        Ok(Command::ShowStats(stats_format))
     } else if start_server {
         Ok(Command::StartServer)
     } else if stop_server {
-        Ok(Command::StopServer)
+        Ok(Command::StopServer(stats_format))
     } else if zero_stats {
-        Ok(Command::ZeroStats)
+        Ok(Command::ZeroStats(stats_format))
+    } else if show_config {
+        Ok(Command::ShowConfig)
+    } else if dump_trace {
+        Ok(Command::DumpTrace)
     } else if let Some(mut args) = cmd {
         if let Some(exe) = args.next() {
             let cmdline = args.map(|s| s.to_owned()).collect::<Vec<_>>();
@@ -12,15 +12,28 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(unix)]
+use client::default_socket_path;
 use client::{
     connect_to_server,
     connect_with_retry,
+    ServerAddress,
     ServerConnection,
 };
-use cmdline::Command;
+use cmdline::{Command, StatsFormat};
 use compiler::{
     run_input_output,
 };
+use config::{
+    Config,
+    SETTINGS,
+    Source,
+};
+use jobserver::{
+    self,
+    Jobserver,
+    LentToken,
+};
 use log::LogLevel::Trace;
 use mock_command::{
     CommandCreatorSync,
@@ -34,16 +47,22 @@ use number_prefix::{
 };
 use protobuf::{RepeatedField, ProtobufError};
 use protocol::{
+    CacheStatistic,
     CacheStats,
+    CacheStatsBreakdown,
     ClientRequest,
     Compile,
     CompileFinished,
     CompileStarted,
+    DumpTrace,
     GetStats,
     Shutdown,
+    TraceDumped,
     UnhandledCompile,
     ZeroStats,
 };
+use retry::retry;
+use serde_json::{self, Map, Value};
 use server;
 use std::env;
 use std::ffi::{OsStr,OsString};
@@ -89,13 +108,41 @@ enum ServerStartup {
 }
 
 /// Get the port on which the server should listen.
-fn get_port() -> u16 {
-    env::var("SCCACHE_SERVER_PORT")
-        .ok()
+fn get_port(config: &Config) -> u16 {
+    config.get("SCCACHE_SERVER_PORT")
         .and_then(|s| s.parse().ok())
         .unwrap_or(DEFAULT_PORT)
 }
 
+/// Get the address on which the server should listen, a TCP port unless
+/// `SCCACHE_SERVER_TRANSPORT` is set to `unix`.
+fn get_server_address(config: &Config) -> Result<ServerAddress> {
+    match config.get("SCCACHE_SERVER_TRANSPORT") {
+        None => Ok(ServerAddress::Tcp(get_port(config))),
+        Some(ref transport) if transport == "tcp" => Ok(ServerAddress::Tcp(get_port(config))),
+        Some(ref transport) if transport == "unix" => get_unix_server_address(config),
+        Some(transport) => bail!("unknown SCCACHE_SERVER_TRANSPORT `{}`", transport),
+    }
+}
+
+/// Get the Unix socket on which the server should listen, from
+/// `SCCACHE_SERVER_SOCKET` or in the user's runtime dir.
+#[cfg(unix)]
+fn get_unix_server_address(config: &Config) -> Result<ServerAddress> {
+    use std::path::PathBuf;
+
+    let path = match config.get("SCCACHE_SERVER_SOCKET") {
+        Some(path) => PathBuf::from(path),
+        None => default_socket_path().chain_err(|| "failed to create the server socket directory")?,
+    };
+    Ok(ServerAddress::Unix(path))
+}
+
+#[cfg(not(unix))]
+fn get_unix_server_address(_config: &Config) -> Result<ServerAddress> {
+    bail!("Unix sockets are not supported on this platform")
+}
+
 /// Re-execute the current executable as a background server, and wait
 /// for it to start up.
 #[cfg(not(windows))]
@@ -293,24 +340,102 @@ fn run_server_process() -> Result<ServerStartup> {
     }
 }
 
-/// Attempt to connect to an sccache server listening on `port`, or start one if no server is running.
-fn connect_or_start_server(port: u16) -> Result<ServerConnection> {
-    trace!("connect_or_start_server({})", port);
-    match connect_to_server(port) {
-        Ok(server) => Ok(server),
+/// Attempt to connect to an sccache server listening on `addr`, or start one if no server is running.
+fn connect_or_start_server(addr: &ServerAddress) -> Result<ServerConnection> {
+    trace!("connect_or_start_server({})", addr);
+    match connect_to_server(addr) {
+        Ok(server) => server,
         Err(ref e) if e.kind() == io::ErrorKind::ConnectionRefused ||
-                      e.kind() == io::ErrorKind::TimedOut => {
-            // If the connection was refused we probably need to start
-            // the server.
+                      e.kind() == io::ErrorKind::TimedOut ||
+                      e.kind() == io::ErrorKind::NotFound => {
+            // If the connection was refused, or there's no socket, we
+            // probably need to start the server.
             //TODO: check startup value!
             let _startup = run_server_process()?;
-            let server = connect_with_retry(port)?;
-            Ok(server)
+            Ok(connect_with_retry(addr)?)
         }
         Err(e) => Err(e.into())
     }
 }
 
+/// Like `connect_or_start_server`, but make sure the server is running
+/// the same version of sccache as this client first.
+///
+/// Compile requests carry the client's version instead, so they don't need
+/// an extra round trip.
+fn connect_or_start_current_server(addr: &ServerAddress) -> Result<ServerConnection> {
+    let mut server = connect_or_start_server(addr)?;
+    if !check_server_version(&mut server)? {
+        shut_down_other_version(server)?;
+        server = restart_server(addr)?;
+    }
+    Ok(server)
+}
+
+/// Ask the server on `conn`, which is running a different version of
+/// sccache, to shut down.
+fn shut_down_other_version(conn: ServerConnection) -> Result<()> {
+    request_shutdown(conn).chain_err(|| {
+        "failed to shut down server of a different version"
+    })?;
+    Ok(())
+}
+
+/// Replace the server listening on `addr`, which is running a different
+/// version of sccache and has been asked to shut down, with one of our own,
+/// and connect to it.
+///
+/// A server of a different version may not understand us, or may use
+/// different cache keys.
+fn restart_server(addr: &ServerAddress) -> Result<ServerConnection> {
+    info!("Restarting sccache server, which is a different version");
+    wait_for_server_exit(addr)?;
+    let _startup = run_server_process()?;
+    Ok(connect_with_retry(addr)?)
+}
+
+/// Send a `Hello` request to the server, and return whether it's running
+/// the same version of sccache as this client.
+pub fn check_server_version(conn: &mut ServerConnection) -> Result<bool> {
+    debug!("check_server_version");
+    let ours = server::hello();
+    let mut req = ClientRequest::new();
+    req.set_hello(ours.clone());
+    let mut response = conn.request(req).chain_err(|| {
+        "failed to send version to server or failed to receive response"
+    })?;
+    // Servers from before the handshake don't know the request at all.
+    if !response.has_hello() {
+        debug!("Server doesn't know its version");
+        return Ok(false)
+    }
+    let theirs = response.take_hello();
+    debug!("Server version {} (cache version {})",
+           theirs.get_version(), theirs.get_cache_version());
+    Ok(server::same_version(&theirs, &ours))
+}
+
+/// Wait for the server listening on `addr`, which has been asked to shut
+/// down, to go away.
+fn wait_for_server_exit(addr: &ServerAddress) -> Result<()> {
+    // A server stops accepting connections as soon as it starts shutting
+    // down, but only removes its Unix socket once it's done, so wait for
+    // that rather than have it remove the new server's socket.
+    let gone = |res: &io::Result<ServerConnection>| {
+        match (addr, res) {
+            (&ServerAddress::Tcp(_), &Err(ref e)) => e.kind() == io::ErrorKind::ConnectionRefused,
+            #[cfg(unix)]
+            (&ServerAddress::Unix(_), &Err(ref e)) => e.kind() == io::ErrorKind::NotFound,
+            (_, &Ok(_)) => false,
+        }
+    };
+    // The server waits up to 10 seconds for other clients to finish.
+    match retry(30, 500, || connect_to_server(addr), gone) {
+        Ok(_) => Ok(()),
+        Err(_) => bail!("timed out waiting for server of a different version to shut down"),
+    }
+}
+
 /// Send a `ZeroStats` request to the server, and return the `CacheStats` request if successful.
 pub fn request_zero_stats(mut conn : ServerConnection) -> Result<CacheStats> {
     debug!("request_stats");
@@ -327,6 +452,21 @@ pub fn request_zero_stats(mut conn : ServerConnection) -> Result<CacheStats> {
     }
 }
 
+/// Send a `DumpTrace` request to the server, and return the `TraceDumped` response if successful.
+pub fn request_dump_trace(mut conn : ServerConnection) -> Result<TraceDumped> {
+    debug!("request_dump_trace");
+    let mut req = ClientRequest::new();
+    req.set_dump_trace(DumpTrace::new());
+    let mut response = conn.request(req).chain_err(|| {
+        "Failed to send data to or receive data from server"
+    })?;
+    if response.has_trace_dumped() {
+        Ok(response.take_trace_dumped())
+    } else {
+        bail!("Unexpected server response!")
+    }
+}
+
 /// Send a `GetStats` request to the server, and return the `CacheStats` request if successful.
 pub fn request_stats(mut conn : ServerConnection) -> Result<CacheStats> {
     debug!("request_stats");
@@ -359,32 +499,162 @@ pub fn request_shutdown(mut conn : ServerConnection) -> Result<CacheStats> {
     }
 }
 
-/// Print `stats` to stdout.
-fn print_stats(stats: CacheStats) -> Result<()> {
+/// Print `stats` to stdout in `format`.
+fn print_stats(stats: CacheStats, format: StatsFormat) -> Result<()> {
+    match format {
+        StatsFormat::Text => print_stats_text(stats),
+        StatsFormat::Json => {
+            let json = serde_json::to_string_pretty(&stats_to_json(&stats)).chain_err(|| {
+                "failed to serialize stats"
+            })?;
+            println!("{}", json);
+            Ok(())
+        }
+    }
+}
+
+/// Format the value of the statistic `s` for people to read.
+fn format_stat(s: &CacheStatistic) -> String {
+    if s.has_count() {
+        format!("{}", s.get_count())
+    } else if s.has_str() {
+        s.get_str().to_owned()
+    } else if s.has_size() {
+        match binary_prefix(s.get_size() as f64) {
+            Standalone(bytes) => format!("{} bytes", bytes),
+            Prefixed(prefix, n) => format!("{:.0} {}B", n, prefix),
+        }
+    } else if s.has_duration() {
+        format_nanos(s.get_duration())
+    } else if s.has_histogram() {
+        let h = s.get_histogram();
+        format!("p50 {}, p90 {}, p99 {} ({})",
+                format_nanos(h.get_p50()), format_nanos(h.get_p90()), format_nanos(h.get_p99()),
+                h.get_count())
+    } else {
+        String::from("???")
+    }
+}
+
+/// Format the duration `nanos` in seconds, to the millisecond.
+fn format_nanos(nanos: u64) -> String {
+    format!("{}.{:03} s", nanos / 1_000_000_000, nanos % 1_000_000_000 / 1_000_000)
+}
+
+/// Print `stats` to stdout as a table, followed by a table for each
+/// breakdown of the statistics.
+fn print_stats_text(stats: CacheStats) -> Result<()> {
     let formatted = stats.get_stats().iter()
-        .map(|s| (s.get_name(), if s.has_count() {
-            format!("{}", s.get_count())
+        .map(|s| (s.get_name().to_owned(), format_stat(s)))
+        .collect::<Vec<_>>();
+    let breakdowns = stats.get_breakdowns().iter()
+        .map(|b| {
+            let title = format!("Stats for {} {}:", b.get_kind(), b.get_value());
+            let stats = b.get_stats().iter()
+                .map(|s| (format!("  {}", s.get_name()), format_stat(s)))
+                .collect::<Vec<_>>();
+            (title, stats)
+        })
+        .collect::<Vec<_>>();
+    let rows = || formatted.iter().chain(breakdowns.iter().flat_map(|&(_, ref stats)| stats.iter()));
+    let name_width = rows().map(|&(ref n, _)| n.len()).max().unwrap();
+    let stat_width = rows().map(|&(_, ref s)| s.len()).max().unwrap();
+    let print_rows = |rows: &[(String, String)]| {
+        for &(ref name, ref stat) in rows {
+            println!("{:<name_width$} {:>stat_width$}", name, stat, name_width=name_width, stat_width=stat_width);
+        }
+    };
+    print_rows(&formatted[..]);
+    for &(ref title, ref stats) in &breakdowns {
+        println!("");
+        println!("{}", title);
+        print_rows(&stats[..]);
+    }
+    Ok(())
+}
+
+/// Convert `stats` to a JSON array with an object per statistic, holding
+/// its `name` and its value under the name of its type: `count`, `str`,
+/// `size` in bytes, `duration` in seconds, or `histogram`, an object with
+/// the `count` of durations and their `p50`, `p90` and `p99` in seconds.
+///
+/// The statistics for each kind of compiler and each source file extension
+/// follow, with the `compiler` or the `extension` they're for.
+pub fn stats_to_json(stats: &CacheStats) -> Value {
+    fn stat_to_json(s: &CacheStatistic, breakdown: Option<&CacheStatsBreakdown>) -> Value {
+        let mut stat = Map::new();
+        stat.insert("name".to_owned(), Value::String(s.get_name().to_owned()));
+        if let Some(b) = breakdown {
+            stat.insert(b.get_kind().to_owned(), Value::String(b.get_value().to_owned()));
+        }
+        let (kind, value) = if s.has_count() {
+            ("count", Value::U64(s.get_count()))
         } else if s.has_str() {
-            s.get_str().to_owned()
+            ("str", Value::String(s.get_str().to_owned()))
         } else if s.has_size() {
-            match binary_prefix(s.get_size() as f64) {
-                Standalone(bytes) => format!("{} bytes", bytes),
-                Prefixed(prefix, n) => format!("{:.0} {}B", n, prefix),
+            ("size", Value::U64(s.get_size()))
+        } else if s.has_duration() {
+            ("duration", Value::F64(s.get_duration() as f64 / 1e9))
+        } else if s.has_histogram() {
+            let h = s.get_histogram();
+            let mut histogram = Map::new();
+            histogram.insert("count".to_owned(), Value::U64(h.get_count()));
+            for &(name, nanos) in &[("p50", h.get_p50()), ("p90", h.get_p90()), ("p99", h.get_p99())] {
+                histogram.insert(name.to_owned(), Value::F64(nanos as f64 / 1e9));
             }
+            ("histogram", Value::Object(histogram))
         } else {
-            String::from("???")
-        }))
+            ("unknown", Value::Null)
+        };
+        stat.insert(kind.to_owned(), value);
+        Value::Object(stat)
+    }
+
+    let mut json = stats.get_stats().iter()
+        .map(|s| stat_to_json(s, None))
         .collect::<Vec<_>>();
-    let name_width = formatted.iter().map(|&(n, _)| n.len()).max().unwrap();
-    let stat_width = formatted.iter().map(|&(_, ref s)| s.len()).max().unwrap();
-    for (name, stat) in formatted {
-        println!("{:<name_width$} {:>stat_width$}", name, stat, name_width=name_width, stat_width=stat_width);
+    for b in stats.get_breakdowns() {
+        json.extend(b.get_stats().iter().map(|s| stat_to_json(s, Some(b))));
+    }
+    Value::Array(json)
+}
+
+/// Print the effective value of each setting in `config`, and where it came from.
+fn print_config(config: &Config) {
+    match config.path() {
+        Some(path) => println!("Config file: {}", path.display()),
+        None => println!("Config file: none"),
+    }
+    let formatted = SETTINGS.iter()
+        .map(|&(name, var)| {
+            let (value, source) = config.lookup(var);
+            let source = match source {
+                Source::Environment => var,
+                Source::File => "config file",
+                Source::Default => "default",
+            };
+            let value = match value {
+                // Don't print credentials.
+                Some(_) if var == "SCCACHE_HTTP_PASSWORD" || var == "SCCACHE_HTTP_TOKEN" => "<hidden>".to_owned(),
+                Some(value) => value,
+                None => "-".to_owned(),
+            };
+            (name, value, source)
+        })
+        .collect::<Vec<_>>();
+    let name_width = formatted.iter().map(|&(n, _, _)| n.len()).max().unwrap();
+    let value_width = formatted.iter().map(|&(_, ref v, _)| v.len()).max().unwrap();
+    for (name, value, source) in formatted {
+        println!("{:<name_width$} {:<value_width$} ({})", name, value, source, name_width=name_width, value_width=value_width);
     }
-    Ok(())
 }
 
 /// Send a `Compile` request to the server, and return the server response if successful.
-fn request_compile<W: AsRef<Path>, X: AsRef<OsStr>, Y: AsRef<Path>>(conn: &mut ServerConnection, exe: W, args: &Vec<X>, cwd: Y) -> Result<CompileResponse> {
+///
+/// If `jobserver` is set, the server takes a token from it before running the compiler.
+/// If the server is running a different version of sccache, this fails with
+/// `ServerVersionMismatch`.
+fn request_compile<W: AsRef<Path>, X: AsRef<OsStr>, Y: AsRef<Path>>(conn: &mut ServerConnection, exe: W, args: &Vec<X>, cwd: Y, jobserver: Option<&Jobserver>) -> Result<CompileResponse> {
     //TODO: It'd be nicer to send these over as raw bytes.
     let exe = exe.as_ref().to_str().ok_or("bad exe")?;
     let cwd = cwd.as_ref().to_str().ok_or("bad cwd")?;
@@ -397,6 +667,11 @@ fn request_compile<W: AsRef<Path>, X: AsRef<OsStr>, Y: AsRef<Path>>(conn: &mut S
     compile.set_exe(exe.to_owned());
     compile.set_cwd(cwd.to_owned());
     compile.set_command(RepeatedField::from_vec(args));
+    if let Some(jobserver) = jobserver {
+        compile.set_client_pid(jobserver::current_pid());
+        compile.set_makeflags(jobserver.makeflags().to_owned());
+    }
+    compile.set_hello(server::hello());
     trace!("request_compile: {:?}", compile);
     req.set_compile(compile);
     //TODO: better error mapping?
@@ -407,6 +682,8 @@ fn request_compile<W: AsRef<Path>, X: AsRef<OsStr>, Y: AsRef<Path>>(conn: &mut S
         Ok(CompileResponse::CompileStarted(response.take_compile_started()))
     } else if response.has_unhandled_compile() {
         Ok(CompileResponse::UnhandledCompile(response.take_unhandled_compile()))
+    } else if response.has_hello() {
+        bail!(ErrorKind::ServerVersionMismatch)
     } else {
         bail!("Unexpected response from server")
     }
@@ -456,7 +733,8 @@ fn handle_compile_finished(response: CompileFinished,
 /// Handle `response`, the response from sending a `Compile` request to the server. Return the compiler exit status.
 ///
 /// If the server returned `CompileStarted`, wait for a `CompileFinished` and
-/// print the results.
+/// print the results. If the server sends `CompileQueued` in the meantime,
+/// lend this process's token to `jobserver` until the compile is done.
 ///
 /// If the server returned `UnhandledCompile`, run the compilation command
 /// locally using `creator` and return the result.
@@ -464,6 +742,7 @@ fn handle_compile_response<T>(mut creator: T,
                               core: &mut Core,
                               conn: &mut ServerConnection,
                               response: CompileResponse,
+                              jobserver: Option<&Jobserver>,
                               exe: &Path,
                               cmdline: Vec<OsString>,
                               cwd: &Path,
@@ -471,33 +750,42 @@ fn handle_compile_response<T>(mut creator: T,
                               stderr: &mut Write) -> Result<i32>
     where T : CommandCreatorSync,
 {
+    let mut lent_token = None;
     match response {
         CompileResponse::CompileStarted(_) => {
             debug!("Server sent CompileStarted");
             // Wait for CompileFinished.
-            match conn.read_one_response() {
-                Ok(mut res) => {
-                    if res.has_compile_finished() {
-                        trace!("Server sent CompileFinished");
-                        return handle_compile_finished(res.take_compile_finished(),
-                                                       stdout, stderr)
-                    } else {
-                        bail!("unexpected response from server")
+            loop {
+                match conn.read_one_response() {
+                    Ok(mut res) => {
+                        if res.has_compile_queued() {
+                            trace!("Server sent CompileQueued");
+                            lent_token = jobserver.and_then(lend_token);
+                        } else if res.has_compile_finished() {
+                            trace!("Server sent CompileFinished");
+                            let res = handle_compile_finished(res.take_compile_finished(),
+                                                              stdout, stderr);
+                            // The server has given back the token it took.
+                            drop(lent_token);
+                            return res
+                        } else {
+                            bail!("unexpected response from server")
+                        }
                     }
-                }
 
-                // Currently the shutdown behavior of the remote sccache server
-                // is to wait at most N seconds for all active connections to
-                // finish and then close everything. If we get unlucky and don't
-                // get a response then we just forge ahead locally and run the
-                // compilation ourselves.
-                Err(ProtobufError::IoError(ref e))
-                    if e.kind() == io::ErrorKind::UnexpectedEof => {}
-
-                Err(e) => return Err(e).chain_err(|| {
-                    //TODO: something better here?
-                    "error reading compile response from server"
-                })
+                    // Currently the shutdown behavior of the remote sccache server
+                    // is to wait at most N seconds for all active connections to
+                    // finish and then close everything. If we get unlucky and don't
+                    // get a response then we just forge ahead locally and run the
+                    // compilation ourselves.
+                    Err(ProtobufError::IoError(ref e))
+                        if e.kind() == io::ErrorKind::UnexpectedEof => break,
+
+                    Err(e) => return Err(e).chain_err(|| {
+                        //TODO: something better here?
+                        "error reading compile response from server"
+                    })
+                }
             }
         }
         CompileResponse::UnhandledCompile(_) => {
@@ -505,6 +793,9 @@ fn handle_compile_response<T>(mut creator: T,
         }
     };
 
+    // Take our jobserver token back before running the compiler ourselves.
+    drop(lent_token);
+
     //TODO: possibly capture output here for testing.
     let mut cmd = creator.new_command_sync(exe);
     cmd.args(&cmdline)
@@ -532,6 +823,10 @@ fn handle_compile_response<T>(mut creator: T,
 ///
 /// The first entry in `cmdline` will be looked up in `path` if it is not
 /// an absolute path.
+/// If `jobserver` is set, this process's jobserver token is lent to it
+/// while the server runs the compiler.
+/// If the server is running a different version of sccache, it's asked to
+/// shut down, and this fails with `ServerVersionMismatch`.
 /// See `request_compile` and `handle_compile_response`.
 pub fn do_compile<T>(creator: T,
                      core: &mut Core,
@@ -540,33 +835,51 @@ pub fn do_compile<T>(creator: T,
                      cmdline: Vec<OsString>,
                      cwd: &Path,
                      path: Option<OsString>,
+                     jobserver: Option<&Jobserver>,
                      stdout: &mut Write,
                      stderr: &mut Write) -> Result<i32>
     where T : CommandCreatorSync,
 {
     trace!("do_compile");
     let exe_path = which_in(exe, path, &cwd)?;
-    let res = request_compile(&mut conn, &exe_path, &cmdline, &cwd)?;
-    handle_compile_response(creator, core, &mut conn, res, &exe_path, cmdline, cwd, stdout, stderr)
+    let res = request_compile(&mut conn, &exe_path, &cmdline, &cwd, jobserver);
+    if let Err(Error(ErrorKind::ServerVersionMismatch, _)) = res {
+        shut_down_other_version(conn)?;
+        bail!(ErrorKind::ServerVersionMismatch)
+    }
+    handle_compile_response(creator, core, &mut conn, res?, jobserver, &exe_path, cmdline, cwd, stdout, stderr)
+}
+
+/// Lend this process's token to `jobserver`, for the server to run a
+/// compile with.
+fn lend_token(jobserver: &Jobserver) -> Option<LentToken> {
+    match jobserver.lend_implicit_token() {
+        Ok(token) => Some(token),
+        Err(e) => {
+            debug!("Failed to lend jobserver token: {}", e);
+            None
+        }
+    }
 }
 
 /// Run `cmd` and return the process exit status.
 pub fn run_command(cmd: Command) -> Result<i32> {
+    let config = Config::load()?;
     match cmd {
-        Command::ShowStats => {
+        Command::ShowStats(format) => {
             trace!("Command::ShowStats");
-            let srv = connect_or_start_server(get_port())?;
+            let srv = connect_or_start_current_server(&get_server_address(&config)?)?;
             let response = request_stats(srv).chain_err(|| {
                 "failed to get stats from server"
             })?;
-            print_stats(response)?;
+            print_stats(response, format)?;
         }
         Command::InternalStartServer => {
             trace!("Command::InternalStartServer");
             // Can't report failure here, we're already daemonized.
             daemonize()?;
             redirect_error_log()?;
-            server::start_server(get_port())?;
+            server::start_server(&config, &get_server_address(&config)?)?;
         }
         Command::StartServer => {
             trace!("Command::StartServer");
@@ -584,39 +897,70 @@ pub fn run_command(cmd: Command) -> Result<i32> {
                 }
             }
         }
-        Command::StopServer => {
+        Command::StopServer(format) => {
             trace!("Command::StopServer");
-            println!("Stopping sccache server...");
-            let server = connect_to_server(get_port()).chain_err(|| {
+            if format == StatsFormat::Text {
+                println!("Stopping sccache server...");
+            }
+            let server = connect_to_server(&get_server_address(&config)?).chain_err(|| {
                 "couldn't connect to server"
             })?;
             let stats = request_shutdown(server)?;
-            print_stats(stats)?
+            print_stats(stats, format)?
         }
         Command::Compile { exe, cmdline, cwd } => {
             trace!("Command::Compile {{ {:?}, {:?}, {:?} }}", exe, cmdline, cwd);
-            let conn = connect_or_start_server(get_port())?;
+            let addr = get_server_address(&config)?;
+            let conn = connect_or_start_server(&addr)?;
             let mut core = Core::new()?;
-            let res = do_compile(ProcessCommandCreator::new(&core.handle()),
-                                 &mut core,
-                                 conn,
-                                 exe.as_ref(),
-                                 cmdline,
-                                 &cwd,
-                                 env::var_os("PATH"),
-                                 &mut io::stdout(),
-                                 &mut io::stderr());
+            let jobserver = Jobserver::from_env();
+            let compile = |core: &mut Core, conn: ServerConnection| {
+                do_compile(ProcessCommandCreator::new(&core.handle()),
+                           core,
+                           conn,
+                           exe.as_ref(),
+                           cmdline.clone(),
+                           &cwd,
+                           env::var_os("PATH"),
+                           jobserver.as_ref(),
+                           &mut io::stdout(),
+                           &mut io::stderr())
+            };
+            let res = match compile(&mut core, conn) {
+                Err(Error(ErrorKind::ServerVersionMismatch, _)) => {
+                    compile(&mut core, restart_server(&addr)?)
+                }
+                res => res,
+            };
             return res.chain_err(|| {
                 "failed to execute compile"
             })
         }
-        Command::ZeroStats => {
+        Command::ZeroStats(format) => {
             trace!("Command::ZeroStats");
-            let conn = connect_or_start_server(get_port())?;
+            let conn = connect_or_start_current_server(&get_server_address(&config)?)?;
             let stats = request_zero_stats(conn).chain_err(|| {
                 "couldn't zero stats on server"
             })?;
-            print_stats(stats)?
+            print_stats(stats, format)?
+        }
+        Command::DumpTrace => {
+            trace!("Command::DumpTrace");
+            let server = connect_to_server(&get_server_address(&config)?).chain_err(|| {
+                "couldn't connect to server"
+            })?;
+            let dumped = request_dump_trace(server)?;
+            if dumped.has_path() {
+                println!("Wrote build timeline to {}", dumped.get_path());
+            } else if dumped.has_error() {
+                bail!("server {}", dumped.get_error());
+            } else {
+                bail!("server isn't recording a timeline, set SCCACHE_TIMELINE to record one");
+            }
+        }
+        Command::ShowConfig => {
+            trace!("Command::ShowConfig");
+            print_config(&config);
         }
     }
 
@@ -0,0 +1,232 @@
+// Copyright 2017 Mozilla Foundation
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Rewriting of absolute paths under a base directory to relative ones, so
+//! that the same sources compiled from different checkout directories hash
+//! to the same key.
+
+use compiler::direct;
+use std::path::{Component, Path, PathBuf};
+use std::str;
+
+/// Return `path` relative to `from`. Both must be absolute.
+fn relative_path(path: &Path, from: &Path) -> PathBuf {
+    let mut path_components = path.components().peekable();
+    let mut from_components = from.components().peekable();
+    // Skip the common prefix.
+    while path_components.peek().is_some() && path_components.peek() == from_components.peek() {
+        path_components.next();
+        from_components.next();
+    }
+    let mut relative = PathBuf::new();
+    for c in from_components {
+        if let Component::Normal(_) = c {
+            relative.push("..");
+        }
+    }
+    for c in path_components {
+        relative.push(c.as_os_str());
+    }
+    if relative.as_os_str().is_empty() {
+        relative.push(".");
+    }
+    relative
+}
+
+/// If `path` is an absolute path under `base_dir`, return it relative to `cwd`.
+pub fn normalize_path(path: &str, base_dir: &Path, cwd: &Path) -> Option<String> {
+    let path = Path::new(path);
+    if !path.is_absolute() || !path.starts_with(base_dir) {
+        return None
+    }
+    relative_path(path, cwd).to_str().map(|s| s.to_owned())
+}
+
+/// Rewrite the absolute path under `base_dir` in the argument `arg`, if any,
+/// relative to `cwd`.
+///
+/// Paths are recognized either as the whole argument, or following an
+/// option such as `-I` or `--sysroot=`.
+pub fn normalize_arg(arg: &str, base_dir: &Path, cwd: &Path) -> String {
+    if let Some(path) = normalize_path(arg, base_dir, cwd) {
+        return path
+    }
+    if arg.starts_with('-') {
+        let base = base_dir.to_string_lossy();
+        if let Some(i) = arg.find(&*base) {
+            let (option, path) = arg.split_at(i);
+            if !option.contains('/') && !option.contains('\\') {
+                if let Some(path) = normalize_path(path, base_dir, cwd) {
+                    return format!("{}{}", option, path)
+                }
+            }
+        }
+    }
+    arg.to_owned()
+}
+
+/// Rewrite the absolute paths under `base_dir` in the line markers of the
+/// preprocessor output `preprocessed`, relative to `cwd`.
+pub fn normalize_preprocessed(preprocessed: &[u8], base_dir: &Path, cwd: &Path) -> Vec<u8> {
+    let mut normalized = Vec::with_capacity(preprocessed.len());
+    for (i, line) in preprocessed.split(|&b| b == b'\n').enumerate() {
+        if i > 0 {
+            normalized.push(b'\n');
+        }
+        match normalize_line_marker(line, base_dir, cwd) {
+            Some(line) => normalized.extend_from_slice(line.as_bytes()),
+            None => normalized.extend_from_slice(line),
+        }
+    }
+    normalized
+}
+
+/// Rewrite the absolute paths under `base_dir` in the dependency file
+/// `depfile`, as written by `-MD`, relative to `cwd`.
+pub fn normalize_depfile(depfile: &[u8], base_dir: &Path, cwd: &Path) -> Vec<u8> {
+    let mut normalized = Vec::with_capacity(depfile.len());
+    let mut start = 0;
+    for i in 0..depfile.len() + 1 {
+        // Paths end at unescaped whitespace.
+        let end = match depfile.get(i) {
+            Some(&b) if b == b' ' || b == b'\t' || b == b'\n' => i > 0 && depfile[i - 1] != b'\\',
+            Some(_) => false,
+            None => true,
+        };
+        if !end {
+            continue
+        }
+        let word = &depfile[start..i];
+        // Targets are followed by a colon.
+        let (path, suffix) = if word.ends_with(b":") {
+            (&word[..word.len() - 1], &b":"[..])
+        } else {
+            (word, &b""[..])
+        };
+        match str::from_utf8(path).ok().and_then(|p| normalize_path(p, base_dir, cwd)) {
+            Some(path) => {
+                normalized.extend_from_slice(path.as_bytes());
+                normalized.extend_from_slice(suffix);
+            }
+            None => normalized.extend_from_slice(word),
+        }
+        normalized.extend(depfile.get(i));
+        start = i + 1;
+    }
+    normalized
+}
+
+/// If `line` is a line marker naming a file under `base_dir`, return it with
+/// the file name relative to `cwd`.
+fn normalize_line_marker(line: &[u8], base_dir: &Path, cwd: &Path) -> Option<String> {
+    // Cheap check first, most lines aren't line markers.
+    if !line.iter().skip_while(|&&b| b == b' ' || b == b'\t').next().map_or(false, |&b| b == b'#') {
+        return None
+    }
+    let file = match direct::parse_line_marker(line) {
+        Some(file) => file,
+        None => return None,
+    };
+    let path = match normalize_path(&file, base_dir, cwd) {
+        Some(path) => path,
+        None => return None,
+    };
+    let line = match str::from_utf8(line) {
+        Ok(line) => line,
+        Err(_) => return None,
+    };
+    // The file name is the only quoted part of a line marker.
+    match (line.find('"'), line.rfind('"')) {
+        (Some(start), Some(end)) if start < end => {
+            let escaped = path.replace('\\', "\\\\").replace('"', "\\\"");
+            Some(format!("{}{}{}", &line[..start + 1], escaped, &line[end..]))
+        }
+        _ => None,
+    }
+}
+
+/// The argument that makes GCC and clang write paths under `base_dir` in
+/// debug info relative to it.
+pub fn debug_prefix_map(base_dir: &Path) -> String {
+    format!("-fdebug-prefix-map={}=.", base_dir.display())
+}
+
+#[cfg(all(test, unix))]
+mod test {
+    use super::*;
+    use std::path::Path;
+
+    #[test]
+    fn test_normalize_path() {
+        let base = Path::new("/home/user/src");
+        let cwd = Path::new("/home/user/src/project/build");
+        assert_eq!(Some("../foo.c".to_owned()),
+                   normalize_path("/home/user/src/project/foo.c", base, cwd));
+        assert_eq!(Some("../../other/foo.h".to_owned()),
+                   normalize_path("/home/user/src/other/foo.h", base, cwd));
+        assert_eq!(Some(".".to_owned()),
+                   normalize_path("/home/user/src/project/build", base, cwd));
+        assert_eq!(None, normalize_path("/usr/include/stdio.h", base, cwd));
+        assert_eq!(None, normalize_path("/home/user/srcs/foo.c", base, cwd));
+        assert_eq!(None, normalize_path("foo.c", base, cwd));
+    }
+
+    #[test]
+    fn test_normalize_arg() {
+        let base = Path::new("/home/user/src");
+        let cwd = Path::new("/home/user/src/project");
+        assert_eq!("include", normalize_arg("/home/user/src/project/include", base, cwd));
+        assert_eq!("-Iinclude", normalize_arg("-I/home/user/src/project/include", base, cwd));
+        assert_eq!("--sysroot=../sysroot",
+                   normalize_arg("--sysroot=/home/user/src/sysroot", base, cwd));
+        assert_eq!("-I/usr/include", normalize_arg("-I/usr/include", base, cwd));
+        assert_eq!("-O2", normalize_arg("-O2", base, cwd));
+    }
+
+    #[test]
+    fn test_normalize_depfile() {
+        let base = Path::new("/home/user/src");
+        let cwd = Path::new("/home/user/src/project");
+        let depfile = b"/home/user/src/project/foo.o: /home/user/src/project/foo.c \\
+ /usr/include/stdio.h /home/user/src/project/some\\ dir/bar.h
+/home/user/src/project/foo.h:
+";
+        let expected = b"foo.o: foo.c \\
+ /usr/include/stdio.h some\\ dir/bar.h
+foo.h:
+";
+        assert_eq!(&expected[..], &normalize_depfile(depfile, base, cwd)[..]);
+    }
+
+    #[test]
+    fn test_normalize_preprocessed() {
+        let base = Path::new("/home/user/src");
+        let cwd = Path::new("/home/user/src/project");
+        let preprocessed = b"# 1 \"/home/user/src/project/foo.c\"
+# 1 \"<built-in>\"
+# 1 \"/usr/include/stdio.h\" 1 3 4
+int x = 1;
+# 2 \"/home/user/src/project/foo.h\" 2
+#line 3 \"/home/user/src/project/bar.h\"
+";
+        let expected = b"# 1 \"foo.c\"
+# 1 \"<built-in>\"
+# 1 \"/usr/include/stdio.h\" 1 3 4
+int x = 1;
+# 2 \"foo.h\" 2
+#line 3 \"bar.h\"
+";
+        assert_eq!(&expected[..], &normalize_preprocessed(preprocessed, base, cwd)[..]);
+    }
+}
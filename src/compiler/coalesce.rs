@@ -0,0 +1,221 @@
+// Copyright 2017 Mozilla Foundation
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Coalescing of identical compilations running at the same time.
+//!
+//! The first compilation of a hash key to miss the cache runs the compiler as
+//! usual. Compilations of the same key that miss while it's running wait for
+//! it instead of running the compiler again, and then write its outputs to
+//! their own output paths.
+
+use futures::Future;
+use futures::sync::oneshot;
+use futures_cpupool::CpuPool;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::PathBuf;
+use std::process;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use errors::*;
+
+/// The result of a compilation, shared with the compilations that waited on
+/// it.
+pub struct SharedCompile {
+    /// The output of the compiler.
+    pub output: process::Output,
+    /// The contents of the output files, by key.
+    pub objects: HashMap<String, Vec<u8>>,
+}
+
+/// The compilations in progress, by hash key.
+#[derive(Clone)]
+pub struct InFlightCompiles {
+    /// The compilations waiting on each compilation in progress.
+    waiting: Rc<RefCell<HashMap<String, Vec<oneshot::Sender<Arc<SharedCompile>>>>>>,
+}
+
+/// What to do about a compilation that missed the cache.
+pub enum Coalesce {
+    /// No identical compilation is in progress, so run the compiler.
+    Leader(Leader),
+    /// Wait for the identical compilation in progress.
+    ///
+    /// Resolves to `None` if it doesn't produce a result that can be shared.
+    Wait(SFuture<Option<Arc<SharedCompile>>>),
+}
+
+/// A compilation in progress that identical compilations may wait on.
+///
+/// If it's dropped without being finished, the waiting compilations run the
+/// compiler themselves.
+pub struct Leader {
+    in_flight: InFlightCompiles,
+    key: String,
+}
+
+impl InFlightCompiles {
+    pub fn new() -> InFlightCompiles {
+        InFlightCompiles {
+            waiting: Rc::new(RefCell::new(HashMap::new())),
+        }
+    }
+
+    /// Start a compilation of hash key `key`, or wait on the one already in
+    /// progress.
+    pub fn start(&self, key: &str) -> Coalesce {
+        match self.waiting.borrow_mut().entry(key.to_owned()) {
+            Entry::Occupied(mut e) => {
+                let (tx, rx) = oneshot::channel();
+                e.get_mut().push(tx);
+                Coalesce::Wait(Box::new(rx.then(|res| Ok::<_, Error>(res.ok()))))
+            }
+            Entry::Vacant(e) => {
+                e.insert(vec!());
+                Coalesce::Leader(Leader {
+                    in_flight: self.clone(),
+                    key: key.to_owned(),
+                })
+            }
+        }
+    }
+}
+
+impl Leader {
+    /// Whether any compilations are waiting on this one.
+    pub fn has_waiters(&self) -> bool {
+        self.in_flight.waiting.borrow().get(&self.key).map_or(false, |w| !w.is_empty())
+    }
+
+    /// Hand `shared` to the compilations waiting on this one.
+    pub fn finish(self, shared: Arc<SharedCompile>) {
+        let waiters = self.in_flight.waiting.borrow_mut().remove(&self.key);
+        for tx in waiters.into_iter().flat_map(|w| w) {
+            drop(tx.send(shared.clone()));
+        }
+    }
+}
+
+impl Drop for Leader {
+    fn drop(&mut self) {
+        self.in_flight.waiting.borrow_mut().remove(&self.key);
+    }
+}
+
+/// Read the files in `outputs` written by a compilation with output `output`,
+/// to share them with the compilations waiting on it.
+pub fn read_outputs(output: process::Output,
+                    outputs: HashMap<String, PathBuf>,
+                    pool: &CpuPool)
+                    -> SFuture<Arc<SharedCompile>> {
+    Box::new(pool.spawn_fn(move || -> Result<_> {
+        let mut objects = HashMap::new();
+        for (key, path) in outputs {
+            let mut contents = vec!();
+            File::open(&path)?.read_to_end(&mut contents)?;
+            objects.insert(key, contents);
+        }
+        Ok(Arc::new(SharedCompile {
+            output: output,
+            objects: objects,
+        }))
+    }))
+}
+
+/// Write the objects in `shared` to the paths in `outputs`, and resolve to
+/// the output of the compilation that produced them.
+pub fn write_outputs(shared: Arc<SharedCompile>,
+                     outputs: HashMap<String, PathBuf>,
+                     pool: &CpuPool)
+                     -> SFuture<process::Output> {
+    Box::new(pool.spawn_fn(move || -> Result<_> {
+        for (key, path) in &outputs {
+            let contents = match shared.objects.get(key) {
+                Some(contents) => contents,
+                None => bail!("coalesced compile has no `{}` output", key),
+            };
+            // Replace the output rather than writing to it, since it may be
+            // a hard link to an object in the cache.
+            match fs::remove_file(path) {
+                Err(ref e) if e.kind() == io::ErrorKind::NotFound => {}
+                res => res?,
+            }
+            File::create(path)?.write_all(contents)?;
+        }
+        Ok(shared.output.clone())
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use mock_command::exit_status;
+
+    fn shared() -> Arc<SharedCompile> {
+        Arc::new(SharedCompile {
+            output: process::Output {
+                status: exit_status(0),
+                stdout: vec!(),
+                stderr: vec!(),
+            },
+            objects: HashMap::new(),
+        })
+    }
+
+    #[test]
+    fn test_coalesce() {
+        let in_flight = InFlightCompiles::new();
+        let leader = match in_flight.start("abc") {
+            Coalesce::Leader(leader) => leader,
+            Coalesce::Wait(_) => panic!("Unexpected wait"),
+        };
+        assert!(!leader.has_waiters());
+        let wait = match in_flight.start("abc") {
+            Coalesce::Wait(wait) => wait,
+            Coalesce::Leader(_) => panic!("Unexpected leader"),
+        };
+        assert!(leader.has_waiters());
+        // Other keys aren't affected.
+        match in_flight.start("def") {
+            Coalesce::Leader(_) => {}
+            Coalesce::Wait(_) => panic!("Unexpected wait"),
+        }
+        leader.finish(shared());
+        assert!(wait.wait().unwrap().is_some());
+    }
+
+    #[test]
+    fn test_coalesce_leader_dropped() {
+        let in_flight = InFlightCompiles::new();
+        let leader = match in_flight.start("abc") {
+            Coalesce::Leader(leader) => leader,
+            Coalesce::Wait(_) => panic!("Unexpected wait"),
+        };
+        let wait = match in_flight.start("abc") {
+            Coalesce::Wait(wait) => wait,
+            Coalesce::Leader(_) => panic!("Unexpected leader"),
+        };
+        drop(leader);
+        assert!(wait.wait().unwrap().is_none());
+        // The next compilation leads again.
+        match in_flight.start("abc") {
+            Coalesce::Leader(_) => {}
+            Coalesce::Wait(_) => panic!("Unexpected wait"),
+        }
+    }
+}
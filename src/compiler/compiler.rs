@@ -14,18 +14,31 @@
 
 use cache::{
     Cache,
+    CacheMode,
+    CacheRead,
     Storage,
     hash_key,
+    hash_key_components,
 };
 use compiler::{
+    basedir,
     clang,
+    coalesce,
+    direct,
     gcc,
     msvc,
+    rustc,
 };
+use compiler::coalesce::{Coalesce, InFlightCompiles};
+use compiler::explain::MissExplainer;
+use compiler::jobs::JobQueue;
+use compiler::latency::{Latencies, Operation};
+use compiler::timeline::{Phase, Timeline};
 use filetime::FileTime;
 use futures::future;
 use futures::{Future, IntoFuture};
 use futures_cpupool::CpuPool;
+use jobserver::Jobserver;
 use log::LogLevel::Debug;
 use mock_command::{
     CommandChild,
@@ -51,6 +64,7 @@ use std::sync::Arc;
 use std::time::{
     Duration,
     Instant,
+    SystemTime,
 };
 use tempdir::TempDir;
 use tokio_core::reactor::{Handle, Timeout};
@@ -69,9 +83,25 @@ pub enum CompilerKind {
         /// The prefix used in the output of `-showIncludes`.
         includes_prefix: String,
     },
+    /// rustc
+    Rustc {
+        /// The sha-1 digest of the compiler and standard libraries in the
+        /// sysroot, as a hex string.
+        sysroot_digest: String,
+    },
 }
 
 impl CompilerKind {
+    /// A short name for this kind of compiler, for statistics.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            CompilerKind::Gcc => "gcc",
+            CompilerKind::Clang => "clang",
+            CompilerKind::Msvc { .. } => "msvc",
+            CompilerKind::Rustc { .. } => "rustc",
+        }
+    }
+
     pub fn parse_arguments(&self,
                            arguments: &[String],
                            cwd: &Path) -> CompilerArguments {
@@ -81,6 +111,7 @@ impl CompilerKind {
             CompilerKind::Gcc => gcc::parse_arguments(arguments, cwd, gcc::argument_takes_value),
             CompilerKind::Clang => gcc::parse_arguments(arguments, cwd, clang::argument_takes_value),
             CompilerKind::Msvc { .. } => msvc::parse_arguments(arguments),
+            CompilerKind::Rustc { .. } => rustc::parse_arguments(arguments),
         }
     }
 
@@ -99,6 +130,7 @@ impl CompilerKind {
                 gcc::preprocess(creator, compiler, parsed_args, cwd, pool)
             },
             CompilerKind::Msvc { ref includes_prefix } => msvc::preprocess(creator, compiler, parsed_args, cwd, includes_prefix, pool),
+            CompilerKind::Rustc { ref sysroot_digest } => rustc::preprocess(creator, compiler, parsed_args, cwd, sysroot_digest, pool),
         }
     }
 
@@ -116,6 +148,7 @@ impl CompilerKind {
             CompilerKind::Gcc => gcc::compile(creator, compiler, preprocessor_result, parsed_args, cwd, pool),
             CompilerKind::Clang => clang::compile(creator, compiler, preprocessor_result, parsed_args, cwd, pool),
             CompilerKind::Msvc { .. } => msvc::compile(creator, compiler, preprocessor_result, parsed_args, cwd, pool),
+            CompilerKind::Rustc { .. } => rustc::compile(creator, compiler, preprocessor_result, parsed_args, cwd, pool),
         }
     }
 }
@@ -130,7 +163,7 @@ pub struct ParsedArguments {
     pub extension: String,
     /// The file in which to generate dependencies.
     pub depfile: Option<String>,
-    /// Output files, keyed by a simple name, like "obj".
+    /// Output files, keyed by a simple name, like "obj" or "rlib".
     pub outputs: HashMap<&'static str, String>,
     /// Commandline arguments for the preprocessor.
     pub preprocessor_args: Vec<String>,
@@ -142,7 +175,7 @@ pub struct ParsedArguments {
 
 impl ParsedArguments {
     pub fn output_file(&self) -> Cow<str> {
-        self.outputs.get("obj").and_then(|o| Path::new(o).file_name().map(|f| f.to_string_lossy())).unwrap_or(Cow::Borrowed("Unknown filename"))
+        self.outputs.get("obj").or_else(|| self.outputs.get("rlib")).or_else(|| self.outputs.get("rmeta")).and_then(|o| Path::new(o).file_name().map(|f| f.to_string_lossy())).unwrap_or(Cow::Borrowed("Unknown filename"))
     }
 }
 
@@ -185,6 +218,10 @@ pub enum MissType {
 pub struct CacheWriteInfo {
     pub object_file: String,
     pub duration: Duration,
+    /// The size of the entry, before compression.
+    pub size: u64,
+    /// The size of the entry as it was stored.
+    pub stored_size: u64,
 }
 
 /// The result of a compilation or cache retrieval.
@@ -196,12 +233,15 @@ pub enum CompileResult {
     /// Result was not found in cache.
     ///
     /// The `CacheWriteFuture` will resolve when the result is finished
-    /// being stored in the cache.
-    CacheMiss(MissType, Duration, SFuture<CacheWriteInfo>),
+    /// being stored in the cache. It is `None` if the cache is read-only.
+    CacheMiss(MissType, Duration, Option<SFuture<CacheWriteInfo>>),
     /// Not in cache, but the compilation result was determined to be not cacheable.
     NotCacheable,
     /// Not in cache, but compilation failed.
     CompileFailed,
+    /// Not in cache, but an identical compilation was already running, and
+    /// its result was used.
+    Coalesced,
 }
 
 
@@ -214,6 +254,7 @@ impl fmt::Debug for CompileResult {
             &CompileResult::CacheMiss(ref m, ref d, _) => write!(f, "CompileResult::CacheMiss({:?}, {:?}, _)", d, m),
             &CompileResult::NotCacheable => write!(f, "CompileResult::NotCacheable"),
             &CompileResult::CompileFailed => write!(f, "CompileResult::CompileFailed"),
+            &CompileResult::Coalesced => write!(f, "CompileResult::Coalesced"),
         }
     }
 }
@@ -227,6 +268,7 @@ impl PartialEq<CompileResult> for CompileResult {
             (&CompileResult::CacheMiss(ref m, _, _), &CompileResult::CacheMiss(ref n, _, _)) => m == n,
             (&CompileResult::NotCacheable, &CompileResult::NotCacheable) => true,
             (&CompileResult::CompileFailed, &CompileResult::CompileFailed) => true,
+            (&CompileResult::Coalesced, &CompileResult::Coalesced) => true,
             _ => false,
         }
     }
@@ -248,6 +290,56 @@ pub enum CacheControl {
     ForceRecache,
 }
 
+/// Control of how compilations are looked up in the cache.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum DirectMode {
+    /// Always run the preprocessor, and look up its output.
+    Disabled,
+    /// Look up the source file and the files it included first, and only
+    /// run the preprocessor if they haven't been seen before.
+    Enabled,
+}
+
+/// The settings and shared state a server uses for every compile request.
+#[derive(Clone)]
+pub struct CompileContext {
+    /// Whether compile results are looked up in the cache, stored in it, or
+    /// both.
+    pub cache_mode: CacheMode,
+    /// Whether compile results are looked up without running the
+    /// preprocessor where possible.
+    pub direct_mode: DirectMode,
+    /// If set, absolute paths under it are made relative before hashing, so
+    /// that compiles from different checkouts can share results.
+    pub base_dir: Option<PathBuf>,
+    /// Compilations that are running, so identical requests can wait for
+    /// them instead of running the compiler again.
+    pub in_flight: InFlightCompiles,
+    /// The queue of compiler processes waiting to run.
+    pub jobs: JobQueue,
+    /// How long cache operations and compiler processes take.
+    pub latencies: Latencies,
+    /// Explains cache misses, if enabled.
+    pub explainer: Option<MissExplainer>,
+    /// The phases of each compile request.
+    pub timeline: Timeline,
+}
+
+impl Default for CompileContext {
+    fn default() -> CompileContext {
+        CompileContext {
+            cache_mode: CacheMode::ReadWrite,
+            direct_mode: DirectMode::Disabled,
+            base_dir: None,
+            in_flight: InFlightCompiles::new(),
+            jobs: JobQueue::new(None),
+            latencies: Latencies::new(),
+            explainer: None,
+            timeline: Timeline::disabled(),
+        }
+    }
+}
+
 impl Compiler {
     /// Create a new `Compiler` of `kind`, with `executable` as the binary.
     ///
@@ -296,6 +388,13 @@ impl Compiler {
 
     /// Look up a cached compile result in `storage`. If not found, run the
     /// compile and store the result.
+    ///
+    /// With `DirectMode::Enabled` in `context`, the result is first looked
+    /// up by the contents of the source file and the files it included the
+    /// last time it was compiled, without running the preprocessor.
+    ///
+    /// Resolves to the result and the compiler output, along with the hash
+    /// key the result was looked up by, if it got that far.
     pub fn get_cached_or_compile<T>(&self,
                                     creator: &T,
                                     storage: &Arc<Storage>,
@@ -303,30 +402,217 @@ impl Compiler {
                                     parsed_args: &ParsedArguments,
                                     cwd: &str,
                                     cache_control: CacheControl,
+                                    jobserver: Option<&Jobserver>,
+                                    context: &CompileContext,
                                     pool: &CpuPool,
                                     handle: &Handle)
-                                    -> SFuture<(CompileResult, process::Output)>
+                                    -> SFuture<(CompileResult, process::Output, Option<String>)>
         where T: CommandCreatorSync
     {
-        let out_file = parsed_args.output_file();
+        let out_file = parsed_args.output_file().into_owned();
         if log_enabled!(Debug) {
             let cmd_str = arguments.join(" ");
             debug!("[{}]: get_cached_or_compile: {}", out_file, cmd_str);
         }
-        let result = self.kind.preprocess(creator, self, parsed_args, cwd, pool);
-        let out_file = out_file.into_owned();
-        let result = result.map_err(move |e| {
-            debug!("[{}]: preprocessor failed: {:?}", out_file, e);
-            e
+        let direct_key = if context.direct_mode == DirectMode::Enabled && direct::is_eligible(parsed_args) {
+            direct::source_key(self, parsed_args, cwd, context.base_dir.as_ref().map(|p| p.as_path()), pool)
+        } else {
+            Box::new(future::ok(None))
+        };
+        // If the source can't be read, the preprocessor will report it.
+        let direct_key = direct_key.then(move |res| -> Result<_> {
+            match res {
+                Ok(key) => Ok(key),
+                Err(e) => {
+                    debug!("[{}]: not using direct mode: {:?}", out_file, e);
+                    Ok(None)
+                }
+            }
         });
         let parsed_args = parsed_args.clone();
         let cwd = cwd.to_string();
+        let jobserver = jobserver.cloned();
+        let context = context.clone();
         let me = self.clone();
         let storage = storage.clone();
         let pool = pool.clone();
         let creator = creator.clone();
         let handle = handle.clone();
 
+        Box::new(direct_key.and_then(move |direct_key| -> SFuture<_> {
+            // If `ForceRecache` is enabled, we won't check the cache.
+            let lookup = match direct_key {
+                Some(ref direct_key) if cache_control == CacheControl::Default && context.cache_mode.can_read() => {
+                    let lookup = me.direct_lookup(&storage, direct_key, &parsed_args, &cwd, &pool, &handle);
+                    context.timeline.span(Phase::CacheLookup, &parsed_args.output_file(), lookup)
+                }
+                _ => Box::new(future::ok(None)),
+            };
+            Box::new(lookup.and_then(move |hit| -> SFuture<_> {
+                match hit {
+                    Some((result, output, key)) => Box::new(future::ok((result, output, Some(key)))),
+                    None => me.preprocess_and_compile(&creator,
+                                                      storage,
+                                                      parsed_args,
+                                                      cwd,
+                                                      cache_control,
+                                                      direct_key,
+                                                      jobserver,
+                                                      context,
+                                                      pool,
+                                                      handle),
+                }
+            }))
+        }))
+    }
+
+    /// Look up the direct mode manifest stored under `direct_key`, and if
+    /// none of the files it lists have changed, the compile result it
+    /// points to.
+    ///
+    /// Resolves to `None` if the result has to be found by running the
+    /// preprocessor, or the result and its hash key otherwise.
+    fn direct_lookup(&self,
+                     storage: &Arc<Storage>,
+                     direct_key: &str,
+                     parsed_args: &ParsedArguments,
+                     cwd: &str,
+                     pool: &CpuPool,
+                     handle: &Handle)
+                     -> SFuture<Option<(CompileResult, process::Output, String)>>
+    {
+        let start = Instant::now();
+        let out_file = parsed_args.output_file().into_owned();
+        let parsed_args = parsed_args.clone();
+        let cwd = PathBuf::from(cwd);
+        let storage = storage.clone();
+        let pool = pool.clone();
+        let handle = handle.clone();
+
+        let lookup = cache_lookup(&storage, direct_key, &handle);
+        let lookup = lookup.and_then(move |result| -> SFuture<_> {
+            let mut entry = match result {
+                Some(Cache::Hit(entry)) => entry,
+                _ => return Box::new(future::ok(None)),
+            };
+            let manifest = match direct::Manifest::from_entry(&mut entry) {
+                Ok(manifest) => manifest,
+                Err(e) => return Box::new(future::err(e)),
+            };
+            let manifest_cwd = cwd.clone();
+            let check = pool.spawn_fn(move || -> Result<_> {
+                if manifest.matches(&manifest_cwd) {
+                    Ok(Some(manifest))
+                } else {
+                    Ok(None)
+                }
+            });
+            Box::new(check.and_then(move |manifest| -> SFuture<_> {
+                let manifest = match manifest {
+                    Some(manifest) => manifest,
+                    None => {
+                        debug!("[{}]: Direct mode manifest is out of date",
+                               parsed_args.output_file());
+                        return Box::new(future::ok(None))
+                    }
+                };
+                let lookup = cache_lookup(&storage, &manifest.key, &handle);
+                let key = manifest.key.clone();
+                Box::new(lookup.and_then(move |result| -> SFuture<_> {
+                    let entry = match result {
+                        Some(Cache::Hit(entry)) => entry,
+                        _ => return Box::new(future::ok(None)),
+                    };
+                    debug!("[{}]: Direct cache hit!", parsed_args.output_file());
+                    let duration = start.elapsed();
+                    let outputs = parsed_args.outputs.iter()
+                        .map(|(key, path)| (key.to_string(), cwd.join(path)))
+                        .collect::<HashMap<_, _>>();
+                    // The preprocessor didn't run, so write the dependency
+                    // file it would have written.
+                    let depfile = match (parsed_args.depfile, manifest.depfile) {
+                        (Some(path), Some(contents)) => Some((cwd.join(path), contents)),
+                        _ => None,
+                    };
+                    let write_depfile = pool.spawn_fn(move || -> Result<_> {
+                        if let Some((path, contents)) = depfile {
+                            File::create(&path)?.write_all(&contents)?;
+                        }
+                        Ok(())
+                    });
+                    let write = write_cached_outputs(entry, outputs, &pool);
+                    Box::new(write.join(write_depfile).map(move |(output, ())| {
+                        Some((CompileResult::CacheHit(duration), output, key))
+                    }))
+                }))
+            }))
+        });
+        Box::new(lookup.then(move |res| -> Result<_> {
+            match res {
+                Ok(hit) => Ok(hit),
+                Err(e) => {
+                    debug!("[{}]: Direct mode lookup failed: {:?}", out_file, e);
+                    Ok(None)
+                }
+            }
+        }))
+    }
+
+    /// Run the preprocessor and look up the compile result by its output.
+    /// If not found, run the compile and store the result.
+    ///
+    /// If `direct_key` is set, a direct mode manifest is stored under it
+    /// as well.
+    fn preprocess_and_compile<T>(&self,
+                                 creator: &T,
+                                 storage: Arc<Storage>,
+                                 parsed_args: ParsedArguments,
+                                 cwd: String,
+                                 cache_control: CacheControl,
+                                 direct_key: Option<String>,
+                                 jobserver: Option<Jobserver>,
+                                 context: CompileContext,
+                                 pool: CpuPool,
+                                 handle: Handle)
+                                 -> SFuture<(CompileResult, process::Output, Option<String>)>
+        where T: CommandCreatorSync
+    {
+        let CompileContext {
+            cache_mode,
+            base_dir,
+            in_flight,
+            jobs,
+            latencies,
+            explainer,
+            timeline,
+            ..
+        } = context;
+        // Files modified after this point may not match what the
+        // preprocessor read.
+        let since = SystemTime::now();
+        let result = {
+            let me = self.clone();
+            let creator = creator.clone();
+            let parsed_args = parsed_args.clone();
+            let cwd = cwd.clone();
+            let pool = pool.clone();
+            let latencies = latencies.clone();
+            let timeline = timeline.clone();
+            jobs.run(move || {
+                let preprocess = me.kind.preprocess(&creator, &me, &parsed_args, &cwd, &pool);
+                timeline.span(Phase::Preprocess,
+                              &parsed_args.output_file(),
+                              latencies.time(Operation::Preprocess, preprocess))
+            })
+        };
+        let out_file = parsed_args.output_file().into_owned();
+        let result = result.map_err(move |e| {
+            debug!("[{}]: preprocessor failed: {:?}", out_file, e);
+            e
+        });
+        let me = self.clone();
+        let creator = creator.clone();
+
         Box::new(result.and_then(move |preprocessor_result| -> SFuture<_> {
             // If the preprocessor failed, just return that result.
             if !preprocessor_result.status.success() {
@@ -338,72 +624,108 @@ impl Compiler {
                     stdout: vec!(),
                     ..preprocessor_result
                 };
-                return Box::new(future::ok((CompileResult::Error, output)))
+                return Box::new(future::ok((CompileResult::Error, output, None)))
             }
             trace!("[{}]: Preprocessor output is {} bytes",
                    parsed_args.output_file(),
                    preprocessor_result.stdout.len());
 
-            // Remove object file from arguments before hash calculation
-            let key = {
+            // Remove object file from arguments before hash calculation.
+            // Keep the inputs to the key too, if misses are being explained.
+            let (key, components) = {
                 let out_file = parsed_args.output_file();
                 let arguments = parsed_args.common_args.iter()
-                    .filter(|a| **a != out_file)
-                    .map(|a| a.as_str())
-                    .collect::<String>();
-                hash_key(&me, &arguments, &preprocessor_result.stdout)
+                    .filter(|a| **a != out_file);
+                match base_dir {
+                    Some(ref base_dir) => {
+                        let cwd = Path::new(&cwd);
+                        let arguments = arguments
+                            .map(|a| basedir::normalize_arg(a, base_dir, cwd))
+                            .collect::<String>();
+                        let preprocessed = basedir::normalize_preprocessed(&preprocessor_result.stdout, base_dir, cwd);
+                        let components = explainer.as_ref().map(|_| {
+                            hash_key_components(&me, &arguments, &preprocessed)
+                        });
+                        (hash_key(&me, &arguments, &preprocessed), components)
+                    }
+                    None => {
+                        let arguments = arguments
+                            .map(|a| a.as_str())
+                            .collect::<String>();
+                        let components = explainer.as_ref().map(|_| {
+                            hash_key_components(&me, &arguments, &preprocessor_result.stdout)
+                        });
+                        (hash_key(&me, &arguments, &preprocessor_result.stdout), components)
+                    }
+                }
             };
             trace!("[{}]: Hash key: {}", parsed_args.output_file(), key);
+            // Start building the manifest while we check the cache, unless
+            // the cache is read-only.
+            let direct_key = if cache_mode.can_write() { direct_key } else { None };
+            let manifest = direct_key.map(|direct_key| {
+                let entry = direct::manifest_entry(&storage,
+                                                   &direct_key,
+                                                   &key,
+                                                   &preprocessor_result.stdout,
+                                                   &parsed_args,
+                                                   &cwd,
+                                                   base_dir.as_ref().map(|p| p.as_path()),
+                                                   since,
+                                                   &pool);
+                let out_file = parsed_args.output_file().into_owned();
+                direct::store_manifest(storage.clone(), direct_key, entry).then(move |res| {
+                    match res {
+                        Ok(()) => trace!("[{}]: Stored direct mode manifest", out_file),
+                        Err(e) => debug!("[{}]: Failed to store direct mode manifest: {:?}", out_file, e),
+                    }
+                    Ok::<(), ()>(())
+                })
+            });
             // If `ForceRecache` is enabled, we won't check the cache.
             let start = Instant::now();
             let cache_status = if cache_control == CacheControl::ForceRecache {
-                Box::new(future::ok(Cache::Recache))
+                Box::new(future::ok(Some(Cache::Recache)))
+            } else if !cache_mode.can_read() {
+                debug!("[{}]: Cache is write-only, not looking up", parsed_args.output_file());
+                Box::new(future::ok(Some(Cache::Miss)))
             } else {
-                storage.get(&key)
+                timeline.span(Phase::CacheLookup,
+                              &parsed_args.output_file(),
+                              cache_lookup(&storage, &key, &handle))
             };
 
-            // Wait at most a minute for the cache to respond before we forge
-            // ahead ourselves with a compilation.
-            let timeout = Duration::new(60, 0);
-            let timeout = Timeout::new(timeout, &handle).into_future().flatten();
-
-            let cache_status = cache_status.map(Some);
-            let timeout = timeout.map(|_| None).chain_err(|| "timeout error");
-            let cache_status = cache_status.select(timeout).then(|r| {
-                match r {
-                    Ok((a, _other)) => Ok(a),
-                    Err((e, _other)) => Err(e),
-                }
-            });
-
-            Box::new(cache_status.and_then(move |result| {
+            let result_key = key.clone();
+            let compiled = cache_status.and_then(move |result| {
                 let duration = start.elapsed();
-                let pwd = Path::new(&cwd);
-                let outputs = parsed_args.outputs.iter()
-                    .map(|(key, path)| (key.to_string(), pwd.join(path)))
-                    .collect::<HashMap<_, _>>();
+                let outputs = {
+                    let pwd = Path::new(&cwd);
+                    parsed_args.outputs.iter()
+                        .map(|(key, path)| (key.to_string(), pwd.join(path)))
+                        .collect::<HashMap<_, _>>()
+                };
+                if let (Some(explainer), Some(components)) = (explainer, components) {
+                    let missed = match result {
+                        Some(Cache::Miss) => true,
+                        _ => false,
+                    };
+                    let output = Path::new(&cwd).join(&*parsed_args.output_file());
+                    let out_file = parsed_args.output_file().into_owned();
+                    let record = explainer.record(&output, &key, components, missed, &pool);
+                    handle.spawn(record.map_err(move |e| {
+                        debug!("[{}]: Failed to record hash key inputs: {:?}", out_file, e);
+                    }));
+                }
 
                 let miss_type = match result {
-                    Some(Cache::Hit(mut entry)) => {
+                    Some(Cache::Hit(entry)) => {
                         debug!("[{}]: Cache hit!", parsed_args.output_file());
-                        let mut stdout = io::Cursor::new(vec!());
-                        let mut stderr = io::Cursor::new(vec!());
-                        entry.get_object("stdout", &mut stdout).unwrap_or(());
-                        entry.get_object("stderr", &mut stderr).unwrap_or(());
-                        let write = pool.spawn_fn(move ||{
-                            for (key, path) in &outputs {
-                                let mut f = try!(File::create(path));
-                                try!(entry.get_object(&key, &mut f));
-                            }
-                            Ok(())
-                        });
-                        let output = process::Output {
-                            status: exit_status(0),
-                            stdout: stdout.into_inner(),
-                            stderr: stderr.into_inner(),
-                        };
+                        if let Some(manifest) = manifest {
+                            handle.spawn(manifest);
+                        }
+                        let write = write_cached_outputs(entry, outputs, &pool);
                         let result = CompileResult::CacheHit(duration);
-                        return Box::new(write.map(|_| {
+                        return Box::new(write.map(|output| {
                             (result, output)
                         })) as SFuture<_>
                     }
@@ -420,16 +742,93 @@ impl Compiler {
                         MissType::TimedOut
                     }
                 };
-                me.compile(&creator,
-                           preprocessor_result,
-                           parsed_args,
-                           &cwd,
-                           pool,
-                           outputs,
-                           storage,
-                           key,
-                           miss_type)
-            }))
+                // If an identical compilation is already running, wait for
+                // it rather than running the compiler again.
+                let (leader, wait) = match in_flight.start(&key) {
+                    Coalesce::Leader(leader) => {
+                        (Some(leader), Box::new(future::ok(None)) as SFuture<_>)
+                    }
+                    Coalesce::Wait(wait) => {
+                        debug!("[{}]: Waiting for an identical compile", parsed_args.output_file());
+                        (None, wait)
+                    }
+                };
+                Box::new(wait.and_then(move |shared| -> SFuture<_> {
+                    if let Some(shared) = shared {
+                        debug!("[{}]: Using the result of an identical compile",
+                               parsed_args.output_file());
+                        let write = coalesce::write_outputs(shared, outputs, &pool);
+                        return Box::new(write.map(|output| {
+                            (CompileResult::Coalesced, output)
+                        }))
+                    }
+                    // Keep paths in debug info from depending on the base
+                    // directory, now that it doesn't affect the hash key.
+                    let mut parsed_args = parsed_args;
+                    if let Some(ref base_dir) = base_dir {
+                        match me.kind {
+                            CompilerKind::Gcc | CompilerKind::Clang => {
+                                parsed_args.common_args.push(basedir::debug_prefix_map(base_dir));
+                            }
+                            _ => {}
+                        }
+                    }
+                    let compile = me.compile(&creator,
+                                             preprocessor_result,
+                                             parsed_args,
+                                             &cwd,
+                                             pool.clone(),
+                                             outputs.clone(),
+                                             storage,
+                                             key,
+                                             cache_mode,
+                                             miss_type,
+                                             jobs,
+                                             jobserver,
+                                             latencies,
+                                             timeline);
+                    // Hand the result to any identical compilations that
+                    // started waiting in the meantime.
+                    let compile = match leader {
+                        Some(leader) => Box::new(compile.and_then(move |(result, output)| -> SFuture<_> {
+                            let share = match result {
+                                CompileResult::CacheMiss(..) => leader.has_waiters(),
+                                _ => false,
+                            };
+                            if !share {
+                                return Box::new(future::ok((result, output)))
+                            }
+                            let read = coalesce::read_outputs(output.clone(), outputs, &pool);
+                            Box::new(read.then(move |shared| {
+                                match shared {
+                                    Ok(shared) => leader.finish(shared),
+                                    Err(e) => debug!("Failed to share compile result: {:?}", e),
+                                }
+                                Ok::<_, Error>((result, output))
+                            }))
+                        })) as SFuture<_>,
+                        None => compile,
+                    };
+                    match manifest {
+                        // Only store the manifest once the result it points to
+                        // has been stored.
+                        Some(manifest) => Box::new(compile.map(|(result, output)| {
+                            let result = match result {
+                                CompileResult::CacheMiss(miss_type, duration, Some(future)) => {
+                                    let future = future.and_then(move |info| {
+                                        manifest.then(|_| Ok(info))
+                                    });
+                                    CompileResult::CacheMiss(miss_type, duration, Some(Box::new(future)))
+                                }
+                                result => result,
+                            };
+                            (result, output)
+                        })),
+                        None => compile,
+                    }
+                }))
+            });
+            Box::new(compiled.map(move |(result, output)| (result, output, Some(result_key))))
         }))
     }
 
@@ -442,19 +841,57 @@ impl Compiler {
                   outputs: HashMap<String, PathBuf>,
                   storage: Arc<Storage>,
                   key: String,
-                  miss_type: MissType)
+                  cache_mode: CacheMode,
+                  miss_type: MissType,
+                  jobs: JobQueue,
+                  jobserver: Option<Jobserver>,
+                  latencies: Latencies,
+                  timeline: Timeline)
                   -> SFuture<(CompileResult, process::Output)>
         where T: CommandCreatorSync,
     {
         let start = Instant::now();
         let out_file = parsed_args.output_file().into_owned();
 
-        let compile = self.kind.compile(creator,
-                                        self,
-                                        preprocessor_result,
-                                        &parsed_args,
-                                        cwd,
-                                        &pool);
+        // The outputs of an earlier cache hit may be hard links to objects
+        // in the cache, so don't let the compiler write over them.
+        for path in outputs.values() {
+            match fs::remove_file(path) {
+                Err(ref e) if e.kind() != io::ErrorKind::NotFound => {
+                    debug!("[{}]: Failed to remove {:?}: {}", out_file, path, e);
+                }
+                _ => {}
+            }
+        }
+
+        let compile = {
+            let me = self.clone();
+            let creator = creator.clone();
+            let parsed_args = parsed_args.clone();
+            let cwd = cwd.to_owned();
+            let pool = pool.clone();
+            let timeline = timeline.clone();
+            jobs.run(move || {
+                // Only run the compiler once the jobserver has room for it
+                // too, if the client lent its token to one. The token isn't
+                // taken until now, so a compile waiting for a slot doesn't
+                // hold one.
+                let token = match jobserver {
+                    Some(jobserver) => Box::new(jobserver.acquire().map(Some)) as SFuture<_>,
+                    None => Box::new(future::ok(None)),
+                };
+                Box::new(token.and_then(move |token| {
+                    let compile = me.kind.compile(&creator, &me, preprocessor_result, &parsed_args, &cwd, &pool);
+                    timeline.span(Phase::Compile,
+                                  &parsed_args.output_file(),
+                                  latencies.time(Operation::Compile, compile))
+                        .then(move |res| {
+                            drop(token);
+                            res
+                        })
+                }))
+            })
+        };
         Box::new(compile.and_then(move |(cacheable, compiler_result)| {
             let duration = start.elapsed();
             if !compiler_result.status.success() {
@@ -469,11 +906,18 @@ impl Compiler {
                        parsed_args.output_file());
                 return Box::new(future::ok((CompileResult::NotCacheable, compiler_result)))
             }
+            if !cache_mode.can_write() {
+                debug!("[{}]: Compiled, but the cache is read-only",
+                       parsed_args.output_file());
+                return Box::new(future::ok((CompileResult::CacheMiss(miss_type, duration, None), compiler_result)))
+            }
             debug!("[{}]: Compiled, storing in cache", parsed_args.output_file());
+            let write_start = Instant::now();
             let mut entry = match storage.start_put(&key) {
                 Ok(entry) => entry,
                 Err(e) => return Box::new(future::err(e))
             };
+            // The entry is written to a file, so do it all on the pool.
             let write = pool.spawn_fn(move || -> Result<_> {
                 for (key, path) in &outputs {
                     let mut f = File::open(&path)?;
@@ -481,10 +925,6 @@ impl Compiler {
                         format!("failed to put object `{:?}` in zip", path)
                     })?;
                 }
-                Ok(entry)
-            });
-            let write = write.chain_err(|| "failed to zip up compiler outputs");
-            Box::new(write.and_then(move |mut entry| {
                 if !compiler_result.stdout.is_empty() {
                     let mut stdout = &compiler_result.stdout[..];
                     entry.put_object("stdout", &mut stdout)?;
@@ -493,23 +933,30 @@ impl Compiler {
                     let mut stderr = &compiler_result.stderr[..];
                     entry.put_object("stderr", &mut stderr)?;
                 }
-
+                Ok((entry.finish()?, compiler_result))
+            });
+            let write = write.chain_err(|| "failed to zip up compiler outputs");
+            Box::new(write.and_then(move |(entry, compiler_result)| {
                 // Try to finish storing the newly-written cache
                 // entry. We'll get the result back elsewhere.
                 let out_file = parsed_args.output_file().into_owned();
+                let (size, stored_size) = (entry.size(), entry.stored_size());
                 let future = storage.finish_put(&key, entry)
                     .then(move |res| {
                         match res {
                             Ok(_) => debug!("[{}]: Stored in cache successfully!", out_file),
                             Err(ref e) => debug!("[{}]: Cache write error: {:?}", out_file, e),
                         }
+                        timeline.record(Phase::CacheWrite, &out_file, write_start, Instant::now());
                         res.map(|duration| CacheWriteInfo {
                             object_file: out_file,
                             duration: duration,
+                            size: size,
+                            stored_size: stored_size,
                         })
                     });
                 let future = Box::new(future);
-                Ok((CompileResult::CacheMiss(miss_type, duration, future), compiler_result))
+                Ok((CompileResult::CacheMiss(miss_type, duration, Some(future)), compiler_result))
             }).chain_err(move || {
                 format!("failed to store `{}` to cache", out_file)
             }))
@@ -517,6 +964,49 @@ impl Compiler {
     }
 }
 
+/// Look up `key` in `storage`.
+///
+/// Wait at most a minute for the cache to respond, resolving to `None` if
+/// it takes longer so we can forge ahead ourselves with a compilation.
+fn cache_lookup(storage: &Arc<Storage>, key: &str, handle: &Handle) -> SFuture<Option<Cache>> {
+    let cache_status = storage.get(key);
+    let timeout = Duration::new(60, 0);
+    let timeout = Timeout::new(timeout, handle).into_future().flatten();
+
+    let cache_status = cache_status.map(Some);
+    let timeout = timeout.map(|_| None).chain_err(|| "timeout error");
+    Box::new(cache_status.select(timeout).then(|r| {
+        match r {
+            Ok((a, _other)) => Ok(a),
+            Err((e, _other)) => Err(e),
+        }
+    }))
+}
+
+/// Write the objects in the cache entry `entry` to the paths in `outputs`,
+/// and resolve to the output of the compilation that produced them.
+fn write_cached_outputs(mut entry: CacheRead,
+                        outputs: HashMap<String, PathBuf>,
+                        pool: &CpuPool)
+                        -> SFuture<process::Output> {
+    let mut stdout = io::Cursor::new(vec!());
+    let mut stderr = io::Cursor::new(vec!());
+    entry.get_object("stdout", &mut stdout).unwrap_or(());
+    entry.get_object("stderr", &mut stderr).unwrap_or(());
+    let write = pool.spawn_fn(move ||{
+        for (key, path) in &outputs {
+            try!(entry.extract_object(&key, path));
+        }
+        Ok(())
+    });
+    let output = process::Output {
+        status: exit_status(0),
+        stdout: stdout.into_inner(),
+        stderr: stderr.into_inner(),
+    };
+    Box::new(write.map(|_| output))
+}
+
 /// Creates a future that will write `contents` to `path` inside of a temporary
 /// directory.
 ///
@@ -546,6 +1036,17 @@ pub fn detect_compiler_kind<T>(creator: &T, executable: &str, pool: &CpuPool)
     where T: CommandCreatorSync
 {
     trace!("detect_compiler");
+    // rustc can't be told apart from C compilers by what it preprocesses,
+    // so go by its name.
+    if Path::new(executable).file_stem().and_then(|s| s.to_str()) == Some("rustc") {
+        debug!("Found rustc");
+        return Box::new(rustc::sysroot_digest(creator, executable, pool).map(|digest| {
+            trace!("rustc sysroot digest: {}", digest);
+            Some(CompilerKind::Rustc {
+                sysroot_digest: digest,
+            })
+        }))
+    }
     let test = b"#if defined(_MSC_VER)
 msvc
 #elif defined(__clang__)
@@ -680,7 +1181,7 @@ pub fn run_input_output<C>(mut command: C, input: Option<Vec<u8>>)
 #[cfg(test)]
 mod test {
     use super::*;
-    use cache::Storage;
+    use cache::{CacheMode, Storage};
     use cache::disk::DiskCache;
     use futures::Future;
     use futures_cpupool::CpuPool;
@@ -733,6 +1234,23 @@ mod test {
         assert_eq!(Some(CompilerKind::Msvc { includes_prefix: prefix }), kind);
     }
 
+    #[test]
+    fn test_detect_compiler_kind_rustc() {
+        let creator = new_creator();
+        let pool = CpuPool::new(1);
+        let f = TestFixture::new();
+        fs::create_dir_all(f.tempdir.path().join("lib/rustlib/x86_64-unknown-linux-gnu/lib")).unwrap();
+        f.touch("lib/librustc_driver.so").unwrap();
+        f.touch("lib/rustlib/x86_64-unknown-linux-gnu/lib/libstd.rlib").unwrap();
+        let sysroot = format!("{}\n", f.tempdir.path().to_str().unwrap());
+        // `rustc --print=sysroot` output
+        next_command(&creator, Ok(MockChild::new(exit_status(0), &sysroot, &String::new())));
+        match detect_compiler_kind(&creator, "/foo/rustc", &pool).wait().unwrap() {
+            Some(CompilerKind::Rustc { .. }) => {}
+            k => panic!("Unexpected compiler kind: {:?}", k),
+        }
+    }
+
     #[test]
     fn test_detect_compiler_kind_unknown() {
         let creator = new_creator();
@@ -804,18 +1322,20 @@ mod test {
             CompilerArguments::Ok(parsed) => parsed,
             o @ _ => panic!("Bad result from parse_arguments: {:?}", o),
         };
-        let (cached, res) = c.get_cached_or_compile(&creator,
+        let (cached, res, _) = c.get_cached_or_compile(&creator,
                                                     &storage,
                                                     &arguments,
                                                     &parsed_args,
                                                     cwd,
                                                     CacheControl::Default,
+                                                    None,
+                                                    &CompileContext::default(),
                                                     &pool,
                                                     &handle).wait().unwrap();
         // Ensure that the object file was created.
         assert_eq!(true, fs::metadata(&obj).and_then(|m| Ok(m.len() > 0)).unwrap());
         match cached {
-            CompileResult::CacheMiss(MissType::Normal, _, f) => {
+            CompileResult::CacheMiss(MissType::Normal, _, Some(f)) => {
                 // wait on cache write future so we don't race with it!
                 f.wait().unwrap();
             }
@@ -829,12 +1349,14 @@ mod test {
         // The preprocessor invocation.
         next_command(&creator, Ok(MockChild::new(exit_status(0), "preprocessor output", "")));
         // There should be no actual compiler invocation.
-        let (cached, res) = c.get_cached_or_compile(&creator,
+        let (cached, res, _) = c.get_cached_or_compile(&creator,
                                                     &storage,
                                                     &arguments,
                                                     &parsed_args,
                                                     cwd,
                                                     CacheControl::Default,
+                                                    None,
+                                                    &CompileContext::default(),
                                                     &pool,
                                                     &handle).wait().unwrap();
         // Ensure that the object file was created.
@@ -884,18 +1406,20 @@ mod test {
             CompilerArguments::Ok(parsed) => parsed,
             o @ _ => panic!("Bad result from parse_arguments: {:?}", o),
         };
-        let (cached, res) = c.get_cached_or_compile(&creator,
+        let (cached, res, _) = c.get_cached_or_compile(&creator,
                                                     &storage,
                                                     &arguments,
                                                     &parsed_args,
                                                     cwd,
                                                     CacheControl::Default,
+                                                    None,
+                                                    &CompileContext::default(),
                                                     &pool,
                                                     &handle).wait().unwrap();
         // Ensure that the object file was created.
         assert_eq!(true, fs::metadata(&obj).and_then(|m| Ok(m.len() > 0)).unwrap());
         match cached {
-            CompileResult::CacheMiss(MissType::Normal, _, f) => {
+            CompileResult::CacheMiss(MissType::Normal, _, Some(f)) => {
                 // wait on cache write future so we don't race with it!
                 f.wait().unwrap();
             }
@@ -910,12 +1434,14 @@ mod test {
         // The preprocessor invocation.
         next_command(&creator, Ok(MockChild::new(exit_status(0), "preprocessor output", "")));
         // There should be no actual compiler invocation.
-        let (cached, res) = c.get_cached_or_compile(&creator,
+        let (cached, res, _) = c.get_cached_or_compile(&creator,
                                                     &storage,
                                                     &arguments,
                                                     &parsed_args,
                                                     cwd,
                                                     CacheControl::Default,
+                                                    None,
+                                                    &CompileContext::default(),
                                                     &pool,
                                                     &handle).wait().unwrap();
         // Ensure that the object file was created.
@@ -926,6 +1452,74 @@ mod test {
         assert_eq!(COMPILER_STDERR, res.stderr.as_slice());
     }
 
+    #[test]
+    fn test_compiler_get_cached_or_compile_cache_mode() {
+        use env_logger;
+        drop(env_logger::init());
+        let creator = new_creator();
+        let f = TestFixture::new();
+        let pool = CpuPool::new(1);
+        let core = Core::new().unwrap();
+        let handle = core.handle();
+        let storage = DiskCache::new(&f.tempdir.path().join("cache"),
+                                     usize::MAX,
+                                     &pool);
+        let storage: Arc<Storage> = Arc::new(storage);
+        // Pretend to be GCC.
+        next_command(&creator, Ok(MockChild::new(exit_status(0), "gcc", "")));
+        let c = get_compiler_info(&creator,
+                                  f.bins[0].to_str().unwrap(),
+                                  &pool).wait().unwrap();
+        let obj = f.tempdir.path().join("foo.o");
+        let cwd = f.tempdir.path().to_str().unwrap();
+        let arguments = stringvec!["-c", "foo.c", "-o", "foo.o"];
+        let parsed_args = match c.parse_arguments(&arguments, ".".as_ref()) {
+            CompilerArguments::Ok(parsed) => parsed,
+            o @ _ => panic!("Bad result from parse_arguments: {:?}", o),
+        };
+        let compile = |cache_mode| {
+            // The preprocessor invocation.
+            next_command(&creator, Ok(MockChild::new(exit_status(0), "preprocessor output", "")));
+            // The compiler invocation, if there is one.
+            let o = obj.clone();
+            next_command_calls(&creator, move || {
+                match File::create(&o)
+                    .and_then(|mut f| f.write_all(b"file contents")) {
+                        Ok(_) => Ok(MockChild::new(exit_status(0), "", "")),
+                        Err(e) => Err(e),
+                    }
+            });
+            let (cached, _, _) = c.get_cached_or_compile(&creator,
+                                                      &storage,
+                                                      &arguments,
+                                                      &parsed_args,
+                                                      cwd,
+                                                      CacheControl::Default,
+                                                      None,
+                                                      &CompileContext { cache_mode: cache_mode, ..CompileContext::default() },
+                                                      &pool,
+                                                      &handle).wait().unwrap();
+            cached
+        };
+        // A read-only cache doesn't store anything.
+        match compile(CacheMode::ReadOnly) {
+            CompileResult::CacheMiss(MissType::Normal, _, None) => {}
+            cached => assert!(false, "Unexpected compile result: {:?}", cached),
+        }
+        // A write-only cache stores results without looking them up.
+        for _ in 0..2 {
+            match compile(CacheMode::WriteOnly) {
+                CompileResult::CacheMiss(MissType::Normal, _, Some(f)) => {
+                    // wait on cache write future so we don't race with it!
+                    f.wait().unwrap();
+                }
+                cached => assert!(false, "Unexpected compile result: {:?}", cached),
+            }
+        }
+        // And a read-only cache finds them.
+        assert_eq!(CompileResult::CacheHit(Duration::new(0, 0)), compile(CacheMode::ReadOnly));
+    }
+
     #[test]
     fn test_compiler_get_cached_or_compile_force_recache() {
         use env_logger;
@@ -969,18 +1563,20 @@ mod test {
             CompilerArguments::Ok(parsed) => parsed,
             o @ _ => panic!("Bad result from parse_arguments: {:?}", o),
         };
-        let (cached, res) = c.get_cached_or_compile(&creator,
+        let (cached, res, _) = c.get_cached_or_compile(&creator,
                                                     &storage,
                                                     &arguments,
                                                     &parsed_args,
                                                     cwd,
                                                     CacheControl::Default,
+                                                    None,
+                                                    &CompileContext::default(),
                                                     &pool,
                                                     &handle).wait().unwrap();
         // Ensure that the object file was created.
         assert_eq!(true, fs::metadata(&obj).and_then(|m| Ok(m.len() > 0)).unwrap());
         match cached {
-            CompileResult::CacheMiss(MissType::Normal, _, f) => {
+            CompileResult::CacheMiss(MissType::Normal, _, Some(f)) => {
                 // wait on cache write future so we don't race with it!
                 f.wait().unwrap();
             }
@@ -991,18 +1587,20 @@ mod test {
         assert_eq!(COMPILER_STDERR, res.stderr.as_slice());
         // Now compile again, but force recaching.
         fs::remove_file(&obj).unwrap();
-        let (cached, res) = c.get_cached_or_compile(&creator,
+        let (cached, res, _) = c.get_cached_or_compile(&creator,
                                                     &storage,
                                                     &arguments,
                                                     &parsed_args,
                                                     cwd,
                                                     CacheControl::ForceRecache,
+                                                    None,
+                                                    &CompileContext::default(),
                                                     &pool,
                                                     &handle).wait().unwrap();
         // Ensure that the object file was created.
         assert_eq!(true, fs::metadata(&obj).and_then(|m| Ok(m.len() > 0)).unwrap());
         match cached {
-            CompileResult::CacheMiss(MissType::ForcedRecache, _, f) => {
+            CompileResult::CacheMiss(MissType::ForcedRecache, _, Some(f)) => {
                 // wait on cache write future so we don't race with it!
                 f.wait().unwrap();
             }
@@ -1040,12 +1638,14 @@ mod test {
             CompilerArguments::Ok(parsed) => parsed,
             o @ _ => panic!("Bad result from parse_arguments: {:?}", o),
         };
-        let (cached, res) = c.get_cached_or_compile(&creator,
+        let (cached, res, _) = c.get_cached_or_compile(&creator,
                                                     &storage,
                                                     &arguments,
                                                     &parsed_args,
                                                     cwd,
                                                     CacheControl::Default,
+                                                    None,
+                                                    &CompileContext::default(),
                                                     &pool,
                                                     &handle).wait().unwrap();
         assert_eq!(cached, CompileResult::Error);
@@ -1054,4 +1654,102 @@ mod test {
         assert_eq!(b"", res.stdout.as_slice());
         assert_eq!(PREPROCESSOR_STDERR, res.stderr.as_slice());
     }
+
+    #[test]
+    fn test_compiler_get_cached_or_compile_direct() {
+        use env_logger;
+        drop(env_logger::init());
+        let creator = new_creator();
+        let f = TestFixture::new();
+        let pool = CpuPool::new(1);
+        let core = Core::new().unwrap();
+        let handle = core.handle();
+        let storage = DiskCache::new(&f.tempdir.path().join("cache"),
+                                     usize::MAX,
+                                     &pool);
+        let storage: Arc<Storage> = Arc::new(storage);
+        // Pretend to be GCC.
+        next_command(&creator, Ok(MockChild::new(exit_status(0), "gcc", "")));
+        let c = get_compiler_info(&creator,
+                                  f.bins[0].to_str().unwrap(),
+                                  &pool).wait().unwrap();
+        create_file(f.tempdir.path(), "foo.c", |mut f| f.write_all(b"#include \"foo.h\"\n")).unwrap();
+        create_file(f.tempdir.path(), "foo.h", |mut f| f.write_all(b"int x;\n")).unwrap();
+        const PREPROCESSOR_STDOUT: &'static [u8] = b"# 1 \"foo.c\"\n# 1 \"foo.h\" 1\nint x;\n# 2 \"foo.c\" 2\n";
+        // The preprocessor invocation.
+        next_command(&creator, Ok(MockChild::new(exit_status(0), PREPROCESSOR_STDOUT, "")));
+        // The compiler invocation.
+        const COMPILER_STDOUT : &'static [u8] = b"compiler stdout";
+        const COMPILER_STDERR : &'static [u8] = b"compiler stderr";
+        let obj = f.tempdir.path().join("foo.o");
+        let o = obj.clone();
+        next_command_calls(&creator, move || {
+            // Pretend to compile something.
+            match File::create(&o)
+                .and_then(|mut f| f.write_all(b"file contents")) {
+                    Ok(_) => Ok(MockChild::new(exit_status(0), COMPILER_STDOUT, COMPILER_STDERR)),
+                    Err(e) => Err(e),
+                }
+        });
+        let cwd = f.tempdir.path().to_str().unwrap();
+        let arguments = stringvec!["-c", "foo.c", "-o", "foo.o"];
+        let parsed_args = match c.parse_arguments(&arguments, ".".as_ref()) {
+            CompilerArguments::Ok(parsed) => parsed,
+            o @ _ => panic!("Bad result from parse_arguments: {:?}", o),
+        };
+        let context = CompileContext {
+            direct_mode: DirectMode::Enabled,
+            ..CompileContext::default()
+        };
+        let (cached, _, _) = c.get_cached_or_compile(&creator,
+                                                  &storage,
+                                                  &arguments,
+                                                  &parsed_args,
+                                                  cwd,
+                                                  CacheControl::Default,
+                                                  None,
+                                                  &context,
+                                                  &pool,
+                                                  &handle).wait().unwrap();
+        match cached {
+            CompileResult::CacheMiss(MissType::Normal, _, Some(f)) => {
+                // wait on cache write future so we don't race with it!
+                f.wait().unwrap();
+            }
+            _ => assert!(false, "Unexpected compile result: {:?}", cached),
+        }
+        // Now compile again, which should be a cache hit without running
+        // the preprocessor.
+        fs::remove_file(&obj).unwrap();
+        let (cached, res, _) = c.get_cached_or_compile(&creator,
+                                                    &storage,
+                                                    &arguments,
+                                                    &parsed_args,
+                                                    cwd,
+                                                    CacheControl::Default,
+                                                    None,
+                                                    &context,
+                                                    &pool,
+                                                    &handle).wait().unwrap();
+        assert_eq!(true, fs::metadata(&obj).and_then(|m| Ok(m.len() > 0)).unwrap());
+        assert_eq!(CompileResult::CacheHit(Duration::new(0, 0)), cached);
+        assert_eq!(exit_status(0), res.status);
+        assert_eq!(COMPILER_STDOUT, res.stdout.as_slice());
+        assert_eq!(COMPILER_STDERR, res.stderr.as_slice());
+        // Changing the header means the preprocessor has to run again. Make
+        // it fail so we can tell that it ran.
+        create_file(f.tempdir.path(), "foo.h", |mut f| f.write_all(b"int y;\n")).unwrap();
+        next_command(&creator, Ok(MockChild::new(exit_status(1), "", "")));
+        let (cached, _, _) = c.get_cached_or_compile(&creator,
+                                                  &storage,
+                                                  &arguments,
+                                                  &parsed_args,
+                                                  cwd,
+                                                  CacheControl::Default,
+                                                  None,
+                                                  &context,
+                                                  &pool,
+                                                  &handle).wait().unwrap();
+        assert_eq!(CompileResult::Error, cached);
+    }
 }
@@ -0,0 +1,458 @@
+// Copyright 2017 Mozilla Foundation
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Support for "direct mode", where a compilation is looked up in the cache
+//! without running the preprocessor.
+//!
+//! The first time a compilation is seen it is preprocessed as usual, and the
+//! list of files the preprocessor read is stored in a *manifest* alongside
+//! the digest of each file and the hash key the preprocessed output resolved
+//! to. The manifest is stored in the cache under a key computed from the
+//! compiler, the arguments and the contents of the source file. Later
+//! compilations of the same source look up that manifest, and if none of the
+//! listed files have changed they can go straight to the cache entry named in
+//! the manifest.
+//!
+//! With a base directory set, absolute paths under it are made relative in
+//! the key and in the manifest, as they are for the preprocessor output, so
+//! that manifests can be shared between checkouts.
+
+use cache::{
+    CACHE_VERSION,
+    CACHED_ENV_VARS,
+    CacheEntryFile,
+    CacheRead,
+    CacheWrite,
+    Storage,
+};
+use compiler::{
+    Compiler,
+    ParsedArguments,
+};
+use compiler::basedir;
+use futures::future;
+use futures::Future;
+use futures_cpupool::CpuPool;
+use sha1;
+use std::collections::HashSet;
+use std::env;
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::str;
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use errors::*;
+
+/// The name of the object in a direct mode cache entry that holds the manifest.
+const MANIFEST_OBJECT: &'static str = "manifest";
+
+/// The name of the object in a direct mode cache entry that holds the
+/// dependency file written by the preprocessor, if any.
+const DEPFILE_OBJECT: &'static str = "depfile";
+
+/// Macros whose expansion changes from one run to the next. Files that use
+/// them can't be looked up without running the preprocessor.
+const VOLATILE_MACROS: &'static [&'static [u8]] = &[
+    b"__DATE__",
+    b"__TIME__",
+    b"__TIMESTAMP__",
+];
+
+/// The files read while preprocessing a compilation, and the hash key of its
+/// results.
+#[derive(Debug, PartialEq)]
+pub struct Manifest {
+    /// The hash key of the cache entry containing the compilation results.
+    pub key: String,
+    /// Every file read by the preprocessor, with the sha-1 digest of its
+    /// contents, as a hex string.
+    pub includes: Vec<(String, String)>,
+    /// The contents of the dependency file written by the preprocessor.
+    pub depfile: Option<Vec<u8>>,
+}
+
+impl Manifest {
+    /// Read a manifest from the cache entry `entry`.
+    pub fn from_entry(entry: &mut CacheRead) -> Result<Manifest> {
+        let mut data = vec!();
+        entry.get_object(MANIFEST_OBJECT, &mut data)?;
+        let data = str::from_utf8(&data).chain_err(|| "manifest is not valid utf-8")?;
+        let mut lines = data.lines();
+        let key = match lines.next() {
+            Some(key) if !key.is_empty() => key.to_owned(),
+            _ => bail!("manifest is missing a hash key"),
+        };
+        let mut includes = vec!();
+        for line in lines {
+            let mut parts = line.splitn(2, ' ');
+            match (parts.next(), parts.next()) {
+                (Some(digest), Some(path)) => includes.push((path.to_owned(), digest.to_owned())),
+                _ => bail!("malformed manifest line: `{}`", line),
+            }
+        }
+        let mut depfile = vec!();
+        let depfile = match entry.get_object(DEPFILE_OBJECT, &mut depfile) {
+            Ok(()) => Some(depfile),
+            Err(_) => None,
+        };
+        Ok(Manifest {
+            key: key,
+            includes: includes,
+            depfile: depfile,
+        })
+    }
+
+    /// Write this manifest to the cache entry `entry`.
+    pub fn to_entry(&self, entry: &mut CacheWrite) -> Result<()> {
+        let mut data = format!("{}\n", self.key);
+        for &(ref path, ref digest) in self.includes.iter() {
+            data.push_str(&format!("{} {}\n", digest, path));
+        }
+        entry.put_object(MANIFEST_OBJECT, &mut data.as_bytes())?;
+        if let Some(ref depfile) = self.depfile {
+            entry.put_object(DEPFILE_OBJECT, &mut &depfile[..])?;
+        }
+        Ok(())
+    }
+
+    /// Return true if none of the files listed in this manifest have changed.
+    ///
+    /// Relative paths are resolved against `cwd`. This reads every file, so
+    /// don't call it where it shouldn't block on I/O.
+    pub fn matches(&self, cwd: &Path) -> bool {
+        self.includes.iter().all(|&(ref path, ref digest)| {
+            match hash_file(&cwd.join(path)) {
+                Ok((ref d, false)) => d == digest,
+                _ => false,
+            }
+        })
+    }
+}
+
+/// Return true if the compilation described by `parsed_args` can be looked
+/// up without running the preprocessor.
+pub fn is_eligible(parsed_args: &ParsedArguments) -> bool {
+    // `-M` and `-MM` make the preprocessor output dependency rules instead
+    // of preprocessed source, so there's nothing to learn the includes from.
+    !parsed_args.preprocessor_args.iter().any(|a| a == "-M" || a == "-MM")
+}
+
+/// Compute the key under which the manifest for compiling `source` with
+/// `compiler` and `parsed_args` in `cwd` is stored.
+///
+/// If `base_dir` is set, absolute paths under it in the arguments are made
+/// relative to `cwd` first.
+pub fn direct_key(compiler: &Compiler,
+                  parsed_args: &ParsedArguments,
+                  source: &[u8],
+                  base_dir: Option<&Path>,
+                  cwd: &Path)
+                  -> String {
+    let normalize = |arg: &str| {
+        match base_dir {
+            Some(base_dir) => basedir::normalize_arg(arg, base_dir, cwd),
+            None => arg.to_owned(),
+        }
+    };
+    // If you change any of the inputs to the hash, you should change `CACHE_VERSION`.
+    let mut m = sha1::Sha1::new();
+    m.update(compiler.digest.as_bytes());
+    m.update(CACHE_VERSION);
+    // Keep direct mode keys distinct from preprocessed output keys.
+    m.update(b"direct");
+    // The input path ends up in the preprocessed output (and in `__FILE__`).
+    m.update(normalize(&parsed_args.input).as_bytes());
+    for arg in parsed_args.preprocessor_args.iter().chain(parsed_args.common_args.iter()) {
+        m.update(normalize(arg).as_bytes());
+        m.update(&b"\0"[..]);
+    }
+    for var in CACHED_ENV_VARS.iter() {
+        if let Ok(val) = env::var(var) {
+            m.update(var.as_bytes());
+            m.update(&b"="[..]);
+            m.update(val.as_bytes());
+        }
+    }
+    m.update(source);
+    m.digest().to_string()
+}
+
+/// Read the source file of the compilation described by `parsed_args` and
+/// compute its direct mode key, with paths under `base_dir` made relative.
+///
+/// Resolves to `None` if the compilation can't be looked up without running
+/// the preprocessor.
+pub fn source_key(compiler: &Compiler,
+                  parsed_args: &ParsedArguments,
+                  cwd: &str,
+                  base_dir: Option<&Path>,
+                  pool: &CpuPool)
+                  -> SFuture<Option<String>> {
+    let compiler = compiler.clone();
+    let parsed_args = parsed_args.clone();
+    let cwd = PathBuf::from(cwd);
+    let base_dir = base_dir.map(|p| p.to_owned());
+    let input = cwd.join(&parsed_args.input);
+    pool.spawn_fn(move || -> Result<_> {
+        let mut source = vec!();
+        File::open(&input)?.read_to_end(&mut source)?;
+        if uses_volatile_macros(&source) {
+            debug!("{:?} uses time macros, not using direct mode", input);
+            return Ok(None)
+        }
+        Ok(Some(direct_key(&compiler, &parsed_args, &source, base_dir.as_ref().map(|p| p.as_path()), &cwd)))
+    }).chain_err(|| "failed to compute direct mode key")
+}
+
+/// Return the list of files read by the preprocessor, in the order they were
+/// first mentioned in `preprocessed`.
+///
+/// Both GCC-style (`# 1 "foo.h"`) and MSVC-style (`#line 1 "foo.h"`) line
+/// markers are recognized. Pseudo-files like `<built-in>` are skipped.
+pub fn included_files(preprocessed: &[u8]) -> Vec<String> {
+    let mut seen = HashSet::new();
+    let mut files = vec!();
+    for line in preprocessed.split(|&b| b == b'\n') {
+        if let Some(file) = parse_line_marker(line) {
+            if !file.starts_with('<') && seen.insert(file.clone()) {
+                files.push(file);
+            }
+        }
+    }
+    files
+}
+
+/// Parse the file name out of a preprocessor line marker.
+pub fn parse_line_marker(line: &[u8]) -> Option<String> {
+    let line = match str::from_utf8(line) {
+        Ok(line) => line.trim_left(),
+        Err(_) => return None,
+    };
+    if !line.starts_with('#') {
+        return None
+    }
+    let rest = line[1..].trim_left();
+    let rest = if rest.starts_with("line") { rest[4..].trim_left() } else { rest };
+    let digits = rest.chars().take_while(|c| c.is_digit(10)).count();
+    if digits == 0 {
+        return None
+    }
+    let rest = rest[digits..].trim_left();
+    if !rest.starts_with('"') {
+        return None
+    }
+    let mut file = String::new();
+    let mut chars = rest[1..].chars();
+    loop {
+        match chars.next() {
+            Some('"') => return Some(file),
+            Some('\\') => match chars.next() {
+                Some(c) => file.push(c),
+                None => return None,
+            },
+            Some(c) => file.push(c),
+            None => return None,
+        }
+    }
+}
+
+/// Return true if `contents` mentions any of `VOLATILE_MACROS`.
+fn uses_volatile_macros(contents: &[u8]) -> bool {
+    VOLATILE_MACROS.iter().any(|m| contents.windows(m.len()).any(|w| w == *m))
+}
+
+/// Return the sha-1 digest of the contents of `path` as a hex string, and
+/// whether the file uses any of `VOLATILE_MACROS`.
+fn hash_file(path: &Path) -> io::Result<(String, bool)> {
+    let mut contents = vec!();
+    File::open(path)?.read_to_end(&mut contents)?;
+    let mut m = sha1::Sha1::new();
+    m.update(&contents);
+    Ok((m.digest().to_string(), uses_volatile_macros(&contents)))
+}
+
+/// Build a manifest entry for a compilation whose preprocessed output is
+/// `preprocessed` and whose results are stored under `key`.
+///
+/// `since` is the time preprocessing started; files modified after that may
+/// not match what the preprocessor read, so their presence makes the
+/// compilation ineligible for direct mode. Resolves to `None` if the
+/// compilation can't be looked up without running the preprocessor.
+///
+/// If `base_dir` is set, paths under it are recorded relative to `cwd`.
+pub fn manifest_entry(storage: &Arc<Storage>,
+                      direct_key: &str,
+                      key: &str,
+                      preprocessed: &[u8],
+                      parsed_args: &ParsedArguments,
+                      cwd: &str,
+                      base_dir: Option<&Path>,
+                      since: SystemTime,
+                      pool: &CpuPool)
+                      -> SFuture<Option<CacheEntryFile>> {
+    let includes = included_files(preprocessed);
+    if includes.is_empty() {
+        // No line markers (`-P`, for instance), so we can't tell what was read.
+        return future::ok(None).boxed()
+    }
+    let mut entry = match storage.start_put(direct_key) {
+        Ok(entry) => entry,
+        Err(e) => return future::err(e).boxed(),
+    };
+    let key = key.to_owned();
+    let cwd = PathBuf::from(cwd);
+    let depfile = parsed_args.depfile.as_ref().map(|d| cwd.join(d));
+    let base_dir = base_dir.map(|p| p.to_owned());
+    pool.spawn_fn(move || -> Result<_> {
+        let mut hashed = vec!();
+        for path in includes {
+            let full = cwd.join(&path);
+            let modified = fs::metadata(&full)?.modified()?;
+            if modified >= since {
+                debug!("{:?} was modified during compilation, not using direct mode", full);
+                return Ok(None)
+            }
+            let (digest, volatile) = hash_file(&full)?;
+            if volatile {
+                debug!("{:?} uses time macros, not using direct mode", full);
+                return Ok(None)
+            }
+            let path = match base_dir {
+                Some(ref base_dir) => basedir::normalize_path(&path, base_dir, &cwd).unwrap_or(path),
+                None => path,
+            };
+            hashed.push((path, digest));
+        }
+        let depfile = match depfile {
+            Some(depfile) => {
+                let mut contents = vec!();
+                File::open(&depfile)?.read_to_end(&mut contents)?;
+                match base_dir {
+                    Some(ref base_dir) => Some(basedir::normalize_depfile(&contents, base_dir, &cwd)),
+                    None => Some(contents),
+                }
+            }
+            None => None,
+        };
+        let manifest = Manifest {
+            key: key,
+            includes: hashed,
+            depfile: depfile,
+        };
+        manifest.to_entry(&mut entry)?;
+        Ok(Some(entry.finish()?))
+    }).chain_err(|| "failed to create direct mode manifest")
+}
+
+/// Store the manifest entry produced by `entry`, if any, in `storage` under
+/// `direct_key`.
+pub fn store_manifest(storage: Arc<Storage>,
+                      direct_key: String,
+                      entry: SFuture<Option<CacheEntryFile>>)
+                      -> SFuture<()> {
+    Box::new(entry.and_then(move |entry| -> SFuture<()> {
+        match entry {
+            Some(entry) => Box::new(storage.finish_put(&direct_key, entry).map(|_| ())),
+            None => future::ok(()).boxed(),
+        }
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use cache::{CacheRead, CacheWrite, Codec};
+    use compiler::CompilerKind;
+    use std::collections::HashMap;
+    use test::utils::*;
+
+    #[test]
+    fn test_included_files_gcc() {
+        let preprocessed = b"# 1 \"foo.c\"
+# 1 \"<built-in>\"
+# 1 \"<command-line>\"
+# 1 \"foo.c\"
+# 1 \"/usr/include/stdio.h\" 1 3 4
+int printf(const char*, ...);
+# 2 \"foo.c\" 2
+# 1 \"some dir/bar.h\" 1
+";
+        assert_eq!(stringvec!["foo.c", "/usr/include/stdio.h", "some dir/bar.h"],
+                   included_files(preprocessed));
+    }
+
+    #[test]
+    fn test_included_files_msvc() {
+        let preprocessed = b"#line 1 \"c:\\\\src\\\\foo.c\"\r
+#line 1 \"c:\\\\program files\\\\include\\\\stdio.h\"\r
+int printf(const char*, ...);\r
+#line 2 \"c:\\\\src\\\\foo.c\"\r
+";
+        assert_eq!(stringvec!["c:\\src\\foo.c", "c:\\program files\\include\\stdio.h"],
+                   included_files(preprocessed));
+    }
+
+    #[test]
+    fn test_included_files_none() {
+        assert!(included_files(b"int main() { return 0; }\n#pragma once\n").is_empty());
+    }
+
+    #[test]
+    fn test_uses_volatile_macros() {
+        assert!(uses_volatile_macros(b"const char* d = __DATE__;"));
+        assert!(uses_volatile_macros(b"const char* t = __TIME__;"));
+        assert!(!uses_volatile_macros(b"const char* f = __FILE__;"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_direct_key_base_dir() {
+        let f = TestFixture::new();
+        let c = Compiler::new(f.bins[0].to_str().unwrap(), CompilerKind::Gcc).unwrap();
+        let args = |dir: &str| {
+            ParsedArguments {
+                input: format!("{}/foo.c", dir),
+                extension: "c".to_owned(),
+                depfile: None,
+                outputs: HashMap::new(),
+                preprocessor_args: vec![format!("-I{}/include", dir)],
+                common_args: vec![],
+                msvc_show_includes: false,
+            }
+        };
+        let key = |dir: &str, base_dir: Option<&Path>| {
+            direct_key(&c, &args(dir), b"int x;", base_dir, Path::new(dir))
+        };
+        let base_dir = Path::new("/src");
+        assert_eq!(key("/src/a", Some(base_dir)), key("/src/b", Some(base_dir)));
+        assert_neq!(key("/src/a", None), key("/src/b", None));
+    }
+
+    #[test]
+    fn test_manifest_roundtrip() {
+        let manifest = Manifest {
+            key: "abcd".to_owned(),
+            includes: vec![("foo.h".to_owned(), "1234".to_owned()),
+                           ("dir with spaces/bar.h".to_owned(), "5678".to_owned())],
+            depfile: Some(b"foo.o: foo.c foo.h\n".to_vec()),
+        };
+        let mut entry = CacheWrite::new(Codec::Zstd).unwrap();
+        manifest.to_entry(&mut entry).unwrap();
+        let file = entry.finish().unwrap();
+        let mut entry = CacheRead::from(file.open().unwrap()).unwrap();
+        assert_eq!(manifest, Manifest::from_entry(&mut entry).unwrap());
+    }
+}
@@ -0,0 +1,231 @@
+// Copyright 2017 Mozilla Foundation
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Explanations of cache misses.
+//!
+//! The inputs to the hash key of each compilation are recorded in a file
+//! named after its output file. When a compilation misses the cache, its
+//! inputs are compared to those of the previous compilations of the same
+//! output file, and the differences from the closest one are written to a
+//! log.
+
+use futures_cpupool::CpuPool;
+use serde_json::{self, Map, Value};
+use sha1;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+use errors::*;
+
+/// The most compilations of each output file to remember.
+const MAX_RECORDS: usize = 10;
+
+/// The name of the log of explanations, in the explain directory.
+pub const MISSES_LOG: &'static str = "misses.log";
+
+/// The inputs to a hash key.
+#[derive(Clone, Debug, PartialEq)]
+struct KeyRecord {
+    key: String,
+    /// The inputs to the key, by name.
+    components: Vec<(String, String)>,
+}
+
+/// Records hash key inputs in a directory, and explains cache misses.
+#[derive(Clone)]
+pub struct MissExplainer {
+    dir: PathBuf,
+}
+
+impl MissExplainer {
+    /// Keep records and the log of explanations in `dir`.
+    pub fn new(dir: PathBuf) -> MissExplainer {
+        MissExplainer {
+            dir: dir,
+        }
+    }
+
+    /// Remember that `output` was compiled with the hash key `key`, made
+    /// from `components`, and if that missed the cache, log why.
+    pub fn record(&self,
+                  output: &Path,
+                  key: &str,
+                  components: Vec<(String, String)>,
+                  missed: bool,
+                  pool: &CpuPool)
+                  -> SFuture<()> {
+        let dir = self.dir.clone();
+        let output = output.to_owned();
+        let record = KeyRecord {
+            key: key.to_owned(),
+            components: components,
+        };
+        Box::new(pool.spawn_fn(move || -> Result<()> {
+            fs::create_dir_all(&dir)?;
+            let path = dir.join(records_file_name(&output));
+            let mut records = match load_records(&path) {
+                Ok(records) => records,
+                Err(ref e) if e.kind() == io::ErrorKind::NotFound => vec!(),
+                Err(e) => return Err(e.into()),
+            };
+            if missed {
+                let explanation = explain(&records, &record);
+                info!("[{}]: Cache miss: {}", output.display(), explanation);
+                let mut log = OpenOptions::new().create(true).append(true).open(dir.join(MISSES_LOG))?;
+                writeln!(log, "{}: {}", output.display(), explanation)?;
+            }
+            // Keep the most recent compilations, once each.
+            records.retain(|r| r.key != record.key);
+            records.push(record);
+            let excess = records.len().saturating_sub(MAX_RECORDS);
+            records.drain(..excess);
+            save_records(&path, &records)?;
+            Ok(())
+        }))
+    }
+}
+
+/// The name of the file recording the compilations of `output`.
+fn records_file_name(output: &Path) -> String {
+    let mut m = sha1::Sha1::new();
+    m.update(output.to_string_lossy().as_bytes());
+    m.digest().to_string()
+}
+
+/// Explain why `current` doesn't match any of the `previous` records of
+/// the same output file, by comparing it to the one it differs least from.
+fn explain(previous: &[KeyRecord], current: &KeyRecord) -> String {
+    let differences = |record: &KeyRecord| {
+        current.components.iter()
+            .filter_map(|&(ref name, ref value)| {
+                let old = record.components.iter().find(|&&(ref n, _)| n == name).map(|&(_, ref v)| v);
+                match old {
+                    Some(old) if old == value => None,
+                    old => Some((name.clone(), old.cloned(), value.clone())),
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+    // The most recent of the closest records wins.
+    let closest = previous.iter().rev()
+        .map(|r| differences(r))
+        .min_by_key(|d| d.len());
+    match closest {
+        None => "no previous compilation of this output".to_owned(),
+        Some(ref d) if d.is_empty() => {
+            "same inputs as a previous compilation, so its entry was evicted or never stored".to_owned()
+        }
+        Some(d) => {
+            let d = d.into_iter().map(|(name, old, new)| {
+                match old {
+                    Some(old) => format!("{} was `{}`, is now `{}`", name, old, new),
+                    None => format!("{} was not recorded, is now `{}`", name, new),
+                }
+            }).collect::<Vec<_>>();
+            format!("differs from a previous compilation: {}", d.join("; "))
+        }
+    }
+}
+
+/// Read the records in `path`, one JSON object per line.
+fn load_records(path: &Path) -> io::Result<Vec<KeyRecord>> {
+    let mut records = vec!();
+    for line in BufReader::new(File::open(path)?).lines() {
+        let line = line?;
+        let value = match serde_json::from_str::<Value>(&line) {
+            Ok(value) => value,
+            Err(_) => continue,
+        };
+        let key = value.find("key").and_then(|k| k.as_str());
+        let components = value.find("components").and_then(|c| c.as_object());
+        if let (Some(key), Some(components)) = (key, components) {
+            records.push(KeyRecord {
+                key: key.to_owned(),
+                components: components.iter()
+                    .filter_map(|(name, value)| value.as_str().map(|v| (name.clone(), v.to_owned())))
+                    .collect(),
+            });
+        }
+    }
+    Ok(records)
+}
+
+/// Write `records` to `path`, one JSON object per line.
+fn save_records(path: &Path, records: &[KeyRecord]) -> io::Result<()> {
+    let mut contents = String::new();
+    for record in records {
+        let mut components = Map::new();
+        for &(ref name, ref value) in &record.components {
+            components.insert(name.clone(), Value::String(value.clone()));
+        }
+        let mut object = Map::new();
+        object.insert("key".to_owned(), Value::String(record.key.clone()));
+        object.insert("components".to_owned(), Value::Object(components));
+        let line = serde_json::to_string(&Value::Object(object))
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+        contents.push_str(&line);
+        contents.push('\n');
+    }
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    File::create(&tmp)?.write_all(contents.as_bytes())?;
+    fs::rename(&tmp, path)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use futures::Future;
+    use std::io::Read;
+    use tempdir::TempDir;
+
+    fn record(key: &str, arguments: &str, digest: &str) -> KeyRecord {
+        KeyRecord {
+            key: key.to_owned(),
+            components: vec!(("arguments".to_owned(), arguments.to_owned()),
+                             ("preprocessor output digest".to_owned(), digest.to_owned())),
+        }
+    }
+
+    #[test]
+    fn test_explain() {
+        let current = record("k3", "-O2", "def");
+        assert_eq!("no previous compilation of this output", explain(&[], &current));
+        // The record that differs in fewest components is picked.
+        let previous = [record("k1", "-O0", "abc"), record("k2", "-O2", "abc")];
+        assert_eq!("differs from a previous compilation: preprocessor output digest was `abc`, is now `def`",
+                   explain(&previous, &current));
+        assert!(explain(&[record("k3", "-O2", "def")], &current).starts_with("same inputs"));
+    }
+
+    #[test]
+    fn test_record() {
+        let td = TempDir::new("sccache").unwrap();
+        let pool = CpuPool::new(1);
+        let explainer = MissExplainer::new(td.path().join("explain"));
+        let output = Path::new("/build/foo.o");
+        let first = record("k1", "-O0", "abc");
+        explainer.record(output, "k1", first.components.clone(), true, &pool).wait().unwrap();
+        let second = record("k2", "-O2", "abc");
+        explainer.record(output, "k2", second.components.clone(), true, &pool).wait().unwrap();
+        let records = load_records(&td.path().join("explain").join(records_file_name(output))).unwrap();
+        assert_eq!(vec!(first, second), records);
+        let mut log = String::new();
+        File::open(td.path().join("explain").join(MISSES_LOG)).unwrap().read_to_string(&mut log).unwrap();
+        assert_eq!("/build/foo.o: no previous compilation of this output
+/build/foo.o: differs from a previous compilation: arguments was `-O0`, is now `-O2`
+", log);
+    }
+}
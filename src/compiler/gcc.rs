@@ -76,6 +76,8 @@ fn _parse_arguments(arguments: &[String],
     let mut output_arg = None;
     let mut input_arg = None;
     let mut dep_target = None;
+    let mut dep_file = None;
+    let mut writes_dep_file = false;
     let mut common_args = vec!();
     let mut preprocessor_args = vec!();
     let mut compilation = false;
@@ -108,6 +110,9 @@ fn _parse_arguments(arguments: &[String],
             "-MF" | "-MQ" => {
                 preprocessor_args.push(arg.clone());
                 if let Some(arg_val) = it.next() {
+                    if arg == "-MF" {
+                        dep_file = Some(arg_val.clone());
+                    }
                     preprocessor_args.push(arg_val);
                 }
             }
@@ -126,6 +131,9 @@ fn _parse_arguments(arguments: &[String],
                 // need -MT on the preprocessor command line, whether it's
                 // been passed already or not
                 need_explicit_dep_target = true;
+                if arg == "-MD" || arg == "-MMD" {
+                    writes_dep_file = true;
+                }
                 preprocessor_args.push(arg.clone());
             }
             // Other options.
@@ -180,11 +188,22 @@ fn _parse_arguments(arguments: &[String],
             }
         }
     }
+    // With `-MD` and no `-MF`, the preprocessor (which doesn't get `-o`)
+    // names the dependency file after the input file.
+    let depfile = if writes_dep_file {
+        dep_file.or_else(|| {
+            Path::new(&input).file_stem()
+                .and_then(|stem| stem.to_str())
+                .map(|stem| format!("{}.d", stem))
+        })
+    } else {
+        None
+    };
 
     CompilerArguments::Ok(ParsedArguments {
         input: input,
         extension: extension,
-        depfile: None,
+        depfile: depfile,
         outputs: outputs,
         preprocessor_args: preprocessor_args,
         common_args: common_args,
@@ -513,7 +532,7 @@ mod test {
         let ParsedArguments {
             input,
             extension,
-            depfile: _,
+            depfile,
             outputs,
             preprocessor_args,
             msvc_show_includes,
@@ -525,6 +544,7 @@ mod test {
         assert!(true, "Parsed ok");
         assert_eq!("foo.c", input);
         assert_eq!("c", extension);
+        assert_eq!(Some("file".to_owned()), depfile);
         assert_map_contains!(outputs, ("obj", "foo.o"));
         //TODO: fix assert_map_contains to assert no extra keys!
         assert_eq!(1, outputs.len());
@@ -533,6 +553,21 @@ mod test {
         assert!(!msvc_show_includes);
     }
 
+    #[test]
+    fn test_parse_arguments_default_depfile() {
+        let args = stringvec!["-c", "src/foo.c", "-fabc", "-o", "foo.o", "-MMD"];
+        let ParsedArguments {
+            depfile,
+            preprocessor_args,
+            ..
+        } = match _parse_arguments(&args) {
+            CompilerArguments::Ok(args) => args,
+            o @ _ => panic!("Got unexpected parse result: {:?}", o),
+        };
+        assert_eq!(Some("foo.d".to_owned()), depfile);
+        assert_eq!(stringvec!["-MMD", "-MT", "foo.o"], preprocessor_args);
+    }
+
     #[test]
     fn test_parse_arguments_empty_args() {
         assert_eq!(CompilerArguments::NotCompilation,
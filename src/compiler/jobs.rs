@@ -0,0 +1,186 @@
+// Copyright 2017 Mozilla Foundation
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A limit on the number of compiler processes running at once.
+//!
+//! Jobs over the limit wait in a FIFO queue until a running job finishes.
+
+use futures::{future, Future};
+use futures::sync::oneshot;
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+use errors::*;
+
+/// Statistics about the jobs run through a `JobQueue`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct JobStats {
+    /// The number of jobs waiting right now.
+    pub queued: usize,
+    /// The most jobs that have waited at once.
+    pub max_queued: usize,
+    /// The number of jobs that had to wait.
+    pub waited: u64,
+    /// The total time jobs spent waiting.
+    pub wait_duration: Duration,
+}
+
+/// A queue of compiler processes to run, at most `limit` at a time.
+#[derive(Clone)]
+pub struct JobQueue {
+    inner: Rc<RefCell<Inner>>,
+}
+
+struct Inner {
+    /// The most jobs to run at once, or `None` for no limit.
+    limit: Option<usize>,
+    /// The number of jobs running.
+    running: usize,
+    /// The jobs waiting to run, and when they started waiting.
+    waiting: VecDeque<(oneshot::Sender<JobSlot>, Instant)>,
+    stats: JobStats,
+}
+
+/// Permission to run a job. The next job in the queue runs once it's
+/// dropped.
+struct JobSlot {
+    inner: Rc<RefCell<Inner>>,
+}
+
+impl JobQueue {
+    /// Create a new `JobQueue` running at most `limit` jobs at a time.
+    pub fn new(limit: Option<usize>) -> JobQueue {
+        JobQueue {
+            inner: Rc::new(RefCell::new(Inner {
+                limit: limit,
+                running: 0,
+                waiting: VecDeque::new(),
+                stats: JobStats::default(),
+            })),
+        }
+    }
+
+    /// Change the most jobs to run at once.
+    pub fn set_limit(&self, limit: Option<usize>) {
+        self.inner.borrow_mut().limit = limit;
+    }
+
+    /// Run the job `f` creates once there's room for it.
+    ///
+    /// `f` should only start its process when it's called.
+    pub fn run<F, T>(&self, f: F) -> SFuture<T>
+        where F: FnOnce() -> SFuture<T> + 'static,
+              T: 'static,
+    {
+        Box::new(self.acquire().and_then(move |slot| {
+            f().then(move |res| {
+                drop(slot);
+                res
+            })
+        }))
+    }
+
+    /// Get statistics about the jobs run so far.
+    pub fn stats(&self) -> JobStats {
+        self.inner.borrow().stats.clone()
+    }
+
+    /// Reset the statistics, except for the jobs waiting right now.
+    pub fn zero_stats(&self) {
+        let mut inner = self.inner.borrow_mut();
+        let queued = inner.stats.queued;
+        inner.stats = JobStats {
+            queued: queued,
+            max_queued: queued,
+            ..JobStats::default()
+        };
+    }
+
+    /// Wait for room to run a job.
+    fn acquire(&self) -> SFuture<JobSlot> {
+        let mut inner = self.inner.borrow_mut();
+        let inner = &mut *inner;
+        if inner.limit.map_or(true, |limit| inner.running < limit) {
+            inner.running += 1;
+            return Box::new(future::ok(JobSlot { inner: self.inner.clone() }))
+        }
+        let (tx, rx) = oneshot::channel();
+        inner.waiting.push_back((tx, Instant::now()));
+        inner.stats.queued += 1;
+        inner.stats.max_queued = inner.stats.max_queued.max(inner.stats.queued);
+        inner.stats.waited += 1;
+        Box::new(rx.map_err(|_| Error::from("job queue was dropped")))
+    }
+}
+
+impl Drop for JobSlot {
+    fn drop(&mut self) {
+        // Hand this slot over to the next job in the queue, if any.
+        let next = {
+            let mut inner = self.inner.borrow_mut();
+            match inner.waiting.pop_front() {
+                Some((tx, since)) => {
+                    inner.stats.queued -= 1;
+                    inner.stats.wait_duration += since.elapsed();
+                    Some(tx)
+                }
+                None => {
+                    inner.running -= 1;
+                    None
+                }
+            }
+        };
+        if let Some(tx) = next {
+            // If the job is gone, dropping the slot we tried to send it
+            // moves on to the one after it.
+            drop(tx.send(JobSlot { inner: self.inner.clone() }));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_job_queue() {
+        let jobs = JobQueue::new(Some(1));
+        let first = jobs.acquire().wait().unwrap();
+        let second = jobs.acquire();
+        let third = jobs.acquire();
+        assert_eq!(2, jobs.stats().queued);
+        assert_eq!(2, jobs.stats().max_queued);
+        // A job that gives up waiting doesn't hold up the rest.
+        drop(second);
+        drop(first);
+        let third = third.wait().unwrap();
+        let stats = jobs.stats();
+        assert_eq!(0, stats.queued);
+        assert_eq!(2, stats.waited);
+        drop(third);
+        assert_eq!(0, jobs.inner.borrow().running);
+    }
+
+    #[test]
+    fn test_job_queue_unlimited() {
+        let jobs = JobQueue::new(None);
+        let slots = (0..10).map(|_| jobs.acquire().wait().unwrap()).collect::<Vec<_>>();
+        assert_eq!(10, jobs.inner.borrow().running);
+        assert_eq!(JobStats::default(), jobs.stats());
+        drop(slots);
+        assert_eq!(0, jobs.inner.borrow().running);
+    }
+}
@@ -0,0 +1,214 @@
+// Copyright 2017 Mozilla Foundation
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Histograms of how long cache operations and compiler processes take.
+//!
+//! Durations are counted in buckets that grow exponentially, four per
+//! doubling, so percentiles are accurate to within about 10% whatever the
+//! scale, without keeping every duration around.
+
+use futures::Future;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+use errors::*;
+
+/// The number of buckets per doubling of the duration.
+const BUCKETS_PER_DOUBLING: f64 = 4.0;
+
+/// An operation whose latency is recorded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Operation {
+    /// Reading a cache hit.
+    CacheReadHit,
+    /// Looking up a cache miss.
+    CacheReadMiss,
+    /// Writing a cache entry.
+    CacheWrite,
+    /// Running the preprocessor.
+    Preprocess,
+    /// Running the compiler.
+    Compile,
+}
+
+impl Operation {
+    /// All the operations, in the order they're reported.
+    pub fn all() -> &'static [Operation] {
+        static ALL: &'static [Operation] = &[
+            Operation::CacheReadHit,
+            Operation::CacheReadMiss,
+            Operation::CacheWrite,
+            Operation::Preprocess,
+            Operation::Compile,
+        ];
+        ALL
+    }
+
+    /// A description of the operation, for statistics.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Operation::CacheReadHit => "Cache read hit",
+            Operation::CacheReadMiss => "Cache read miss",
+            Operation::CacheWrite => "Cache write",
+            Operation::Preprocess => "Preprocessor run",
+            Operation::Compile => "Compiler run",
+        }
+    }
+}
+
+/// A histogram of durations.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Histogram {
+    /// The number of durations in each bucket.
+    buckets: Vec<u64>,
+    /// The number of durations recorded.
+    count: u64,
+}
+
+impl Histogram {
+    pub fn new() -> Histogram {
+        Histogram::default()
+    }
+
+    /// Record `duration`.
+    pub fn record(&mut self, duration: Duration) {
+        let bucket = bucket(duration);
+        if self.buckets.len() <= bucket {
+            self.buckets.resize(bucket + 1, 0);
+        }
+        self.buckets[bucket] += 1;
+        self.count += 1;
+    }
+
+    /// The number of durations recorded.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Estimate the duration that the fraction `p` of the recorded
+    /// durations don't exceed, or zero if there aren't any.
+    pub fn percentile(&self, p: f64) -> Duration {
+        if self.count == 0 {
+            return Duration::new(0, 0)
+        }
+        let rank = ((p * self.count as f64).ceil() as u64).max(1);
+        let mut seen = 0;
+        for (i, &n) in self.buckets.iter().enumerate() {
+            seen += n;
+            if seen >= rank {
+                return bucket_midpoint(i)
+            }
+        }
+        bucket_midpoint(self.buckets.len() - 1)
+    }
+}
+
+/// The bucket `duration` goes in. Bucket 0 holds durations under a
+/// microsecond.
+fn bucket(duration: Duration) -> usize {
+    let micros = duration.as_secs() as f64 * 1e6 + duration.subsec_nanos() as f64 / 1e3;
+    if micros < 1.0 {
+        0
+    } else {
+        1 + (micros.log2() * BUCKETS_PER_DOUBLING) as usize
+    }
+}
+
+/// A duration in the middle of `bucket`, on a log scale.
+fn bucket_midpoint(bucket: usize) -> Duration {
+    if bucket == 0 {
+        return Duration::new(0, 0)
+    }
+    let micros = ((bucket as f64 - 0.5) / BUCKETS_PER_DOUBLING).exp2();
+    let nanos = (micros * 1e3) as u64;
+    Duration::new(nanos / 1_000_000_000, (nanos % 1_000_000_000) as u32)
+}
+
+/// Latency histograms for each operation, shared between the tasks that
+/// record them.
+#[derive(Clone)]
+pub struct Latencies {
+    histograms: Rc<RefCell<HashMap<Operation, Histogram>>>,
+}
+
+impl Latencies {
+    pub fn new() -> Latencies {
+        Latencies {
+            histograms: Rc::new(RefCell::new(HashMap::new())),
+        }
+    }
+
+    /// Record that `op` took `duration`.
+    pub fn record(&self, op: Operation, duration: Duration) {
+        self.histograms.borrow_mut().entry(op).or_insert_with(Histogram::new).record(duration);
+    }
+
+    /// Record how long `f` takes to finish as the latency of `op`.
+    pub fn time<T: 'static>(&self, op: Operation, f: SFuture<T>) -> SFuture<T> {
+        let me = self.clone();
+        let start = Instant::now();
+        Box::new(f.then(move |res| {
+            me.record(op, start.elapsed());
+            res
+        }))
+    }
+
+    /// Get the histogram of the latencies of `op`.
+    pub fn histogram(&self, op: Operation) -> Histogram {
+        self.histograms.borrow().get(&op).cloned().unwrap_or_default()
+    }
+
+    /// Forget all the latencies recorded so far.
+    pub fn zero(&self) {
+        self.histograms.borrow_mut().clear();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use futures::future;
+
+    #[test]
+    fn test_histogram_percentiles() {
+        let mut histogram = Histogram::new();
+        assert_eq!(Duration::new(0, 0), histogram.percentile(0.5));
+        for ms in 1..101 {
+            histogram.record(Duration::from_millis(ms));
+        }
+        assert_eq!(100, histogram.count());
+        // Estimates are within the accuracy of the buckets.
+        for &(p, expected) in &[(0.5, 50.0), (0.9, 90.0), (0.99, 99.0)] {
+            let estimate = histogram.percentile(p);
+            let ms = estimate.as_secs() as f64 * 1e3 + estimate.subsec_nanos() as f64 / 1e6;
+            assert!((ms - expected).abs() / expected < 0.1,
+                    "p{} was {} ms, expected about {} ms", p * 100.0, ms, expected);
+        }
+    }
+
+    #[test]
+    fn test_latencies() {
+        let latencies = Latencies::new();
+        latencies.record(Operation::CacheWrite, Duration::from_millis(5));
+        let res = latencies.time(Operation::Compile, Box::new(future::ok::<_, Error>(1))).wait();
+        assert_eq!(1, res.unwrap());
+        assert_eq!(1, latencies.histogram(Operation::CacheWrite).count());
+        assert_eq!(1, latencies.histogram(Operation::Compile).count());
+        assert_eq!(0, latencies.histogram(Operation::Preprocess).count());
+        latencies.zero();
+        assert_eq!(0, latencies.histogram(Operation::CacheWrite).count());
+    }
+}
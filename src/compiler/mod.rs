@@ -12,9 +12,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod basedir;
 mod clang;
+mod coalesce;
 mod compiler;
+mod direct;
+mod explain;
 mod gcc;
+mod jobs;
+mod latency;
 mod msvc;
+mod rustc;
+mod timeline;
 
+pub use compiler::coalesce::InFlightCompiles;
 pub use compiler::compiler::*;
+pub use compiler::explain::{MISSES_LOG, MissExplainer};
+pub use compiler::jobs::{JobQueue, JobStats};
+pub use compiler::latency::{Histogram, Latencies, Operation};
+pub use compiler::timeline::{Phase, Timeline};
@@ -0,0 +1,641 @@
+// Copyright 2017 Mozilla Foundation
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Support for caching rustc compilations.
+//!
+//! rustc has no preprocessor, so "preprocessing" a crate means running
+//! `rustc --emit=dep-info` to learn which source files it is built from, and
+//! producing a listing of the digests of those files, the crates passed with
+//! `--extern` and the compiler's sysroot in place of preprocessor output.
+//!
+//! In `ParsedArguments`, `preprocessor_args` holds the `--extern`, `-L` and
+//! `-l` arguments, which are needed to run rustc but aren't hashed as-is,
+//! since they name the dependencies, whose contents are hashed instead.
+
+use ::compiler::{
+    Cacheable,
+    Compiler,
+    CompilerArguments,
+    ParsedArguments,
+    run_input_output,
+};
+use futures::future::{self, Future};
+use futures_cpupool::CpuPool;
+use log::LogLevel::Trace;
+use mock_command::{
+    CommandCreatorSync,
+    RunCommand,
+};
+use sha1;
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::str;
+use tempdir::TempDir;
+
+use errors::*;
+
+/// The kinds of `--emit` output that can be cached, and the name of each in
+/// `ParsedArguments::outputs`.
+const EMIT_KINDS: &'static [(&'static str, &'static str)] = &[
+    ("link", "rlib"),
+    ("metadata", "rmeta"),
+    ("dep-info", "d"),
+];
+
+/// Arguments that take a value, either as the next argument, joined with
+/// `=` for long arguments, or appended directly for short ones.
+const ARGS_WITH_VALUE: &'static [&'static str] = &[
+    "--cap-lints",
+    "--cfg",
+    "--codegen",
+    "--color",
+    "--crate-name",
+    "--crate-type",
+    "--edition",
+    "--emit",
+    "--error-format",
+    "--explain",
+    "--extern",
+    "--json",
+    "--out-dir",
+    "--print",
+    "--remap-path-prefix",
+    "--sysroot",
+    "--target",
+    "-A",
+    "-C",
+    "-D",
+    "-F",
+    "-L",
+    "-W",
+    "-Z",
+    "-l",
+    "-o",
+];
+
+pub fn parse_arguments(arguments: &[String]) -> CompilerArguments {
+    let mut input = None;
+    let mut crate_name = None;
+    let mut crate_types = vec!();
+    let mut emit = vec!();
+    let mut out_dir = None;
+    let mut extra_filename = String::new();
+    let mut dep_args = vec!();
+    let mut common_args = vec!();
+    let mut not_compilation = false;
+    let mut cannot_cache = false;
+
+    let mut it = arguments.iter();
+    while let Some(arg) = it.next() {
+        let (flag, value) = if arg.starts_with("--") {
+            match arg.find('=') {
+                Some(i) => (&arg[..i], Some(arg[i + 1..].to_owned())),
+                None if ARGS_WITH_VALUE.contains(&arg.as_str()) => (arg.as_str(), it.next().cloned()),
+                None => (arg.as_str(), None),
+            }
+        } else if arg.len() > 2 && arg.is_char_boundary(2) && ARGS_WITH_VALUE.contains(&&arg[..2]) {
+            (&arg[..2], Some(arg[2..].to_owned()))
+        } else if ARGS_WITH_VALUE.contains(&arg.as_str()) {
+            (arg.as_str(), it.next().cloned())
+        } else {
+            (arg.as_str(), None)
+        };
+        if value.is_none() && ARGS_WITH_VALUE.contains(&flag) {
+            trace!("Missing value for argument {}", flag);
+            return CompilerArguments::CannotCache;
+        }
+        match flag {
+            "--print" | "--explain" | "-V" | "-vV" | "--version" | "-h" | "--help" => not_compilation = true,
+            "--crate-name" => crate_name = value.clone(),
+            "--crate-type" => {
+                crate_types.extend(value.as_ref().unwrap().split(',').map(|t| t.to_owned()));
+            }
+            "--emit" => {
+                emit.extend(value.unwrap().split(',').map(|e| e.to_owned()));
+                continue;
+            }
+            "--out-dir" => {
+                out_dir = value;
+                continue;
+            }
+            "--extern" | "-L" | "-l" => {
+                dep_args.push(flag.to_owned());
+                dep_args.push(value.unwrap());
+                continue;
+            }
+            "-C" | "--codegen" => {
+                let value = value.as_ref().unwrap();
+                if value.starts_with("extra-filename=") {
+                    extra_filename = value["extra-filename=".len()..].to_owned();
+                } else if value.starts_with("incremental=") {
+                    cannot_cache = true;
+                }
+            }
+            // Output names can't be determined in advance.
+            "-o" => cannot_cache = true,
+            // Tests are linked into an executable.
+            "--test" => cannot_cache = true,
+            // Reading the source from stdin.
+            "-" => cannot_cache = true,
+            f if !f.starts_with('-') => {
+                if input.is_some() {
+                    // Can't cache compilations with multiple inputs.
+                    cannot_cache = true;
+                }
+                input = Some(f.to_owned());
+                continue;
+            }
+            _ => {}
+        }
+        common_args.push(flag.to_owned());
+        if let Some(value) = value {
+            common_args.push(value);
+        }
+    }
+    if not_compilation {
+        return CompilerArguments::NotCompilation;
+    }
+    if cannot_cache {
+        return CompilerArguments::CannotCache;
+    }
+    let input = match input {
+        Some(input) => input,
+        // We can't cache compilation without an input.
+        None => return CompilerArguments::CannotCache,
+    };
+    let crate_name = match crate_name {
+        Some(crate_name) => crate_name,
+        None => return CompilerArguments::CannotCache,
+    };
+    // Only libraries can be cached: anything else gets linked against
+    // system libraries we know nothing about.
+    if crate_types.is_empty() || crate_types.iter().any(|t| t != "lib" && t != "rlib") {
+        trace!("Unsupported crate types: {:?}", crate_types);
+        return CompilerArguments::CannotCache;
+    }
+    if emit.is_empty() {
+        emit.push("link".to_owned());
+    }
+    if let Some(e) = emit.iter().find(|e| !EMIT_KINDS.iter().any(|&(kind, _)| kind == e.as_str())) {
+        trace!("Unsupported emit kind: {}", e);
+        return CompilerArguments::CannotCache;
+    }
+    let out_dir = PathBuf::from(out_dir.unwrap_or(".".to_owned()));
+    let mut outputs = HashMap::new();
+    for &(kind, key) in EMIT_KINDS.iter() {
+        if !emit.iter().any(|e| e == kind) {
+            continue;
+        }
+        let filename = match key {
+            "d" => format!("{}{}.d", crate_name, extra_filename),
+            ext => format!("lib{}{}.{}", crate_name, extra_filename, ext),
+        };
+        outputs.insert(key, out_dir.join(filename).to_string_lossy().into_owned());
+    }
+    let extension = Path::new(&input).extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("")
+        .to_owned();
+
+    CompilerArguments::Ok(ParsedArguments {
+        input: input,
+        extension: extension,
+        depfile: outputs.get("d").cloned(),
+        outputs: outputs,
+        preprocessor_args: dep_args,
+        common_args: common_args,
+        msvc_show_includes: false,
+    })
+}
+
+/// Return the `--emit` kinds that produce the outputs in `parsed_args`.
+fn emit_kinds(parsed_args: &ParsedArguments) -> Vec<&'static str> {
+    EMIT_KINDS.iter()
+        .filter(|&&(_, key)| parsed_args.outputs.contains_key(key))
+        .map(|&(kind, _)| kind)
+        .collect()
+}
+
+/// Find the sysroot of the rustc at `executable`, and return a digest of the
+/// compiler libraries and standard library crates in it.
+pub fn sysroot_digest<T>(creator: &T, executable: &str, pool: &CpuPool)
+                         -> SFuture<String>
+    where T: CommandCreatorSync
+{
+    let mut cmd = creator.clone().new_command_sync(&executable);
+    cmd.arg("--print=sysroot");
+    if log_enabled!(Trace) {
+        trace!("sysroot_digest: {:?}", cmd);
+    }
+    let pool = pool.clone();
+    Box::new(run_input_output(cmd, None).and_then(move |output| -> SFuture<_> {
+        if !output.status.success() {
+            return future::err("failed to find rustc sysroot".into()).boxed()
+        }
+        let sysroot = match str::from_utf8(&output.stdout) {
+            Ok(s) => PathBuf::from(s.trim()),
+            Err(_) => return future::err("Failed to parse output".into()).boxed(),
+        };
+        trace!("rustc sysroot: {:?}", sysroot);
+        pool.spawn_fn(move || hash_sysroot(&sysroot))
+            .chain_err(|| "failed to hash rustc sysroot")
+    }))
+}
+
+/// Return a digest of the files in `sysroot/lib`, where the compiler
+/// libraries live, and in `sysroot/lib/rustlib/<target>/lib`, where the
+/// standard library crates live.
+fn hash_sysroot(sysroot: &Path) -> Result<String> {
+    let lib = sysroot.join("lib");
+    let mut files = list_files(&lib)?;
+    let rustlib = lib.join("rustlib");
+    if rustlib.is_dir() {
+        for target in fs::read_dir(&rustlib)? {
+            let target_lib = target?.path().join("lib");
+            if target_lib.is_dir() {
+                files.extend(list_files(&target_lib)?);
+            }
+        }
+    }
+    files.sort();
+    let mut m = sha1::Sha1::new();
+    for file in files {
+        m.update(hash_file(&file)?.as_bytes());
+    }
+    Ok(m.digest().to_string())
+}
+
+/// Return the paths of the regular files in `dir`.
+fn list_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut files = vec!();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_file() {
+            files.push(path);
+        }
+    }
+    Ok(files)
+}
+
+/// Return the sha-1 digest of the contents of `path` as a hex string.
+fn hash_file(path: &Path) -> Result<String> {
+    let mut contents = vec!();
+    File::open(path)
+        .and_then(|mut f| f.read_to_end(&mut contents))
+        .chain_err(|| format!("failed to read {:?}", path))?;
+    let mut m = sha1::Sha1::new();
+    m.update(&contents);
+    Ok(m.digest().to_string())
+}
+
+/// Parse the output of `rustc --emit=dep-info`, returning the source files
+/// it lists, and the environment variables read with `env!`, as
+/// `NAME=value`.
+fn parse_dep_info(dep_info: &str) -> (Vec<String>, Vec<String>) {
+    let mut sources = vec!();
+    let mut env_deps = vec!();
+    for line in dep_info.lines() {
+        if line.starts_with("# env-dep:") {
+            env_deps.push(line["# env-dep:".len()..].to_owned());
+        } else if line.ends_with(':') && !line.starts_with('#') {
+            // Every source file gets a rule of its own with no dependencies.
+            sources.push(line[..line.len() - 1].replace("\\ ", " "));
+        }
+    }
+    (sources, env_deps)
+}
+
+/// Produce the stand-in for preprocessor output for the compilation
+/// described by `parsed_args`, given the dep-info file rustc wrote into
+/// `dep_info_dir`.
+fn hash_inputs(dep_info_dir: &Path,
+               parsed_args: &ParsedArguments,
+               cwd: &Path,
+               sysroot_digest: &str)
+               -> Result<Vec<u8>> {
+    let mut out = format!("sysroot {}\n", sysroot_digest);
+    out.push_str(&format!("emit {}\n", emit_kinds(parsed_args).join(",")));
+    // The dep-info file names the outputs by their full path, so a cached
+    // one is only valid for the same `--out-dir`.
+    if let Some(dep_info) = parsed_args.outputs.get("d") {
+        out.push_str(&format!("dep-info {}\n", dep_info));
+    }
+    // rustc names the file after the crate, so just take whatever it wrote.
+    let dep_info = match list_files(dep_info_dir)?.into_iter().find(|p| p.extension().map_or(false, |e| e == "d")) {
+        Some(dep_info) => dep_info,
+        None => bail!("rustc didn't write a dep-info file"),
+    };
+    let mut contents = String::new();
+    File::open(&dep_info)?.read_to_string(&mut contents)?;
+    let (sources, env_deps) = parse_dep_info(&contents);
+    for source in sources {
+        out.push_str(&format!("source {} {}\n", hash_file(&cwd.join(&source))?, source));
+    }
+    for env_dep in env_deps {
+        out.push_str(&format!("env {}\n", env_dep));
+    }
+    let mut native_dirs = vec!();
+    let mut it = parsed_args.preprocessor_args.iter();
+    while let (Some(flag), Some(value)) = (it.next(), it.next()) {
+        if flag == "-L" {
+            // Native libraries are searched for in `native` and `all` dirs.
+            let (kind, dir) = match value.find('=') {
+                Some(i) => (&value[..i], &value[i + 1..]),
+                None => ("all", &value[..]),
+            };
+            if kind == "native" || kind == "all" {
+                native_dirs.push(cwd.join(dir));
+            }
+        }
+    }
+    let mut it = parsed_args.preprocessor_args.iter();
+    while let (Some(flag), Some(value)) = (it.next(), it.next()) {
+        match flag.as_str() {
+            "--extern" => {
+                match value.find('=') {
+                    Some(i) => {
+                        let digest = hash_file(&cwd.join(&value[i + 1..]))?;
+                        out.push_str(&format!("extern {} {}\n", digest, &value[..i]));
+                    }
+                    None => out.push_str(&format!("extern {}\n", value)),
+                }
+            }
+            "-l" => {
+                // Static libraries get bundled into the rlib, so their
+                // contents have to be hashed.
+                match find_static_lib(&native_dirs, value) {
+                    Some(lib) => {
+                        out.push_str(&format!("lib {} {}\n", hash_file(&lib)?, value));
+                    }
+                    None => out.push_str(&format!("lib {}\n", value)),
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(out.into_bytes())
+}
+
+/// Find the static library that rustc would link for the argument `-l
+/// <lib>`, in the directories `dirs`.
+fn find_static_lib(dirs: &[PathBuf], lib: &str) -> Option<PathBuf> {
+    // The argument is `[kind[:modifiers]=]name[:rename]`.
+    let name = match lib.find('=') {
+        Some(i) => &lib[i + 1..],
+        None => lib,
+    };
+    let name = name.split(':').next().unwrap();
+    let filenames = [format!("lib{}.a", name), format!("{}.lib", name)];
+    dirs.iter()
+        .flat_map(|dir| filenames.iter().map(move |f| dir.join(f)))
+        .find(|path| path.is_file())
+}
+
+pub fn preprocess<T>(creator: &T,
+                     compiler: &Compiler,
+                     parsed_args: &ParsedArguments,
+                     cwd: &str,
+                     sysroot_digest: &str,
+                     pool: &CpuPool)
+                     -> SFuture<process::Output>
+    where T: CommandCreatorSync
+{
+    trace!("preprocess");
+    let mut cmd = creator.clone().new_command_sync(&compiler.executable);
+    cmd.arg(&parsed_args.input)
+        .args(&parsed_args.common_args)
+        .args(&parsed_args.preprocessor_args)
+        .arg("--emit=dep-info")
+        .current_dir(cwd);
+    let tempdir = pool.spawn_fn(|| TempDir::new("sccache"))
+        .chain_err(|| "failed to create temporary directory");
+    let parsed_args = parsed_args.clone();
+    let cwd = PathBuf::from(cwd);
+    let sysroot_digest = sysroot_digest.to_owned();
+    let pool = pool.clone();
+    Box::new(tempdir.and_then(move |tempdir| {
+        cmd.arg("--out-dir").arg(tempdir.path());
+        if log_enabled!(Trace) {
+            trace!("preprocess: {:?}", cmd);
+        }
+        run_input_output(cmd, None).and_then(move |output| -> SFuture<_> {
+            if !output.status.success() {
+                return future::ok(output).boxed()
+            }
+            pool.spawn_fn(move || -> Result<_> {
+                let stdout = hash_inputs(tempdir.path(), &parsed_args, &cwd, &sysroot_digest)?;
+                Ok(process::Output {
+                    stdout: stdout,
+                    ..output
+                })
+            }).chain_err(|| "failed to hash rustc inputs")
+        })
+    }))
+}
+
+pub fn compile<T>(creator: &T,
+                  compiler: &Compiler,
+                  _preprocessor_result: process::Output,
+                  parsed_args: &ParsedArguments,
+                  cwd: &str,
+                  _pool: &CpuPool)
+                  -> SFuture<(Cacheable, process::Output)>
+    where T: CommandCreatorSync
+{
+    trace!("compile");
+    let out_dir = match parsed_args.outputs.values().next().and_then(|o| Path::new(o).parent()) {
+        Some(out_dir) => out_dir,
+        None => return future::err("Missing output directory".into()).boxed(),
+    };
+    let mut cmd = creator.clone().new_command_sync(&compiler.executable);
+    cmd.arg(&parsed_args.input)
+        .args(&parsed_args.common_args)
+        .args(&parsed_args.preprocessor_args)
+        .arg(format!("--emit={}", emit_kinds(parsed_args).join(",")))
+        .arg("--out-dir")
+        .arg(out_dir)
+        .current_dir(cwd);
+    if log_enabled!(Trace) {
+        trace!("compile: {:?}", cmd);
+    }
+    Box::new(run_input_output(cmd, None).map(|output| {
+        (Cacheable::Yes, output)
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    use compiler::*;
+    use super::*;
+    use std::io::Write;
+    use test::utils::*;
+
+    fn parse(arguments: &[String]) -> ParsedArguments {
+        match parse_arguments(arguments) {
+            CompilerArguments::Ok(args) => args,
+            o @ _ => panic!("Got unexpected parse result: {:?}", o),
+        }
+    }
+
+    #[test]
+    fn test_parse_arguments_cargo() {
+        let args = stringvec!["--crate-name", "foo", "src/lib.rs",
+                              "--crate-type", "lib",
+                              "--emit=dep-info,link",
+                              "-C", "debuginfo=2",
+                              "-C", "metadata=0123",
+                              "-C", "extra-filename=-0123",
+                              "--out-dir", "/out",
+                              "-L", "dependency=/out",
+                              "--extern", "bar=/out/libbar-4567.rlib"];
+        let ParsedArguments {
+            input,
+            extension,
+            depfile,
+            outputs,
+            preprocessor_args,
+            msvc_show_includes,
+            common_args,
+        } = parse(&args);
+        assert_eq!("src/lib.rs", input);
+        assert_eq!("rs", extension);
+        assert_eq!(Some("/out/foo-0123.d".to_owned()), depfile);
+        assert_map_contains!(outputs,
+                             ("rlib", "/out/libfoo-0123.rlib"),
+                             ("d", "/out/foo-0123.d"));
+        assert_eq!(2, outputs.len());
+        assert_eq!(stringvec!["-L", "dependency=/out", "--extern", "bar=/out/libbar-4567.rlib"],
+                   preprocessor_args);
+        assert_eq!(stringvec!["--crate-name", "foo", "--crate-type", "lib",
+                              "-C", "debuginfo=2", "-C", "metadata=0123",
+                              "-C", "extra-filename=-0123"],
+                   common_args);
+        assert!(!msvc_show_includes);
+    }
+
+    #[test]
+    fn test_parse_arguments_joined_values() {
+        let args = stringvec!["--crate-name=foo", "lib.rs", "--crate-type=rlib",
+                              "--emit=metadata", "-Cextra-filename=-abc", "-Ldeps"];
+        let parsed = parse(&args);
+        assert_map_contains!(parsed.outputs, ("rmeta", "./libfoo-abc.rmeta"));
+        assert_eq!(1, parsed.outputs.len());
+        assert_eq!(None, parsed.depfile);
+        assert_eq!(stringvec!["-L", "deps"], parsed.preprocessor_args);
+        assert_eq!(stringvec!["--crate-name", "foo", "--crate-type", "rlib",
+                              "-C", "extra-filename=-abc"],
+                   parsed.common_args);
+    }
+
+    #[test]
+    fn test_parse_arguments_default_emit() {
+        let args = stringvec!["--crate-name", "foo", "lib.rs", "--crate-type", "lib"];
+        let parsed = parse(&args);
+        assert_map_contains!(parsed.outputs, ("rlib", "./libfoo.rlib"));
+        assert_eq!(1, parsed.outputs.len());
+    }
+
+    #[test]
+    fn test_parse_arguments_not_compilation() {
+        assert_eq!(CompilerArguments::NotCompilation,
+                   parse_arguments(&stringvec!["-", "--crate-name", "___", "--print=file-names"]));
+        assert_eq!(CompilerArguments::NotCompilation,
+                   parse_arguments(&stringvec!["-vV"]));
+    }
+
+    #[test]
+    fn test_parse_arguments_cannot_cache() {
+        // No crate name.
+        assert_eq!(CompilerArguments::CannotCache,
+                   parse_arguments(&stringvec!["lib.rs", "--crate-type", "lib"]));
+        // Binaries get linked.
+        assert_eq!(CompilerArguments::CannotCache,
+                   parse_arguments(&stringvec!["--crate-name", "foo", "main.rs", "--crate-type", "bin"]));
+        // No explicit crate type.
+        assert_eq!(CompilerArguments::CannotCache,
+                   parse_arguments(&stringvec!["--crate-name", "foo", "main.rs"]));
+        // Unsupported emit kind.
+        assert_eq!(CompilerArguments::CannotCache,
+                   parse_arguments(&stringvec!["--crate-name", "foo", "lib.rs", "--crate-type", "lib", "--emit", "asm"]));
+        // Incremental compilation.
+        assert_eq!(CompilerArguments::CannotCache,
+                   parse_arguments(&stringvec!["--crate-name", "foo", "lib.rs", "--crate-type", "lib", "-C", "incremental=/tmp"]));
+        // Missing value.
+        assert_eq!(CompilerArguments::CannotCache,
+                   parse_arguments(&stringvec!["--crate-name", "foo", "lib.rs", "--crate-type"]));
+    }
+
+    #[test]
+    fn test_hash_inputs_native_libs() {
+        let f = TestFixture::new();
+        let dep_info_dir = f.tempdir.path().join("dep-info");
+        fs::create_dir(&dep_info_dir).unwrap();
+        File::create(dep_info_dir.join("foo.d")).unwrap()
+            .write_all(b"foo.d: lib.rs\n\nlib.rs:\n").unwrap();
+        File::create(f.tempdir.path().join("lib.rs")).unwrap();
+        fs::create_dir(f.tempdir.path().join("native")).unwrap();
+        let write_lib = |contents: &[u8]| {
+            File::create(f.tempdir.path().join("native/libbar.a")).unwrap()
+                .write_all(contents).unwrap();
+        };
+        let args = stringvec!["--crate-name", "foo", "lib.rs", "--crate-type", "lib",
+                              "-L", "native=native", "-l", "static=bar"];
+        let parsed = parse(&args);
+        assert_eq!(stringvec!["-L", "native=native", "-l", "static=bar"],
+                   parsed.preprocessor_args);
+        let hash = || hash_inputs(&dep_info_dir, &parsed, f.tempdir.path(), "sysroot").unwrap();
+        write_lib(b"one");
+        let one = hash();
+        // Rebuilding the library at the same path changes the hash.
+        write_lib(b"two");
+        assert_neq!(one, hash());
+    }
+
+    #[test]
+    fn test_hash_inputs_dep_info_out_dir() {
+        let f = TestFixture::new();
+        let dep_info_dir = f.tempdir.path().join("dep-info");
+        fs::create_dir(&dep_info_dir).unwrap();
+        File::create(dep_info_dir.join("foo.d")).unwrap()
+            .write_all(b"foo.d: lib.rs\n\nlib.rs:\n").unwrap();
+        File::create(f.tempdir.path().join("lib.rs")).unwrap();
+        let hash = |args: &[String]| {
+            hash_inputs(&dep_info_dir, &parse(args), f.tempdir.path(), "sysroot").unwrap()
+        };
+        let emit = |out_dir: &str, emit: &str| {
+            stringvec!["--crate-name", "foo", "lib.rs", "--crate-type", "lib",
+                       "--out-dir", out_dir, "--emit", emit]
+        };
+        // The out-dir only matters when the dep-info file is cached.
+        assert_eq!(hash(&emit("/a", "link")), hash(&emit("/b", "link")));
+        assert_neq!(hash(&emit("/a", "link,dep-info")), hash(&emit("/b", "link,dep-info")));
+    }
+
+    #[test]
+    fn test_parse_dep_info() {
+        let dep_info = "/out/foo-0123.d: src/lib.rs src/some\\ file.rs
+
+src/lib.rs:
+src/some\\ file.rs:
+
+# env-dep:CARGO_PKG_NAME=foo
+";
+        let (sources, env_deps) = parse_dep_info(dep_info);
+        assert_eq!(stringvec!["src/lib.rs", "src/some file.rs"], sources);
+        assert_eq!(stringvec!["CARGO_PKG_NAME=foo"], env_deps);
+    }
+}
@@ -0,0 +1,224 @@
+// Copyright 2017 Mozilla Foundation
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A timeline of the phases of each compile request, to see where the time
+//! goes in a build.
+//!
+//! The timeline is written out in the Chrome trace event format, which
+//! `chrome://tracing` can open, with a row for each object file.
+
+use futures::Future;
+use serde_json::{Map, Value};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+use errors::*;
+
+/// A phase of a compile request.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Phase {
+    /// Finding out what kind of compiler it is.
+    DetectCompiler,
+    /// Running the preprocessor.
+    Preprocess,
+    /// Looking up the result in the cache.
+    CacheLookup,
+    /// Running the compiler.
+    Compile,
+    /// Storing the result in the cache.
+    CacheWrite,
+}
+
+impl Phase {
+    /// A description of the phase, for the timeline.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Phase::DetectCompiler => "Compiler detection",
+            Phase::Preprocess => "Preprocess",
+            Phase::CacheLookup => "Cache lookup",
+            Phase::Compile => "Compile",
+            Phase::CacheWrite => "Cache write",
+        }
+    }
+}
+
+/// A phase of the compilation of an object file.
+struct Span {
+    phase: Phase,
+    object_file: String,
+    /// When the phase began and ended, since the timeline started.
+    begin: Duration,
+    end: Duration,
+}
+
+struct Spans {
+    /// When the timeline started.
+    origin: Instant,
+    spans: Vec<Span>,
+}
+
+/// The spans of the phases of compile requests, shared between the tasks
+/// that record them. A disabled timeline doesn't record anything.
+#[derive(Clone)]
+pub struct Timeline {
+    spans: Option<Rc<RefCell<Spans>>>,
+}
+
+impl Timeline {
+    /// Start a timeline, from now.
+    pub fn new() -> Timeline {
+        Timeline {
+            spans: Some(Rc::new(RefCell::new(Spans {
+                origin: Instant::now(),
+                spans: vec!(),
+            }))),
+        }
+    }
+
+    /// A timeline that doesn't record anything.
+    pub fn disabled() -> Timeline {
+        Timeline {
+            spans: None,
+        }
+    }
+
+    /// Whether spans are being recorded.
+    pub fn is_enabled(&self) -> bool {
+        self.spans.is_some()
+    }
+
+    /// Record that `phase` of compiling `object_file` ran from `begin` to
+    /// `end`.
+    pub fn record(&self, phase: Phase, object_file: &str, begin: Instant, end: Instant) {
+        if let Some(ref spans) = self.spans {
+            let mut spans = spans.borrow_mut();
+            let origin = spans.origin;
+            // Anything before the timeline started is clamped to its start.
+            let since_origin = |t: Instant| {
+                if t > origin { t - origin } else { Duration::new(0, 0) }
+            };
+            spans.spans.push(Span {
+                phase: phase,
+                object_file: object_file.to_owned(),
+                begin: since_origin(begin),
+                end: since_origin(end),
+            });
+        }
+    }
+
+    /// Record the time `f` takes to finish as `phase` of compiling
+    /// `object_file`.
+    pub fn span<T: 'static>(&self, phase: Phase, object_file: &str, f: SFuture<T>) -> SFuture<T> {
+        if !self.is_enabled() {
+            return f
+        }
+        let me = self.clone();
+        let object_file = object_file.to_owned();
+        let begin = Instant::now();
+        Box::new(f.then(move |res| {
+            me.record(phase, &object_file, begin, Instant::now());
+            res
+        }))
+    }
+
+    /// The timeline in the Chrome trace event format, with each object file
+    /// on its own row.
+    pub fn to_trace_events(&self) -> Value {
+        fn micros(d: Duration) -> u64 {
+            d.as_secs() * 1_000_000 + d.subsec_nanos() as u64 / 1_000
+        }
+        fn event(ph: &str, name: &str, tid: u64) -> Map<String, Value> {
+            let mut event = Map::new();
+            event.insert("ph".to_owned(), Value::String(ph.to_owned()));
+            event.insert("name".to_owned(), Value::String(name.to_owned()));
+            event.insert("pid".to_owned(), Value::U64(1));
+            event.insert("tid".to_owned(), Value::U64(tid));
+            event
+        }
+        fn args(key: &str, value: &str) -> Value {
+            let mut args = Map::new();
+            args.insert(key.to_owned(), Value::String(value.to_owned()));
+            Value::Object(args)
+        }
+        let mut events = vec!();
+        let mut process_name = event("M", "process_name", 0);
+        process_name.insert("args".to_owned(), args("name", "sccache"));
+        events.push(Value::Object(process_name));
+        if let Some(ref spans) = self.spans {
+            // Number the rows in the order the object files first appear.
+            let mut rows = HashMap::new();
+            for span in &spans.borrow().spans {
+                let next = rows.len() as u64 + 1;
+                let tid = *rows.entry(span.object_file.clone()).or_insert_with(|| {
+                    let mut thread_name = event("M", "thread_name", next);
+                    thread_name.insert("args".to_owned(), args("name", &span.object_file));
+                    events.push(Value::Object(thread_name));
+                    next
+                });
+                let mut complete = event("X", span.phase.name(), tid);
+                complete.insert("cat".to_owned(), Value::String("sccache".to_owned()));
+                complete.insert("ts".to_owned(), Value::U64(micros(span.begin)));
+                complete.insert("dur".to_owned(), Value::U64(micros(span.end - span.begin)));
+                complete.insert("args".to_owned(), args("object_file", &span.object_file));
+                events.push(Value::Object(complete));
+            }
+        }
+        let mut trace = Map::new();
+        trace.insert("traceEvents".to_owned(), Value::Array(events));
+        trace.insert("displayTimeUnit".to_owned(), Value::String("ms".to_owned()));
+        Value::Object(trace)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use futures::future;
+
+    #[test]
+    fn test_timeline() {
+        let timeline = Timeline::new();
+        let start = Instant::now();
+        timeline.record(Phase::Preprocess, "foo.o", start, start + Duration::from_millis(3));
+        timeline.record(Phase::Preprocess, "bar.o", start, start + Duration::from_millis(1));
+        let res = timeline.span(Phase::Compile, "foo.o", Box::new(future::ok::<_, Error>(1))).wait();
+        assert_eq!(1, res.unwrap());
+        let trace = timeline.to_trace_events();
+        let events = trace.find("traceEvents").and_then(|e| e.as_array()).unwrap();
+        let describe = |e: &Value| {
+            let get = |k| e.find(k).and_then(|v| v.as_str()).unwrap_or("").to_owned();
+            (get("ph"), get("name"), e.find("tid").and_then(|v| v.as_u64()).unwrap())
+        };
+        assert_eq!(vec!(("M".to_owned(), "process_name".to_owned(), 0),
+                        ("M".to_owned(), "thread_name".to_owned(), 1),
+                        ("X".to_owned(), "Preprocess".to_owned(), 1),
+                        ("M".to_owned(), "thread_name".to_owned(), 2),
+                        ("X".to_owned(), "Preprocess".to_owned(), 2),
+                        ("X".to_owned(), "Compile".to_owned(), 1)),
+                   events.iter().map(describe).collect::<Vec<_>>());
+        assert_eq!(Some(3000), events[2].find("dur").and_then(|v| v.as_u64()));
+    }
+
+    #[test]
+    fn test_disabled_timeline() {
+        let timeline = Timeline::disabled();
+        let start = Instant::now();
+        timeline.record(Phase::Compile, "foo.o", start, start);
+        let trace = timeline.to_trace_events();
+        // Only the process name.
+        assert_eq!(1, trace.find("traceEvents").and_then(|e| e.as_array()).unwrap().len());
+    }
+}
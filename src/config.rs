@@ -0,0 +1,252 @@
+// Copyright 2017 Mozilla Foundation
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use app_dirs::{
+    AppDataType,
+    AppInfo,
+    get_app_root,
+};
+use serde_json::{self, Value};
+use std::collections::HashMap;
+use std::env;
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::PathBuf;
+
+use errors::*;
+
+pub const APP_INFO: AppInfo = AppInfo {
+    name: "sccache",
+    author: "Mozilla",
+};
+
+/// The name of the config file in the app config dir.
+const CONFIG_FILE_NAME: &'static str = "config.json";
+
+/// The settings that can be set in the config file, with the environment
+/// variables that override them.
+pub const SETTINGS: &'static [(&'static str, &'static str)] = &[
+    ("dir", "SCCACHE_DIR"),
+    ("cache_size", "SCCACHE_CACHE_SIZE"),
+    ("cache_mode", "SCCACHE_CACHE_MODE"),
+    ("hard_link", "SCCACHE_HARD_LINK"),
+    ("compression", "SCCACHE_COMPRESSION"),
+    ("bucket", "SCCACHE_BUCKET"),
+    ("endpoint", "SCCACHE_ENDPOINT"),
+    ("region", "SCCACHE_REGION"),
+    ("redis", "SCCACHE_REDIS"),
+    ("memcached", "SCCACHE_MEMCACHED"),
+    ("http_url", "SCCACHE_HTTP_URL"),
+    ("http_username", "SCCACHE_HTTP_USERNAME"),
+    ("http_password", "SCCACHE_HTTP_PASSWORD"),
+    ("http_token", "SCCACHE_HTTP_TOKEN"),
+    ("tiered", "SCCACHE_TIERED"),
+    ("server_port", "SCCACHE_SERVER_PORT"),
+    ("server_transport", "SCCACHE_SERVER_TRANSPORT"),
+    ("server_socket", "SCCACHE_SERVER_SOCKET"),
+    ("recache", "SCCACHE_RECACHE"),
+    ("direct", "SCCACHE_DIRECT"),
+    ("basedir", "SCCACHE_BASEDIR"),
+    ("max_jobs", "SCCACHE_MAX_JOBS"),
+    ("metrics_addr", "SCCACHE_METRICS_ADDR"),
+    ("explain_misses", "SCCACHE_EXPLAIN_MISSES"),
+    ("trace_log", "SCCACHE_TRACE_LOG"),
+    ("trace_log_size", "SCCACHE_TRACE_LOG_SIZE"),
+    ("timeline", "SCCACHE_TIMELINE"),
+];
+
+/// Where the effective value of a setting comes from.
+#[derive(Debug, PartialEq)]
+pub enum Source {
+    /// The setting's environment variable.
+    Environment,
+    /// The config file.
+    File,
+    /// The setting isn't set, so its default applies.
+    Default,
+}
+
+/// sccache settings, read from the config file and the environment.
+///
+/// Settings are looked up by the name of their environment variable, which
+/// takes precedence over the config file.
+pub struct Config {
+    /// The config file that was read, if any.
+    path: Option<PathBuf>,
+    /// The values from the config file, by environment variable name.
+    file: HashMap<&'static str, String>,
+    /// Looks up environment variables; `env::var` outside of tests.
+    env: fn(&str) -> Option<String>,
+}
+
+/// Look up `var` in the process environment.
+fn env_var(var: &str) -> Option<String> {
+    env::var(var).ok()
+}
+
+impl Config {
+    /// Read the config file named by the `SCCACHE_CONF` environment variable,
+    /// or `config.json` in the app config dir if it's not set.
+    ///
+    /// It's fine for the default config file not to exist.
+    pub fn load() -> Result<Config> {
+        let (path, required) = match env::var_os("SCCACHE_CONF") {
+            Some(path) => (Some(PathBuf::from(path)), true),
+            None => (get_app_root(AppDataType::UserConfig, &APP_INFO).ok()
+                     .map(|dir| dir.join(CONFIG_FILE_NAME)), false),
+        };
+        let path = match path {
+            Some(path) => path,
+            None => return Ok(Config::empty()),
+        };
+        let mut contents = String::new();
+        match File::open(&path).and_then(|mut f| f.read_to_string(&mut contents)) {
+            Ok(_) => {}
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound && !required => {
+                trace!("No config file at {:?}", path);
+                return Ok(Config::empty())
+            }
+            Err(e) => {
+                return Err(e).chain_err(|| format!("failed to read config file {:?}", path))
+            }
+        }
+        let file = parse(&contents).chain_err(|| {
+            format!("failed to parse config file {:?}", path)
+        })?;
+        Ok(Config {
+            path: Some(path),
+            file: file,
+            env: env_var,
+        })
+    }
+
+    /// A `Config` with no config file, only looking at the environment.
+    pub fn empty() -> Config {
+        Config {
+            path: None,
+            file: HashMap::new(),
+            env: env_var,
+        }
+    }
+
+    /// The config file that was read, if any.
+    pub fn path(&self) -> Option<&PathBuf> {
+        self.path.as_ref()
+    }
+
+    /// Get the value of the setting for environment variable `var`, and
+    /// where it came from.
+    pub fn lookup(&self, var: &str) -> (Option<String>, Source) {
+        if let Some(val) = (self.env)(var) {
+            return (Some(val), Source::Environment)
+        }
+        match self.file.get(var) {
+            Some(val) => (Some(val.clone()), Source::File),
+            None => (None, Source::Default),
+        }
+    }
+
+    /// Get the value of the setting for environment variable `var`.
+    pub fn get(&self, var: &str) -> Option<String> {
+        self.lookup(var).0
+    }
+
+    /// Whether the setting for environment variable `var` is set.
+    pub fn is_set(&self, var: &str) -> bool {
+        self.get(var).is_some()
+    }
+}
+
+/// Parse the JSON `contents` of a config file into settings, by
+/// environment variable name.
+///
+/// Booleans set to `false` leave the setting unset.
+fn parse(contents: &str) -> Result<HashMap<&'static str, String>> {
+    let map = match serde_json::from_str(contents) {
+        Ok(Value::Object(map)) => map,
+        Ok(_) => bail!("expected a JSON object"),
+        Err(e) => bail!("invalid JSON: {}", e),
+    };
+    let mut settings = HashMap::new();
+    for (key, value) in map {
+        let var = match SETTINGS.iter().find(|&&(name, _)| name == key) {
+            Some(&(_, var)) => var,
+            None => {
+                warn!("Unknown setting `{}` in config file", key);
+                continue
+            }
+        };
+        let value = match value {
+            Value::String(s) => s,
+            Value::I64(n) => n.to_string(),
+            Value::U64(n) => n.to_string(),
+            Value::Bool(true) => "1".to_owned(),
+            Value::Bool(false) | Value::Null => continue,
+            _ => bail!("unsupported value for setting `{}`", key),
+        };
+        settings.insert(var, value);
+    }
+    Ok(settings)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let settings = parse(r#"{
+            "dir": "/tmp/cache",
+            "server_port": 4227,
+            "recache": true,
+            "direct": false,
+            "unknown": "ignored"
+        }"#).unwrap();
+        assert_eq!(3, settings.len());
+        assert_eq!("/tmp/cache", settings["SCCACHE_DIR"]);
+        assert_eq!("4227", settings["SCCACHE_SERVER_PORT"]);
+        assert_eq!("1", settings["SCCACHE_RECACHE"]);
+        assert!(!settings.contains_key("SCCACHE_DIRECT"));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse("not json").is_err());
+        assert!(parse("[]").is_err());
+        assert!(parse(r#"{"dir": ["a", "b"]}"#).is_err());
+    }
+
+    fn fake_env(var: &str) -> Option<String> {
+        match var {
+            "SCCACHE_REGION" => Some("from-env".to_owned()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_precedence() {
+        let mut file = HashMap::new();
+        file.insert("SCCACHE_REGION", "from-file".to_owned());
+        file.insert("SCCACHE_BUCKET", "from-file".to_owned());
+        let config = Config {
+            path: None,
+            file: file,
+            env: fake_env,
+        };
+        assert_eq!((Some("from-file".to_owned()), Source::File), config.lookup("SCCACHE_BUCKET"));
+        assert_eq!((Some("from-env".to_owned()), Source::Environment), config.lookup("SCCACHE_REGION"));
+        assert_eq!((None, Source::Default), config.lookup("SCCACHE_REDIS"));
+        assert!(!config.is_set("SCCACHE_REDIS"));
+    }
+}
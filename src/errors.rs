@@ -31,6 +31,10 @@ error_chain! {
             description("failed to get a successful HTTP status")
             display("didn't get a successful HTTP status, got `{}`", status)
         }
+        ServerVersionMismatch {
+            description("the server is running a different version of sccache")
+            display("the server is running a different version of sccache")
+        }
     }
 }
 
@@ -0,0 +1,380 @@
+// Copyright 2017 Mozilla Foundation
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Support for the GNU make jobserver, which limits the number of jobs
+//! running at once across make and its children.
+//!
+//! make starts each job holding one implicit token, and children that want
+//! to run more jobs take extra tokens from a pipe, writing them back when
+//! they're done. The server takes a token before running the compiler on a
+//! cache miss, first telling the client, which lends its implicit token to
+//! the jobserver until the compile is done. So compiles only run when make
+//! has room for them, and cache hits leave the client's token alone.
+//!
+//! The server reaches the client's pipe through `/proc`, so this only works
+//! on Linux, or with make's named pipe jobserver on other Unix systems.
+
+use futures::Future;
+use futures::sync::oneshot;
+use std::env;
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use errors::*;
+
+/// The token lent to the jobserver by a client.
+const LENT_TOKEN: u8 = b'+';
+
+/// How a jobserver is reached, as named in `MAKEFLAGS`.
+#[derive(Debug, PartialEq)]
+enum Auth {
+    /// The read and write ends of a pipe, as file descriptors.
+    Fds(i32, i32),
+    /// A named pipe.
+    Fifo(PathBuf),
+}
+
+/// The jobserver's pipe.
+struct Pipe {
+    read: File,
+    write: File,
+}
+
+/// A connection to a GNU make jobserver.
+#[derive(Clone)]
+pub struct Jobserver {
+    /// The `MAKEFLAGS` naming the jobserver.
+    makeflags: String,
+    pipe: Arc<Pipe>,
+    /// Called before waiting for a token.
+    on_acquire: Option<Rc<Fn()>>,
+}
+
+/// A token taken from the jobserver, which is given back when it's dropped.
+pub struct Token {
+    pipe: Arc<Pipe>,
+    byte: u8,
+}
+
+/// The implicit token of this process, lent to the jobserver. It's taken
+/// back when this is dropped.
+pub struct LentToken {
+    pipe: Arc<Pipe>,
+}
+
+impl Jobserver {
+    /// Connect to the jobserver named in the `MAKEFLAGS` this process was
+    /// run with, if any.
+    pub fn from_env() -> Option<Jobserver> {
+        let makeflags = match env::var("MAKEFLAGS") {
+            Ok(makeflags) => makeflags,
+            Err(_) => return None,
+        };
+        let auth = match parse_makeflags(&makeflags) {
+            Some(auth) => auth,
+            None => return None,
+        };
+        match inherited_pipe(&auth) {
+            Ok(pipe) => Some(Jobserver {
+                makeflags: makeflags,
+                pipe: Arc::new(pipe),
+                on_acquire: None,
+            }),
+            Err(e) => {
+                debug!("Not using jobserver {:?}: {}", auth, e);
+                None
+            }
+        }
+    }
+
+    /// Connect to the jobserver named in `makeflags`, from the client with
+    /// process ID `pid`.
+    pub fn for_client(pid: u32, makeflags: &str) -> Option<Jobserver> {
+        let auth = match parse_makeflags(makeflags) {
+            Some(auth) => auth,
+            None => return None,
+        };
+        match client_pipe(pid, &auth) {
+            Ok(pipe) => Some(Jobserver {
+                makeflags: makeflags.to_owned(),
+                pipe: Arc::new(pipe),
+                on_acquire: None,
+            }),
+            Err(e) => {
+                warn!("Can't reach jobserver {:?} of client {}: {}", auth, pid, e);
+                None
+            }
+        }
+    }
+
+    /// The `MAKEFLAGS` naming this jobserver.
+    pub fn makeflags(&self) -> &str {
+        &self.makeflags
+    }
+
+    /// Call `f` each time before waiting for a token, so the client can
+    /// lend its token for the compile.
+    pub fn on_acquire<F: Fn() + 'static>(mut self, f: F) -> Jobserver {
+        self.on_acquire = Some(Rc::new(f));
+        self
+    }
+
+    /// Take a token from the jobserver, waiting for one if need be.
+    pub fn acquire(&self) -> SFuture<Token> {
+        if let Some(ref on_acquire) = self.on_acquire {
+            on_acquire();
+        }
+        let (tx, rx) = oneshot::channel();
+        let pipe = self.pipe.clone();
+        // Reading the pipe blocks, so wait on a thread of our own rather
+        // than tying up the thread pool.
+        thread::spawn(move || {
+            let token = read_token(&pipe.read).map(|byte| {
+                Token {
+                    pipe: pipe.clone(),
+                    byte: byte,
+                }
+            });
+            // If nobody is waiting any more, the token goes straight back.
+            drop(tx.send(token));
+        });
+        Box::new(rx.then(|res| {
+            match res {
+                Ok(Ok(token)) => Ok(token),
+                Ok(Err(e)) => Err(e).chain_err(|| "failed to take a jobserver token"),
+                Err(_) => Err("jobserver token request was dropped".into()),
+            }
+        }))
+    }
+
+    /// Lend the implicit token this process was started with to the
+    /// jobserver.
+    pub fn lend_implicit_token(&self) -> io::Result<LentToken> {
+        (&self.pipe.write).write_all(&[LENT_TOKEN])?;
+        Ok(LentToken { pipe: self.pipe.clone() })
+    }
+}
+
+impl Drop for Token {
+    fn drop(&mut self) {
+        if let Err(e) = (&self.pipe.write).write_all(&[self.byte]) {
+            warn!("Failed to give back jobserver token: {}", e);
+        }
+    }
+}
+
+impl Drop for LentToken {
+    fn drop(&mut self) {
+        if let Err(e) = read_token(&self.pipe.read) {
+            warn!("Failed to take back jobserver token: {}", e);
+        }
+    }
+}
+
+/// The process ID of this process, for the server to find its jobserver
+/// pipe.
+#[cfg(unix)]
+pub fn current_pid() -> u32 {
+    use libc;
+    unsafe { libc::getpid() as u32 }
+}
+
+#[cfg(not(unix))]
+pub fn current_pid() -> u32 {
+    0
+}
+
+/// Find the jobserver named in `makeflags`, if any.
+///
+/// Newer versions of make use `--jobserver-auth`, older ones
+/// `--jobserver-fds`. If there are several, the last one wins.
+fn parse_makeflags(makeflags: &str) -> Option<Auth> {
+    makeflags.split_whitespace()
+        .filter_map(|arg| {
+            if arg.starts_with("--jobserver-auth=") {
+                Some(&arg["--jobserver-auth=".len()..])
+            } else if arg.starts_with("--jobserver-fds=") {
+                Some(&arg["--jobserver-fds=".len()..])
+            } else {
+                None
+            }
+        })
+        .last()
+        .and_then(|value| {
+            if value.starts_with("fifo:") {
+                return Some(Auth::Fifo(PathBuf::from(&value["fifo:".len()..])))
+            }
+            let mut fds = value.splitn(2, ',').map(|fd| fd.parse::<i32>());
+            match (fds.next(), fds.next()) {
+                (Some(Ok(read)), Some(Ok(write))) if read >= 0 && write >= 0 => {
+                    Some(Auth::Fds(read, write))
+                }
+                _ => None,
+            }
+        })
+}
+
+/// Take a token from `pipe`, waiting for one if need be.
+fn read_token(mut pipe: &File) -> io::Result<u8> {
+    let mut buf = [0; 1];
+    loop {
+        match pipe.read(&mut buf) {
+            Ok(1) => return Ok(buf[0]),
+            Ok(_) => return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "jobserver pipe closed")),
+            Err(ref e) if e.kind() == io::ErrorKind::Interrupted => {}
+            // make may have made the pipe non-blocking.
+            Err(ref e) if e.kind() == io::ErrorKind::WouldBlock => {
+                thread::sleep(Duration::from_millis(10));
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+#[cfg(unix)]
+fn check_fifo(file: File) -> io::Result<File> {
+    use std::os::unix::fs::FileTypeExt;
+    if file.metadata()?.file_type().is_fifo() {
+        Ok(file)
+    } else {
+        Err(io::Error::new(io::ErrorKind::Other, "not a pipe"))
+    }
+}
+
+/// Open a named pipe jobserver.
+#[cfg(unix)]
+fn open_fifo(path: &PathBuf) -> io::Result<Pipe> {
+    use std::fs::OpenOptions;
+    // Open it for both reading and writing so opening it doesn't wait for
+    // a writer.
+    let read = check_fifo(OpenOptions::new().read(true).write(true).open(path)?)?;
+    let write = read.try_clone()?;
+    Ok(Pipe {
+        read: read,
+        write: write,
+    })
+}
+
+/// Open the jobserver pipe this process inherited from make.
+#[cfg(unix)]
+fn inherited_pipe(auth: &Auth) -> io::Result<Pipe> {
+    use libc;
+    use std::os::unix::io::FromRawFd;
+
+    // make doesn't pass the pipe on to commands it doesn't think run make,
+    // in which case these may not be open, or be open as something else.
+    fn dup(fd: i32) -> io::Result<File> {
+        let fd = unsafe { libc::dup(fd) };
+        if fd == -1 {
+            return Err(io::Error::last_os_error())
+        }
+        check_fifo(unsafe { File::from_raw_fd(fd) })
+    }
+
+    match *auth {
+        Auth::Fds(read, write) => {
+            Ok(Pipe {
+                read: dup(read)?,
+                write: dup(write)?,
+            })
+        }
+        Auth::Fifo(ref path) => open_fifo(path),
+    }
+}
+
+/// Open the jobserver pipe inherited by the client with process ID `pid`.
+#[cfg(unix)]
+fn client_pipe(pid: u32, auth: &Auth) -> io::Result<Pipe> {
+    use std::fs::OpenOptions;
+
+    match *auth {
+        Auth::Fds(read, write) => {
+            let read = OpenOptions::new().read(true)
+                .open(format!("/proc/{}/fd/{}", pid, read))?;
+            let write = OpenOptions::new().write(true)
+                .open(format!("/proc/{}/fd/{}", pid, write))?;
+            Ok(Pipe {
+                read: check_fifo(read)?,
+                write: check_fifo(write)?,
+            })
+        }
+        Auth::Fifo(ref path) => open_fifo(path),
+    }
+}
+
+#[cfg(not(unix))]
+fn inherited_pipe(_auth: &Auth) -> io::Result<Pipe> {
+    Err(io::Error::new(io::ErrorKind::Other, "jobservers are only supported on Unix"))
+}
+
+#[cfg(not(unix))]
+fn client_pipe(_pid: u32, _auth: &Auth) -> io::Result<Pipe> {
+    Err(io::Error::new(io::ErrorKind::Other, "jobservers are only supported on Unix"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_makeflags() {
+        assert_eq!(None, parse_makeflags(""));
+        assert_eq!(None, parse_makeflags("-j8"));
+        assert_eq!(Some(Auth::Fds(3, 4)), parse_makeflags(" -j8 --jobserver-fds=3,4"));
+        assert_eq!(Some(Auth::Fds(5, 6)),
+                   parse_makeflags("kw -j --jobserver-auth=3,4 --jobserver-auth=5,6"));
+        assert_eq!(Some(Auth::Fifo(PathBuf::from("/tmp/GMfifo123"))),
+                   parse_makeflags("-j4 --jobserver-auth=fifo:/tmp/GMfifo123"));
+        assert_eq!(None, parse_makeflags("--jobserver-auth=-2,-2"));
+        assert_eq!(None, parse_makeflags("--jobserver-auth=3"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_tokens() {
+        use libc;
+        use std::cell::RefCell;
+        use std::os::unix::io::FromRawFd;
+
+        let mut fds = [0; 2];
+        assert_eq!(0, unsafe { libc::pipe(fds.as_mut_ptr()) });
+        let jobserver = Jobserver {
+            makeflags: String::new(),
+            pipe: Arc::new(Pipe {
+                read: unsafe { File::from_raw_fd(fds[0]) },
+                write: unsafe { File::from_raw_fd(fds[1]) },
+            }),
+            on_acquire: None,
+        };
+        // The client lends its token when told a compile is waiting for one.
+        let lent = Rc::new(RefCell::new(None));
+        let jobserver = {
+            let lender = jobserver.clone();
+            let lent = lent.clone();
+            jobserver.on_acquire(move || {
+                *lent.borrow_mut() = Some(lender.lend_implicit_token().unwrap());
+            })
+        };
+        let token = jobserver.acquire().wait().unwrap();
+        assert_eq!(LENT_TOKEN, token.byte);
+        // Giving it back lets the lender take its token back.
+        drop(token);
+        drop(lent.borrow_mut().take().unwrap());
+    }
+}
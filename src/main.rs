@@ -33,6 +33,7 @@ extern crate local_encoding;
 #[macro_use]
 extern crate log;
 extern crate lru_disk_cache;
+extern crate lz4;
 extern crate fern;
 extern crate libc;
 #[cfg(windows)]
@@ -50,11 +51,14 @@ extern crate tokio_core;
 extern crate tokio_process;
 extern crate tokio_proto;
 extern crate tokio_service;
+#[cfg(unix)]
+extern crate tokio_uds;
 extern crate uuid;
 #[cfg(windows)]
 extern crate winapi;
 extern crate which;
 extern crate zip;
+extern crate zstd;
 
 // To get macros in scope, this has to be first.
 #[cfg(test)]
@@ -66,11 +70,15 @@ mod client;
 mod cmdline;
 mod commands;
 mod compiler;
+mod config;
 mod errors;
+mod jobserver;
+mod metrics;
 mod mock_command;
 mod protocol;
 mod server;
 mod simples3;
+mod tracelog;
 
 use std::env;
 use std::io::Write;
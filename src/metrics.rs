@@ -0,0 +1,156 @@
+// Copyright 2017 Mozilla Foundation
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! An HTTP endpoint serving server statistics on `/metrics`, in the
+//! Prometheus text exposition format.
+
+use futures::{future, Future, Stream};
+use hyper::{self, Method};
+use hyper::header::{ContentLength, ContentType};
+use hyper::server::{Http, Request, Response};
+use hyper::status::StatusCode;
+use std::rc::Rc;
+use tokio_core::net::TcpListener;
+use tokio_core::reactor::Handle;
+use tokio_service::Service;
+
+/// The kind of a metric.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MetricType {
+    /// A value that only goes up.
+    Counter,
+    /// A value that can go up and down.
+    Gauge,
+}
+
+/// Metrics being written out in the Prometheus text format.
+pub struct Exposition {
+    text: String,
+    /// Labels added to every sample.
+    labels: Vec<(&'static str, String)>,
+}
+
+impl Exposition {
+    /// Start writing metrics, labelling every sample with `labels`.
+    pub fn new(labels: Vec<(&'static str, String)>) -> Exposition {
+        Exposition {
+            text: String::new(),
+            labels: labels,
+        }
+    }
+
+    /// Start the metric `name`, with a description of what it measures.
+    ///
+    /// Its samples should follow.
+    pub fn metric(&mut self, name: &str, kind: MetricType, help: &str) {
+        let kind = match kind {
+            MetricType::Counter => "counter",
+            MetricType::Gauge => "gauge",
+        };
+        self.text.push_str(&format!("# HELP {} {}\n", name, help.replace('\\', "\\\\").replace('\n', "\\n")));
+        self.text.push_str(&format!("# TYPE {} {}\n", name, kind));
+    }
+
+    /// Add a sample of `value` for the metric `name`, with `labels` on top
+    /// of the common ones.
+    pub fn sample(&mut self, name: &str, labels: &[(&str, &str)], value: f64) {
+        let labels = self.labels.iter()
+            .map(|&(k, ref v)| (k, v.as_str()))
+            .chain(labels.iter().cloned())
+            .map(|(k, v)| format!("{}=\"{}\"", k, escape_label(v)))
+            .collect::<Vec<_>>();
+        if labels.is_empty() {
+            self.text.push_str(&format!("{} {}\n", name, value));
+        } else {
+            self.text.push_str(&format!("{}{{{}}} {}\n", name, labels.join(","), value));
+        }
+    }
+
+    /// Start the metric `name` and add a single sample of `value`.
+    pub fn single(&mut self, name: &str, kind: MetricType, help: &str, value: f64) {
+        self.metric(name, kind, help);
+        self.sample(name, &[], value);
+    }
+
+    /// Get the metrics written so far.
+    pub fn into_string(self) -> String {
+        self.text
+    }
+}
+
+/// Escape `value` for use as a label value.
+fn escape_label(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+/// Serves the metrics that `render` produces.
+struct MetricsService {
+    render: Rc<Fn() -> String>,
+}
+
+impl Service for MetricsService {
+    type Request = Request;
+    type Response = Response;
+    type Error = hyper::Error;
+    type Future = future::FutureResult<Response, hyper::Error>;
+
+    fn call(&self, req: Request) -> Self::Future {
+        trace!("metrics request: {} {}", req.method(), req.path());
+        if *req.method() != Method::Get || req.path() != "/metrics" {
+            return future::ok(Response::new().with_status(StatusCode::NotFound))
+        }
+        let body = (self.render)();
+        future::ok(Response::new()
+                   .with_header(ContentType::plaintext())
+                   .with_header(ContentLength(body.len() as u64))
+                   .with_body(body))
+    }
+}
+
+/// Serve the metrics that `render` produces to HTTP connections on
+/// `listener`, on the event loop of `handle`.
+pub fn serve(listener: TcpListener,
+             handle: &Handle,
+             render: Rc<Fn() -> String>)
+             -> Box<Future<Item = (), Error = ()>> {
+    let handle = handle.clone();
+    Box::new(listener.incoming().for_each(move |(socket, addr)| {
+        Http::new().bind_connection(&handle, socket, addr, MetricsService {
+            render: render.clone(),
+        });
+        Ok(())
+    }).map_err(|e| warn!("Metrics listener failed: {}", e)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_exposition() {
+        let mut metrics = Exposition::new(vec!(("backend", "disk".to_owned())));
+        metrics.single("sccache_compile_requests_total", MetricType::Counter, "Compile requests.", 3.0);
+        metrics.metric("sccache_cache_hits_total", MetricType::Counter, "Cache hits.");
+        metrics.sample("sccache_cache_hits_total", &[("compiler", "gcc")], 2.0);
+        metrics.sample("sccache_cache_hits_total", &[("compiler", "say \"hi\"")], 0.5);
+        assert_eq!("# HELP sccache_compile_requests_total Compile requests.
+# TYPE sccache_compile_requests_total counter
+sccache_compile_requests_total{backend=\"disk\"} 3
+# HELP sccache_cache_hits_total Cache hits.
+# TYPE sccache_cache_hits_total counter
+sccache_cache_hits_total{backend=\"disk\",compiler=\"gcc\"} 2
+sccache_cache_hits_total{backend=\"disk\",compiler=\"say \\\"hi\\\"\"} 0.5
+", metrics.into_string());
+    }
+}
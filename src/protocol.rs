@@ -401,146 +401,247 @@ impl ::std::fmt::Debug for ZeroStats {
 }
 
 #[derive(Clone,Default)]
-pub struct Compile {
-    // message fields
-    cwd: ::protobuf::SingularField<::std::string::String>,
-    exe: ::protobuf::SingularField<::std::string::String>,
-    command: ::protobuf::RepeatedField<::std::string::String>,
+pub struct DumpTrace {
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for Compile {}
+unsafe impl ::std::marker::Sync for DumpTrace {}
 
-impl Compile {
-    pub fn new() -> Compile {
+impl DumpTrace {
+    pub fn new() -> DumpTrace {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static Compile {
-        static mut instance: ::protobuf::lazy::Lazy<Compile> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static DumpTrace {
+        static mut instance: ::protobuf::lazy::Lazy<DumpTrace> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Compile,
+            ptr: 0 as *const DumpTrace,
         };
         unsafe {
             instance.get(|| {
-                Compile {
-                    cwd: ::protobuf::SingularField::none(),
-                    exe: ::protobuf::SingularField::none(),
-                    command: ::protobuf::RepeatedField::new(),
+                DumpTrace {
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
             })
         }
     }
+}
 
-    // required string cwd = 1;
+impl ::protobuf::Message for DumpTrace {
+    fn is_initialized(&self) -> bool {
+        true
+    }
 
-    pub fn clear_cwd(&mut self) {
-        self.cwd.clear();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
     }
 
-    pub fn has_cwd(&self) -> bool {
-        self.cwd.is_some()
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
     }
 
-    // Param is passed by value, moved
-    pub fn set_cwd(&mut self, v: ::std::string::String) {
-        self.cwd = ::protobuf::SingularField::some(v);
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_cwd<'a>(&'a mut self) -> &'a mut ::std::string::String {
-        if self.cwd.is_none() {
-            self.cwd.set_default();
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<DumpTrace>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for DumpTrace {
+    fn new() -> DumpTrace {
+        DumpTrace::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<DumpTrace>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
         };
-        self.cwd.as_mut().unwrap()
+        unsafe {
+            descriptor.get(|| {
+                let fields = ::std::vec::Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new::<DumpTrace>(
+                    "DumpTrace",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
     }
+}
 
-    // Take field
-    pub fn take_cwd(&mut self) -> ::std::string::String {
-        self.cwd.take().unwrap_or_else(|| ::std::string::String::new())
+impl ::protobuf::Clear for DumpTrace {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
     }
+}
 
-    pub fn get_cwd<'a>(&'a self) -> &'a str {
-        match self.cwd.as_ref() {
-            Some(v) => &v,
-            None => "",
+impl ::std::cmp::PartialEq for DumpTrace {
+    fn eq(&self, other: &DumpTrace) -> bool {
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for DumpTrace {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct Hello {
+    // message fields
+    version: ::protobuf::SingularField<::std::string::String>,
+    cache_version: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for Hello {}
+
+impl Hello {
+    pub fn new() -> Hello {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static Hello {
+        static mut instance: ::protobuf::lazy::Lazy<Hello> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Hello,
+        };
+        unsafe {
+            instance.get(|| {
+                Hello {
+                    version: ::protobuf::SingularField::none(),
+                    cache_version: ::protobuf::SingularField::none(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
         }
     }
 
-    // required string exe = 2;
+    // required string version = 1;
 
-    pub fn clear_exe(&mut self) {
-        self.exe.clear();
+    pub fn clear_version(&mut self) {
+        self.version.clear();
     }
 
-    pub fn has_exe(&self) -> bool {
-        self.exe.is_some()
+    pub fn has_version(&self) -> bool {
+        self.version.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_exe(&mut self, v: ::std::string::String) {
-        self.exe = ::protobuf::SingularField::some(v);
+    pub fn set_version(&mut self, v: ::std::string::String) {
+        self.version = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_exe<'a>(&'a mut self) -> &'a mut ::std::string::String {
-        if self.exe.is_none() {
-            self.exe.set_default();
+    pub fn mut_version<'a>(&'a mut self) -> &'a mut ::std::string::String {
+        if self.version.is_none() {
+            self.version.set_default();
         };
-        self.exe.as_mut().unwrap()
+        self.version.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_exe(&mut self) -> ::std::string::String {
-        self.exe.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_version(&mut self) -> ::std::string::String {
+        self.version.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn get_exe<'a>(&'a self) -> &'a str {
-        match self.exe.as_ref() {
+    pub fn get_version<'a>(&'a self) -> &'a str {
+        match self.version.as_ref() {
             Some(v) => &v,
             None => "",
         }
     }
 
-    // repeated string command = 3;
+    // required string cache_version = 2;
 
-    pub fn clear_command(&mut self) {
-        self.command.clear();
+    pub fn clear_cache_version(&mut self) {
+        self.cache_version.clear();
+    }
+
+    pub fn has_cache_version(&self) -> bool {
+        self.cache_version.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_command(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
-        self.command = v;
+    pub fn set_cache_version(&mut self, v: ::std::string::String) {
+        self.cache_version = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
-    pub fn mut_command<'a>(&'a mut self) -> &'a mut ::protobuf::RepeatedField<::std::string::String> {
-        &mut self.command
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_cache_version<'a>(&'a mut self) -> &'a mut ::std::string::String {
+        if self.cache_version.is_none() {
+            self.cache_version.set_default();
+        };
+        self.cache_version.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_command(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
-        ::std::mem::replace(&mut self.command, ::protobuf::RepeatedField::new())
+    pub fn take_cache_version(&mut self) -> ::std::string::String {
+        self.cache_version.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn get_command<'a>(&'a self) -> &'a [::std::string::String] {
-        &self.command
+    pub fn get_cache_version<'a>(&'a self) -> &'a str {
+        match self.cache_version.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
     }
 }
 
-impl ::protobuf::Message for Compile {
+impl ::protobuf::Message for Hello {
     fn is_initialized(&self) -> bool {
-        if self.cwd.is_none() {
+        if self.version.is_none() {
             return false;
         };
-        if self.exe.is_none() {
+        if self.cache_version.is_none() {
             return false;
         };
         true
@@ -551,13 +652,10 @@ impl ::protobuf::Message for Compile {
             let (field_number, wire_type) = try!(is.read_tag_unpack());
             match field_number {
                 1 => {
-                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.cwd));
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.version));
                 },
                 2 => {
-                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.exe));
-                },
-                3 => {
-                    try!(::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.command));
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.cache_version));
                 },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
@@ -571,30 +669,24 @@ impl ::protobuf::Message for Compile {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in self.cwd.iter() {
+        for value in self.version.iter() {
             my_size += ::protobuf::rt::string_size(1, &value);
         };
-        for value in self.exe.iter() {
+        for value in self.cache_version.iter() {
             my_size += ::protobuf::rt::string_size(2, &value);
         };
-        for value in self.command.iter() {
-            my_size += ::protobuf::rt::string_size(3, &value);
-        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.cwd.as_ref() {
+        if let Some(v) = self.version.as_ref() {
             try!(os.write_string(1, &v));
         };
-        if let Some(v) = self.exe.as_ref() {
+        if let Some(v) = self.cache_version.as_ref() {
             try!(os.write_string(2, &v));
         };
-        for v in self.command.iter() {
-            try!(os.write_string(3, &v));
-        };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
     }
@@ -612,7 +704,7 @@ impl ::protobuf::Message for Compile {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<Compile>()
+        ::std::any::TypeId::of::<Hello>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -624,12 +716,12 @@ impl ::protobuf::Message for Compile {
     }
 }
 
-impl ::protobuf::MessageStatic for Compile {
-    fn new() -> Compile {
-        Compile::new()
+impl ::protobuf::MessageStatic for Hello {
+    fn new() -> Hello {
+        Hello::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<Compile>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<Hello>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -638,21 +730,17 @@ impl ::protobuf::MessageStatic for Compile {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
-                    "cwd",
-                    Compile::has_cwd,
-                    Compile::get_cwd,
+                    "version",
+                    Hello::has_version,
+                    Hello::get_version,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
-                    "exe",
-                    Compile::has_exe,
-                    Compile::get_exe,
+                    "cache_version",
+                    Hello::has_cache_version,
+                    Hello::get_cache_version,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_string_accessor(
-                    "command",
-                    Compile::get_command,
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<Compile>(
-                    "Compile",
+                ::protobuf::reflect::MessageDescriptor::new::<Hello>(
+                    "Hello",
                     fields,
                     file_descriptor_proto()
                 )
@@ -661,64 +749,64 @@ impl ::protobuf::MessageStatic for Compile {
     }
 }
 
-impl ::protobuf::Clear for Compile {
+impl ::protobuf::Clear for Hello {
     fn clear(&mut self) {
-        self.clear_cwd();
-        self.clear_exe();
-        self.clear_command();
+        self.clear_version();
+        self.clear_cache_version();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for Compile {
-    fn eq(&self, other: &Compile) -> bool {
-        self.cwd == other.cwd &&
-        self.exe == other.exe &&
-        self.command == other.command &&
+impl ::std::cmp::PartialEq for Hello {
+    fn eq(&self, other: &Hello) -> bool {
+        self.version == other.version &&
+        self.cache_version == other.cache_version &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for Compile {
+impl ::std::fmt::Debug for Hello {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 #[derive(Clone,Default)]
-pub struct ClientRequest {
-    // message oneof groups
-    request: ::std::option::Option<ClientRequest_oneof_request>,
+pub struct Compile {
+    // message fields
+    cwd: ::protobuf::SingularField<::std::string::String>,
+    exe: ::protobuf::SingularField<::std::string::String>,
+    command: ::protobuf::RepeatedField<::std::string::String>,
+    client_pid: ::std::option::Option<u32>,
+    makeflags: ::protobuf::SingularField<::std::string::String>,
+    hello: ::protobuf::SingularPtrField<Hello>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for ClientRequest {}
-
-#[derive(Clone,PartialEq)]
-pub enum ClientRequest_oneof_request {
-    compile(Compile),
-    get_stats(GetStats),
-    shutdown(Shutdown),
-    zero_stats(ZeroStats),
-}
+unsafe impl ::std::marker::Sync for Compile {}
 
-impl ClientRequest {
-    pub fn new() -> ClientRequest {
+impl Compile {
+    pub fn new() -> Compile {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static ClientRequest {
-        static mut instance: ::protobuf::lazy::Lazy<ClientRequest> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static Compile {
+        static mut instance: ::protobuf::lazy::Lazy<Compile> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ClientRequest,
+            ptr: 0 as *const Compile,
         };
         unsafe {
             instance.get(|| {
-                ClientRequest {
-                    request: ::std::option::Option::None,
+                Compile {
+                    cwd: ::protobuf::SingularField::none(),
+                    exe: ::protobuf::SingularField::none(),
+                    command: ::protobuf::RepeatedField::new(),
+                    client_pid: ::std::option::Option::None,
+                    makeflags: ::protobuf::SingularField::none(),
+                    hello: ::protobuf::SingularPtrField::none(),
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -726,209 +814,1540 @@ impl ClientRequest {
         }
     }
 
-    // optional .sccache.Compile compile = 1;
+    // required string cwd = 1;
 
-    pub fn clear_compile(&mut self) {
-        self.request = ::std::option::Option::None;
+    pub fn clear_cwd(&mut self) {
+        self.cwd.clear();
     }
 
-    pub fn has_compile(&self) -> bool {
-        match self.request {
-            ::std::option::Option::Some(ClientRequest_oneof_request::compile(..)) => true,
-            _ => false,
-        }
+    pub fn has_cwd(&self) -> bool {
+        self.cwd.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_compile(&mut self, v: Compile) {
-        self.request = ::std::option::Option::Some(ClientRequest_oneof_request::compile(v))
+    pub fn set_cwd(&mut self, v: ::std::string::String) {
+        self.cwd = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_compile<'a>(&'a mut self) -> &'a mut Compile {
-        if let ::std::option::Option::Some(ClientRequest_oneof_request::compile(_)) = self.request {
-        } else {
-            self.request = ::std::option::Option::Some(ClientRequest_oneof_request::compile(Compile::new()));
+    pub fn mut_cwd<'a>(&'a mut self) -> &'a mut ::std::string::String {
+        if self.cwd.is_none() {
+            self.cwd.set_default();
+        };
+        self.cwd.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_cwd(&mut self) -> ::std::string::String {
+        self.cwd.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_cwd<'a>(&'a self) -> &'a str {
+        match self.cwd.as_ref() {
+            Some(v) => &v,
+            None => "",
         }
-        match self.request {
-            ::std::option::Option::Some(ClientRequest_oneof_request::compile(ref mut v)) => v,
-            _ => panic!(),
+    }
+
+    // required string exe = 2;
+
+    pub fn clear_exe(&mut self) {
+        self.exe.clear();
+    }
+
+    pub fn has_exe(&self) -> bool {
+        self.exe.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_exe(&mut self, v: ::std::string::String) {
+        self.exe = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_exe<'a>(&'a mut self) -> &'a mut ::std::string::String {
+        if self.exe.is_none() {
+            self.exe.set_default();
+        };
+        self.exe.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_exe(&mut self) -> ::std::string::String {
+        self.exe.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_exe<'a>(&'a self) -> &'a str {
+        match self.exe.as_ref() {
+            Some(v) => &v,
+            None => "",
         }
     }
 
+    // repeated string command = 3;
+
+    pub fn clear_command(&mut self) {
+        self.command.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_command(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.command = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_command<'a>(&'a mut self) -> &'a mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.command
+    }
+
     // Take field
-    pub fn take_compile(&mut self) -> Compile {
-        if self.has_compile() {
-            match self.request.take() {
-                ::std::option::Option::Some(ClientRequest_oneof_request::compile(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            Compile::new()
+    pub fn take_command(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.command, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_command<'a>(&'a self) -> &'a [::std::string::String] {
+        &self.command
+    }
+
+    // optional uint32 client_pid = 4;
+
+    pub fn clear_client_pid(&mut self) {
+        self.client_pid = ::std::option::Option::None;
+    }
+
+    pub fn has_client_pid(&self) -> bool {
+        self.client_pid.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_client_pid(&mut self, v: u32) {
+        self.client_pid = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_client_pid(&self) -> u32 {
+        self.client_pid.unwrap_or(0)
+    }
+
+    // optional string makeflags = 5;
+
+    pub fn clear_makeflags(&mut self) {
+        self.makeflags.clear();
+    }
+
+    pub fn has_makeflags(&self) -> bool {
+        self.makeflags.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_makeflags(&mut self, v: ::std::string::String) {
+        self.makeflags = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_makeflags<'a>(&'a mut self) -> &'a mut ::std::string::String {
+        if self.makeflags.is_none() {
+            self.makeflags.set_default();
+        };
+        self.makeflags.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_makeflags(&mut self) -> ::std::string::String {
+        self.makeflags.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_makeflags<'a>(&'a self) -> &'a str {
+        match self.makeflags.as_ref() {
+            Some(v) => &v,
+            None => "",
         }
     }
 
-    pub fn get_compile<'a>(&'a self) -> &'a Compile {
-        match self.request {
-            ::std::option::Option::Some(ClientRequest_oneof_request::compile(ref v)) => v,
-            _ => Compile::default_instance(),
+    // optional .sccache.Hello hello = 6;
+
+    pub fn clear_hello(&mut self) {
+        self.hello.clear();
+    }
+
+    pub fn has_hello(&self) -> bool {
+        self.hello.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_hello(&mut self, v: Hello) {
+        self.hello = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_hello<'a>(&'a mut self) -> &'a mut Hello {
+        if self.hello.is_none() {
+            self.hello.set_default();
+        };
+        self.hello.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_hello(&mut self) -> Hello {
+        self.hello.take().unwrap_or_else(|| Hello::new())
+    }
+
+    pub fn get_hello<'a>(&'a self) -> &'a Hello {
+        self.hello.as_ref().unwrap_or_else(|| Hello::default_instance())
+    }
+}
+
+impl ::protobuf::Message for Compile {
+    fn is_initialized(&self) -> bool {
+        if self.cwd.is_none() {
+            return false;
+        };
+        if self.exe.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.cwd));
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.exe));
+                },
+                3 => {
+                    try!(::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.command));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint32());
+                    self.client_pid = ::std::option::Option::Some(tmp);
+                },
+                5 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.makeflags));
+                },
+                6 => {
+                    try!(::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.hello));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.cwd.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in self.exe.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in self.command.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in self.client_pid.iter() {
+            my_size += ::protobuf::rt::value_size(4, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.makeflags.iter() {
+            my_size += ::protobuf::rt::string_size(5, &value);
+        };
+        for value in self.hello.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.cwd.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        if let Some(v) = self.exe.as_ref() {
+            try!(os.write_string(2, &v));
+        };
+        for v in self.command.iter() {
+            try!(os.write_string(3, &v));
+        };
+        if let Some(v) = self.client_pid {
+            try!(os.write_uint32(4, v));
+        };
+        if let Some(v) = self.makeflags.as_ref() {
+            try!(os.write_string(5, &v));
+        };
+        if let Some(v) = self.hello.as_ref() {
+            try!(os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<Compile>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for Compile {
+    fn new() -> Compile {
+        Compile::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Compile>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "cwd",
+                    Compile::has_cwd,
+                    Compile::get_cwd,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "exe",
+                    Compile::has_exe,
+                    Compile::get_exe,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_string_accessor(
+                    "command",
+                    Compile::get_command,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u32_accessor(
+                    "client_pid",
+                    Compile::has_client_pid,
+                    Compile::get_client_pid,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "makeflags",
+                    Compile::has_makeflags,
+                    Compile::get_makeflags,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
+                    "hello",
+                    Compile::has_hello,
+                    Compile::get_hello,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Compile>(
+                    "Compile",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for Compile {
+    fn clear(&mut self) {
+        self.clear_cwd();
+        self.clear_exe();
+        self.clear_command();
+        self.clear_client_pid();
+        self.clear_makeflags();
+        self.clear_hello();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for Compile {
+    fn eq(&self, other: &Compile) -> bool {
+        self.cwd == other.cwd &&
+        self.exe == other.exe &&
+        self.command == other.command &&
+        self.client_pid == other.client_pid &&
+        self.makeflags == other.makeflags &&
+        self.hello == other.hello &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for Compile {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct ClientRequest {
+    // message oneof groups
+    request: ::std::option::Option<ClientRequest_oneof_request>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for ClientRequest {}
+
+#[derive(Clone,PartialEq)]
+pub enum ClientRequest_oneof_request {
+    compile(Compile),
+    get_stats(GetStats),
+    shutdown(Shutdown),
+    zero_stats(ZeroStats),
+    hello(Hello),
+    dump_trace(DumpTrace),
+}
+
+impl ClientRequest {
+    pub fn new() -> ClientRequest {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static ClientRequest {
+        static mut instance: ::protobuf::lazy::Lazy<ClientRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ClientRequest,
+        };
+        unsafe {
+            instance.get(|| {
+                ClientRequest {
+                    request: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // optional .sccache.Compile compile = 1;
+
+    pub fn clear_compile(&mut self) {
+        self.request = ::std::option::Option::None;
+    }
+
+    pub fn has_compile(&self) -> bool {
+        match self.request {
+            ::std::option::Option::Some(ClientRequest_oneof_request::compile(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_compile(&mut self, v: Compile) {
+        self.request = ::std::option::Option::Some(ClientRequest_oneof_request::compile(v))
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_compile<'a>(&'a mut self) -> &'a mut Compile {
+        if let ::std::option::Option::Some(ClientRequest_oneof_request::compile(_)) = self.request {
+        } else {
+            self.request = ::std::option::Option::Some(ClientRequest_oneof_request::compile(Compile::new()));
+        }
+        match self.request {
+            ::std::option::Option::Some(ClientRequest_oneof_request::compile(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_compile(&mut self) -> Compile {
+        if self.has_compile() {
+            match self.request.take() {
+                ::std::option::Option::Some(ClientRequest_oneof_request::compile(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Compile::new()
+        }
+    }
+
+    pub fn get_compile<'a>(&'a self) -> &'a Compile {
+        match self.request {
+            ::std::option::Option::Some(ClientRequest_oneof_request::compile(ref v)) => v,
+            _ => Compile::default_instance(),
+        }
+    }
+
+    // optional .sccache.GetStats get_stats = 2;
+
+    pub fn clear_get_stats(&mut self) {
+        self.request = ::std::option::Option::None;
+    }
+
+    pub fn has_get_stats(&self) -> bool {
+        match self.request {
+            ::std::option::Option::Some(ClientRequest_oneof_request::get_stats(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_get_stats(&mut self, v: GetStats) {
+        self.request = ::std::option::Option::Some(ClientRequest_oneof_request::get_stats(v))
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_get_stats<'a>(&'a mut self) -> &'a mut GetStats {
+        if let ::std::option::Option::Some(ClientRequest_oneof_request::get_stats(_)) = self.request {
+        } else {
+            self.request = ::std::option::Option::Some(ClientRequest_oneof_request::get_stats(GetStats::new()));
+        }
+        match self.request {
+            ::std::option::Option::Some(ClientRequest_oneof_request::get_stats(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_get_stats(&mut self) -> GetStats {
+        if self.has_get_stats() {
+            match self.request.take() {
+                ::std::option::Option::Some(ClientRequest_oneof_request::get_stats(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            GetStats::new()
+        }
+    }
+
+    pub fn get_get_stats<'a>(&'a self) -> &'a GetStats {
+        match self.request {
+            ::std::option::Option::Some(ClientRequest_oneof_request::get_stats(ref v)) => v,
+            _ => GetStats::default_instance(),
+        }
+    }
+
+    // optional .sccache.Shutdown shutdown = 3;
+
+    pub fn clear_shutdown(&mut self) {
+        self.request = ::std::option::Option::None;
+    }
+
+    pub fn has_shutdown(&self) -> bool {
+        match self.request {
+            ::std::option::Option::Some(ClientRequest_oneof_request::shutdown(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_shutdown(&mut self, v: Shutdown) {
+        self.request = ::std::option::Option::Some(ClientRequest_oneof_request::shutdown(v))
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_shutdown<'a>(&'a mut self) -> &'a mut Shutdown {
+        if let ::std::option::Option::Some(ClientRequest_oneof_request::shutdown(_)) = self.request {
+        } else {
+            self.request = ::std::option::Option::Some(ClientRequest_oneof_request::shutdown(Shutdown::new()));
+        }
+        match self.request {
+            ::std::option::Option::Some(ClientRequest_oneof_request::shutdown(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_shutdown(&mut self) -> Shutdown {
+        if self.has_shutdown() {
+            match self.request.take() {
+                ::std::option::Option::Some(ClientRequest_oneof_request::shutdown(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Shutdown::new()
+        }
+    }
+
+    pub fn get_shutdown<'a>(&'a self) -> &'a Shutdown {
+        match self.request {
+            ::std::option::Option::Some(ClientRequest_oneof_request::shutdown(ref v)) => v,
+            _ => Shutdown::default_instance(),
+        }
+    }
+
+    // optional .sccache.ZeroStats zero_stats = 4;
+
+    pub fn clear_zero_stats(&mut self) {
+        self.request = ::std::option::Option::None;
+    }
+
+    pub fn has_zero_stats(&self) -> bool {
+        match self.request {
+            ::std::option::Option::Some(ClientRequest_oneof_request::zero_stats(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_zero_stats(&mut self, v: ZeroStats) {
+        self.request = ::std::option::Option::Some(ClientRequest_oneof_request::zero_stats(v))
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_zero_stats<'a>(&'a mut self) -> &'a mut ZeroStats {
+        if let ::std::option::Option::Some(ClientRequest_oneof_request::zero_stats(_)) = self.request {
+        } else {
+            self.request = ::std::option::Option::Some(ClientRequest_oneof_request::zero_stats(ZeroStats::new()));
+        }
+        match self.request {
+            ::std::option::Option::Some(ClientRequest_oneof_request::zero_stats(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_zero_stats(&mut self) -> ZeroStats {
+        if self.has_zero_stats() {
+            match self.request.take() {
+                ::std::option::Option::Some(ClientRequest_oneof_request::zero_stats(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ZeroStats::new()
+        }
+    }
+
+    pub fn get_zero_stats<'a>(&'a self) -> &'a ZeroStats {
+        match self.request {
+            ::std::option::Option::Some(ClientRequest_oneof_request::zero_stats(ref v)) => v,
+            _ => ZeroStats::default_instance(),
+        }
+    }
+
+    // optional .sccache.Hello hello = 5;
+
+    pub fn clear_hello(&mut self) {
+        self.request = ::std::option::Option::None;
+    }
+
+    pub fn has_hello(&self) -> bool {
+        match self.request {
+            ::std::option::Option::Some(ClientRequest_oneof_request::hello(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_hello(&mut self, v: Hello) {
+        self.request = ::std::option::Option::Some(ClientRequest_oneof_request::hello(v))
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_hello<'a>(&'a mut self) -> &'a mut Hello {
+        if let ::std::option::Option::Some(ClientRequest_oneof_request::hello(_)) = self.request {
+        } else {
+            self.request = ::std::option::Option::Some(ClientRequest_oneof_request::hello(Hello::new()));
+        }
+        match self.request {
+            ::std::option::Option::Some(ClientRequest_oneof_request::hello(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_hello(&mut self) -> Hello {
+        if self.has_hello() {
+            match self.request.take() {
+                ::std::option::Option::Some(ClientRequest_oneof_request::hello(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Hello::new()
+        }
+    }
+
+    pub fn get_hello<'a>(&'a self) -> &'a Hello {
+        match self.request {
+            ::std::option::Option::Some(ClientRequest_oneof_request::hello(ref v)) => v,
+            _ => Hello::default_instance(),
+        }
+    }
+
+    // optional .sccache.DumpTrace dump_trace = 6;
+
+    pub fn clear_dump_trace(&mut self) {
+        self.request = ::std::option::Option::None;
+    }
+
+    pub fn has_dump_trace(&self) -> bool {
+        match self.request {
+            ::std::option::Option::Some(ClientRequest_oneof_request::dump_trace(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_dump_trace(&mut self, v: DumpTrace) {
+        self.request = ::std::option::Option::Some(ClientRequest_oneof_request::dump_trace(v))
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_dump_trace<'a>(&'a mut self) -> &'a mut DumpTrace {
+        if let ::std::option::Option::Some(ClientRequest_oneof_request::dump_trace(_)) = self.request {
+        } else {
+            self.request = ::std::option::Option::Some(ClientRequest_oneof_request::dump_trace(DumpTrace::new()));
+        }
+        match self.request {
+            ::std::option::Option::Some(ClientRequest_oneof_request::dump_trace(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_dump_trace(&mut self) -> DumpTrace {
+        if self.has_dump_trace() {
+            match self.request.take() {
+                ::std::option::Option::Some(ClientRequest_oneof_request::dump_trace(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            DumpTrace::new()
+        }
+    }
+
+    pub fn get_dump_trace<'a>(&'a self) -> &'a DumpTrace {
+        match self.request {
+            ::std::option::Option::Some(ClientRequest_oneof_request::dump_trace(ref v)) => v,
+            _ => DumpTrace::default_instance(),
+        }
+    }
+}
+
+impl ::protobuf::Message for ClientRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    self.request = ::std::option::Option::Some(ClientRequest_oneof_request::compile(try!(is.read_message())));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    self.request = ::std::option::Option::Some(ClientRequest_oneof_request::get_stats(try!(is.read_message())));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    self.request = ::std::option::Option::Some(ClientRequest_oneof_request::shutdown(try!(is.read_message())));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    self.request = ::std::option::Option::Some(ClientRequest_oneof_request::zero_stats(try!(is.read_message())));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    self.request = ::std::option::Option::Some(ClientRequest_oneof_request::hello(try!(is.read_message())));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    self.request = ::std::option::Option::Some(ClientRequest_oneof_request::dump_trace(try!(is.read_message())));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let ::std::option::Option::Some(ref v) = self.request {
+            match v {
+                &ClientRequest_oneof_request::compile(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ClientRequest_oneof_request::get_stats(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ClientRequest_oneof_request::shutdown(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ClientRequest_oneof_request::zero_stats(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ClientRequest_oneof_request::hello(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ClientRequest_oneof_request::dump_trace(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let ::std::option::Option::Some(ref v) = self.request {
+            match v {
+                &ClientRequest_oneof_request::compile(ref v) => {
+                    try!(os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited));
+                    try!(os.write_raw_varint32(v.get_cached_size()));
+                    try!(v.write_to_with_cached_sizes(os));
+                },
+                &ClientRequest_oneof_request::get_stats(ref v) => {
+                    try!(os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited));
+                    try!(os.write_raw_varint32(v.get_cached_size()));
+                    try!(v.write_to_with_cached_sizes(os));
+                },
+                &ClientRequest_oneof_request::shutdown(ref v) => {
+                    try!(os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited));
+                    try!(os.write_raw_varint32(v.get_cached_size()));
+                    try!(v.write_to_with_cached_sizes(os));
+                },
+                &ClientRequest_oneof_request::zero_stats(ref v) => {
+                    try!(os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited));
+                    try!(os.write_raw_varint32(v.get_cached_size()));
+                    try!(v.write_to_with_cached_sizes(os));
+                },
+                &ClientRequest_oneof_request::hello(ref v) => {
+                    try!(os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited));
+                    try!(os.write_raw_varint32(v.get_cached_size()));
+                    try!(v.write_to_with_cached_sizes(os));
+                },
+                &ClientRequest_oneof_request::dump_trace(ref v) => {
+                    try!(os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited));
+                    try!(os.write_raw_varint32(v.get_cached_size()));
+                    try!(v.write_to_with_cached_sizes(os));
+                },
+            };
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<ClientRequest>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for ClientRequest {
+    fn new() -> ClientRequest {
+        ClientRequest::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<ClientRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
+                    "compile",
+                    ClientRequest::has_compile,
+                    ClientRequest::get_compile,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
+                    "get_stats",
+                    ClientRequest::has_get_stats,
+                    ClientRequest::get_get_stats,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
+                    "shutdown",
+                    ClientRequest::has_shutdown,
+                    ClientRequest::get_shutdown,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
+                    "zero_stats",
+                    ClientRequest::has_zero_stats,
+                    ClientRequest::get_zero_stats,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
+                    "hello",
+                    ClientRequest::has_hello,
+                    ClientRequest::get_hello,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
+                    "dump_trace",
+                    ClientRequest::has_dump_trace,
+                    ClientRequest::get_dump_trace,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ClientRequest>(
+                    "ClientRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for ClientRequest {
+    fn clear(&mut self) {
+        self.clear_compile();
+        self.clear_get_stats();
+        self.clear_shutdown();
+        self.clear_zero_stats();
+        self.clear_hello();
+        self.clear_dump_trace();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for ClientRequest {
+    fn eq(&self, other: &ClientRequest) -> bool {
+        self.request == other.request &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for ClientRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct Histogram {
+    // message fields
+    count: ::std::option::Option<u64>,
+    p50: ::std::option::Option<u64>,
+    p90: ::std::option::Option<u64>,
+    p99: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for Histogram {}
+
+impl Histogram {
+    pub fn new() -> Histogram {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static Histogram {
+        static mut instance: ::protobuf::lazy::Lazy<Histogram> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Histogram,
+        };
+        unsafe {
+            instance.get(|| {
+                Histogram {
+                    count: ::std::option::Option::None,
+                    p50: ::std::option::Option::None,
+                    p90: ::std::option::Option::None,
+                    p99: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 count = 1;
+
+    pub fn clear_count(&mut self) {
+        self.count = ::std::option::Option::None;
+    }
+
+    pub fn has_count(&self) -> bool {
+        self.count.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_count(&mut self, v: u64) {
+        self.count = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_count(&self) -> u64 {
+        self.count.unwrap_or(0)
+    }
+
+    // required uint64 p50 = 2;
+
+    pub fn clear_p50(&mut self) {
+        self.p50 = ::std::option::Option::None;
+    }
+
+    pub fn has_p50(&self) -> bool {
+        self.p50.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_p50(&mut self, v: u64) {
+        self.p50 = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_p50(&self) -> u64 {
+        self.p50.unwrap_or(0)
+    }
+
+    // required uint64 p90 = 3;
+
+    pub fn clear_p90(&mut self) {
+        self.p90 = ::std::option::Option::None;
+    }
+
+    pub fn has_p90(&self) -> bool {
+        self.p90.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_p90(&mut self, v: u64) {
+        self.p90 = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_p90(&self) -> u64 {
+        self.p90.unwrap_or(0)
+    }
+
+    // required uint64 p99 = 4;
+
+    pub fn clear_p99(&mut self) {
+        self.p99 = ::std::option::Option::None;
+    }
+
+    pub fn has_p99(&self) -> bool {
+        self.p99.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_p99(&mut self, v: u64) {
+        self.p99 = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_p99(&self) -> u64 {
+        self.p99.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for Histogram {
+    fn is_initialized(&self) -> bool {
+        if self.count.is_none() {
+            return false;
+        };
+        if self.p50.is_none() {
+            return false;
+        };
+        if self.p90.is_none() {
+            return false;
+        };
+        if self.p99.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.count = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.p50 = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.p90 = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.p99 = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.count.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.p50.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.p90.iter() {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.p99.iter() {
+            my_size += ::protobuf::rt::value_size(4, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.count {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.p50 {
+            try!(os.write_uint64(2, v));
+        };
+        if let Some(v) = self.p90 {
+            try!(os.write_uint64(3, v));
+        };
+        if let Some(v) = self.p99 {
+            try!(os.write_uint64(4, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<Histogram>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for Histogram {
+    fn new() -> Histogram {
+        Histogram::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Histogram>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "count",
+                    Histogram::has_count,
+                    Histogram::get_count,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "p50",
+                    Histogram::has_p50,
+                    Histogram::get_p50,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "p90",
+                    Histogram::has_p90,
+                    Histogram::get_p90,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "p99",
+                    Histogram::has_p99,
+                    Histogram::get_p99,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Histogram>(
+                    "Histogram",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for Histogram {
+    fn clear(&mut self) {
+        self.clear_count();
+        self.clear_p50();
+        self.clear_p90();
+        self.clear_p99();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for Histogram {
+    fn eq(&self, other: &Histogram) -> bool {
+        self.count == other.count &&
+        self.p50 == other.p50 &&
+        self.p90 == other.p90 &&
+        self.p99 == other.p99 &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for Histogram {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct CacheStatistic {
+    // message fields
+    name: ::protobuf::SingularField<::std::string::String>,
+    // message oneof groups
+    value: ::std::option::Option<CacheStatistic_oneof_value>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for CacheStatistic {}
+
+#[derive(Clone,PartialEq)]
+pub enum CacheStatistic_oneof_value {
+    count(u64),
+    str(::std::string::String),
+    size(u64),
+    duration(u64),
+    histogram(Histogram),
+}
+
+impl CacheStatistic {
+    pub fn new() -> CacheStatistic {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static CacheStatistic {
+        static mut instance: ::protobuf::lazy::Lazy<CacheStatistic> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const CacheStatistic,
+        };
+        unsafe {
+            instance.get(|| {
+                CacheStatistic {
+                    name: ::protobuf::SingularField::none(),
+                    value: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required string name = 1;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name<'a>(&'a mut self) -> &'a mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
+        };
+        self.name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_name<'a>(&'a self) -> &'a str {
+        match self.name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional uint64 count = 2;
+
+    pub fn clear_count(&mut self) {
+        self.value = ::std::option::Option::None;
+    }
+
+    pub fn has_count(&self) -> bool {
+        match self.value {
+            ::std::option::Option::Some(CacheStatistic_oneof_value::count(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_count(&mut self, v: u64) {
+        self.value = ::std::option::Option::Some(CacheStatistic_oneof_value::count(v))
+    }
+
+    pub fn get_count<'a>(&self) -> u64 {
+        match self.value {
+            ::std::option::Option::Some(CacheStatistic_oneof_value::count(v)) => v,
+            _ => 0,
         }
     }
 
-    // optional .sccache.GetStats get_stats = 2;
+    // optional string str = 3;
 
-    pub fn clear_get_stats(&mut self) {
-        self.request = ::std::option::Option::None;
+    pub fn clear_str(&mut self) {
+        self.value = ::std::option::Option::None;
     }
 
-    pub fn has_get_stats(&self) -> bool {
-        match self.request {
-            ::std::option::Option::Some(ClientRequest_oneof_request::get_stats(..)) => true,
+    pub fn has_str(&self) -> bool {
+        match self.value {
+            ::std::option::Option::Some(CacheStatistic_oneof_value::str(..)) => true,
             _ => false,
         }
     }
 
     // Param is passed by value, moved
-    pub fn set_get_stats(&mut self, v: GetStats) {
-        self.request = ::std::option::Option::Some(ClientRequest_oneof_request::get_stats(v))
+    pub fn set_str(&mut self, v: ::std::string::String) {
+        self.value = ::std::option::Option::Some(CacheStatistic_oneof_value::str(v))
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_get_stats<'a>(&'a mut self) -> &'a mut GetStats {
-        if let ::std::option::Option::Some(ClientRequest_oneof_request::get_stats(_)) = self.request {
+    pub fn mut_str<'a>(&'a mut self) -> &'a mut ::std::string::String {
+        if let ::std::option::Option::Some(CacheStatistic_oneof_value::str(_)) = self.value {
         } else {
-            self.request = ::std::option::Option::Some(ClientRequest_oneof_request::get_stats(GetStats::new()));
+            self.value = ::std::option::Option::Some(CacheStatistic_oneof_value::str(::std::string::String::new()));
         }
-        match self.request {
-            ::std::option::Option::Some(ClientRequest_oneof_request::get_stats(ref mut v)) => v,
+        match self.value {
+            ::std::option::Option::Some(CacheStatistic_oneof_value::str(ref mut v)) => v,
             _ => panic!(),
         }
     }
 
     // Take field
-    pub fn take_get_stats(&mut self) -> GetStats {
-        if self.has_get_stats() {
-            match self.request.take() {
-                ::std::option::Option::Some(ClientRequest_oneof_request::get_stats(v)) => v,
+    pub fn take_str(&mut self) -> ::std::string::String {
+        if self.has_str() {
+            match self.value.take() {
+                ::std::option::Option::Some(CacheStatistic_oneof_value::str(v)) => v,
                 _ => panic!(),
             }
         } else {
-            GetStats::new()
+            ::std::string::String::new()
         }
     }
 
-    pub fn get_get_stats<'a>(&'a self) -> &'a GetStats {
-        match self.request {
-            ::std::option::Option::Some(ClientRequest_oneof_request::get_stats(ref v)) => v,
-            _ => GetStats::default_instance(),
+    pub fn get_str<'a>(&'a self) -> &'a str {
+        match self.value {
+            ::std::option::Option::Some(CacheStatistic_oneof_value::str(ref v)) => v,
+            _ => "",
         }
     }
 
-    // optional .sccache.Shutdown shutdown = 3;
+    // optional uint64 size = 4;
 
-    pub fn clear_shutdown(&mut self) {
-        self.request = ::std::option::Option::None;
+    pub fn clear_size(&mut self) {
+        self.value = ::std::option::Option::None;
     }
 
-    pub fn has_shutdown(&self) -> bool {
-        match self.request {
-            ::std::option::Option::Some(ClientRequest_oneof_request::shutdown(..)) => true,
+    pub fn has_size(&self) -> bool {
+        match self.value {
+            ::std::option::Option::Some(CacheStatistic_oneof_value::size(..)) => true,
             _ => false,
         }
     }
 
     // Param is passed by value, moved
-    pub fn set_shutdown(&mut self, v: Shutdown) {
-        self.request = ::std::option::Option::Some(ClientRequest_oneof_request::shutdown(v))
+    pub fn set_size(&mut self, v: u64) {
+        self.value = ::std::option::Option::Some(CacheStatistic_oneof_value::size(v))
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_shutdown<'a>(&'a mut self) -> &'a mut Shutdown {
-        if let ::std::option::Option::Some(ClientRequest_oneof_request::shutdown(_)) = self.request {
-        } else {
-            self.request = ::std::option::Option::Some(ClientRequest_oneof_request::shutdown(Shutdown::new()));
-        }
-        match self.request {
-            ::std::option::Option::Some(ClientRequest_oneof_request::shutdown(ref mut v)) => v,
-            _ => panic!(),
+    pub fn get_size<'a>(&self) -> u64 {
+        match self.value {
+            ::std::option::Option::Some(CacheStatistic_oneof_value::size(v)) => v,
+            _ => 0,
         }
     }
 
-    // Take field
-    pub fn take_shutdown(&mut self) -> Shutdown {
-        if self.has_shutdown() {
-            match self.request.take() {
-                ::std::option::Option::Some(ClientRequest_oneof_request::shutdown(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            Shutdown::new()
+    // optional uint64 duration = 5;
+
+    pub fn clear_duration(&mut self) {
+        self.value = ::std::option::Option::None;
+    }
+
+    pub fn has_duration(&self) -> bool {
+        match self.value {
+            ::std::option::Option::Some(CacheStatistic_oneof_value::duration(..)) => true,
+            _ => false,
         }
     }
 
-    pub fn get_shutdown<'a>(&'a self) -> &'a Shutdown {
-        match self.request {
-            ::std::option::Option::Some(ClientRequest_oneof_request::shutdown(ref v)) => v,
-            _ => Shutdown::default_instance(),
+    // Param is passed by value, moved
+    pub fn set_duration(&mut self, v: u64) {
+        self.value = ::std::option::Option::Some(CacheStatistic_oneof_value::duration(v))
+    }
+
+    pub fn get_duration<'a>(&self) -> u64 {
+        match self.value {
+            ::std::option::Option::Some(CacheStatistic_oneof_value::duration(v)) => v,
+            _ => 0,
         }
     }
 
-    // optional .sccache.ZeroStats zero_stats = 4;
+    // optional .sccache.Histogram histogram = 6;
 
-    pub fn clear_zero_stats(&mut self) {
-        self.request = ::std::option::Option::None;
+    pub fn clear_histogram(&mut self) {
+        self.value = ::std::option::Option::None;
     }
 
-    pub fn has_zero_stats(&self) -> bool {
-        match self.request {
-            ::std::option::Option::Some(ClientRequest_oneof_request::zero_stats(..)) => true,
+    pub fn has_histogram(&self) -> bool {
+        match self.value {
+            ::std::option::Option::Some(CacheStatistic_oneof_value::histogram(..)) => true,
             _ => false,
         }
     }
 
     // Param is passed by value, moved
-    pub fn set_zero_stats(&mut self, v: ZeroStats) {
-        self.request = ::std::option::Option::Some(ClientRequest_oneof_request::zero_stats(v))
+    pub fn set_histogram(&mut self, v: Histogram) {
+        self.value = ::std::option::Option::Some(CacheStatistic_oneof_value::histogram(v))
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_zero_stats<'a>(&'a mut self) -> &'a mut ZeroStats {
-        if let ::std::option::Option::Some(ClientRequest_oneof_request::zero_stats(_)) = self.request {
+    pub fn mut_histogram<'a>(&'a mut self) -> &'a mut Histogram {
+        if let ::std::option::Option::Some(CacheStatistic_oneof_value::histogram(_)) = self.value {
         } else {
-            self.request = ::std::option::Option::Some(ClientRequest_oneof_request::zero_stats(ZeroStats::new()));
+            self.value = ::std::option::Option::Some(CacheStatistic_oneof_value::histogram(Histogram::new()));
         }
-        match self.request {
-            ::std::option::Option::Some(ClientRequest_oneof_request::zero_stats(ref mut v)) => v,
+        match self.value {
+            ::std::option::Option::Some(CacheStatistic_oneof_value::histogram(ref mut v)) => v,
             _ => panic!(),
         }
     }
 
     // Take field
-    pub fn take_zero_stats(&mut self) -> ZeroStats {
-        if self.has_zero_stats() {
-            match self.request.take() {
-                ::std::option::Option::Some(ClientRequest_oneof_request::zero_stats(v)) => v,
+    pub fn take_histogram(&mut self) -> Histogram {
+        if self.has_histogram() {
+            match self.value.take() {
+                ::std::option::Option::Some(CacheStatistic_oneof_value::histogram(v)) => v,
                 _ => panic!(),
             }
         } else {
-            ZeroStats::new()
+            Histogram::new()
         }
     }
 
-    pub fn get_zero_stats<'a>(&'a self) -> &'a ZeroStats {
-        match self.request {
-            ::std::option::Option::Some(ClientRequest_oneof_request::zero_stats(ref v)) => v,
-            _ => ZeroStats::default_instance(),
+    pub fn get_histogram<'a>(&'a self) -> &'a Histogram {
+        match self.value {
+            ::std::option::Option::Some(CacheStatistic_oneof_value::histogram(ref v)) => v,
+            _ => Histogram::default_instance(),
         }
     }
 }
 
-impl ::protobuf::Message for ClientRequest {
+impl ::protobuf::Message for CacheStatistic {
     fn is_initialized(&self) -> bool {
+        if self.name.is_none() {
+            return false;
+        };
         true
     }
 
@@ -937,28 +2356,37 @@ impl ::protobuf::Message for ClientRequest {
             let (field_number, wire_type) = try!(is.read_tag_unpack());
             match field_number {
                 1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    };
-                    self.request = ::std::option::Option::Some(ClientRequest_oneof_request::compile(try!(is.read_message())));
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name));
                 },
                 2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
-                    self.request = ::std::option::Option::Some(ClientRequest_oneof_request::get_stats(try!(is.read_message())));
+                    self.value = ::std::option::Option::Some(CacheStatistic_oneof_value::count(try!(is.read_uint64())));
                 },
                 3 => {
                     if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
-                    self.request = ::std::option::Option::Some(ClientRequest_oneof_request::shutdown(try!(is.read_message())));
+                    self.value = ::std::option::Option::Some(CacheStatistic_oneof_value::str(try!(is.read_string())));
                 },
                 4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    self.value = ::std::option::Option::Some(CacheStatistic_oneof_value::size(try!(is.read_uint64())));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    self.value = ::std::option::Option::Some(CacheStatistic_oneof_value::duration(try!(is.read_uint64())));
+                },
+                6 => {
                     if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
-                    self.request = ::std::option::Option::Some(ClientRequest_oneof_request::zero_stats(try!(is.read_message())));
+                    self.value = ::std::option::Option::Some(CacheStatistic_oneof_value::histogram(try!(is.read_message())));
                 },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
@@ -972,21 +2400,24 @@ impl ::protobuf::Message for ClientRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let ::std::option::Option::Some(ref v) = self.request {
+        for value in self.name.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        if let ::std::option::Option::Some(ref v) = self.value {
             match v {
-                &ClientRequest_oneof_request::compile(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                &CacheStatistic_oneof_value::count(v) => {
+                    my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
                 },
-                &ClientRequest_oneof_request::get_stats(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                &CacheStatistic_oneof_value::str(ref v) => {
+                    my_size += ::protobuf::rt::string_size(3, &v);
                 },
-                &ClientRequest_oneof_request::shutdown(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                &CacheStatistic_oneof_value::size(v) => {
+                    my_size += ::protobuf::rt::value_size(4, v, ::protobuf::wire_format::WireTypeVarint);
                 },
-                &ClientRequest_oneof_request::zero_stats(ref v) => {
+                &CacheStatistic_oneof_value::duration(v) => {
+                    my_size += ::protobuf::rt::value_size(5, v, ::protobuf::wire_format::WireTypeVarint);
+                },
+                &CacheStatistic_oneof_value::histogram(ref v) => {
                     let len = v.compute_size();
                     my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
                 },
@@ -998,25 +2429,25 @@ impl ::protobuf::Message for ClientRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let ::std::option::Option::Some(ref v) = self.request {
+        if let Some(v) = self.name.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        if let ::std::option::Option::Some(ref v) = self.value {
             match v {
-                &ClientRequest_oneof_request::compile(ref v) => {
-                    try!(os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited));
-                    try!(os.write_raw_varint32(v.get_cached_size()));
-                    try!(v.write_to_with_cached_sizes(os));
+                &CacheStatistic_oneof_value::count(v) => {
+                    try!(os.write_uint64(2, v));
                 },
-                &ClientRequest_oneof_request::get_stats(ref v) => {
-                    try!(os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited));
-                    try!(os.write_raw_varint32(v.get_cached_size()));
-                    try!(v.write_to_with_cached_sizes(os));
+                &CacheStatistic_oneof_value::str(ref v) => {
+                    try!(os.write_string(3, v));
                 },
-                &ClientRequest_oneof_request::shutdown(ref v) => {
-                    try!(os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited));
-                    try!(os.write_raw_varint32(v.get_cached_size()));
-                    try!(v.write_to_with_cached_sizes(os));
+                &CacheStatistic_oneof_value::size(v) => {
+                    try!(os.write_uint64(4, v));
                 },
-                &ClientRequest_oneof_request::zero_stats(ref v) => {
-                    try!(os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited));
+                &CacheStatistic_oneof_value::duration(v) => {
+                    try!(os.write_uint64(5, v));
+                },
+                &CacheStatistic_oneof_value::histogram(ref v) => {
+                    try!(os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited));
                     try!(os.write_raw_varint32(v.get_cached_size()));
                     try!(v.write_to_with_cached_sizes(os));
                 },
@@ -1039,7 +2470,7 @@ impl ::protobuf::Message for ClientRequest {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<ClientRequest>()
+        ::std::any::TypeId::of::<CacheStatistic>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -1051,12 +2482,12 @@ impl ::protobuf::Message for ClientRequest {
     }
 }
 
-impl ::protobuf::MessageStatic for ClientRequest {
-    fn new() -> ClientRequest {
-        ClientRequest::new()
+impl ::protobuf::MessageStatic for CacheStatistic {
+    fn new() -> CacheStatistic {
+        CacheStatistic::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<ClientRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<CacheStatistic>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -1064,28 +2495,38 @@ impl ::protobuf::MessageStatic for ClientRequest {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
-                    "compile",
-                    ClientRequest::has_compile,
-                    ClientRequest::get_compile,
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "name",
+                    CacheStatistic::has_name,
+                    CacheStatistic::get_name,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
-                    "get_stats",
-                    ClientRequest::has_get_stats,
-                    ClientRequest::get_get_stats,
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "count",
+                    CacheStatistic::has_count,
+                    CacheStatistic::get_count,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
-                    "shutdown",
-                    ClientRequest::has_shutdown,
-                    ClientRequest::get_shutdown,
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "str",
+                    CacheStatistic::has_str,
+                    CacheStatistic::get_str,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "size",
+                    CacheStatistic::has_size,
+                    CacheStatistic::get_size,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "duration",
+                    CacheStatistic::has_duration,
+                    CacheStatistic::get_duration,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
-                    "zero_stats",
-                    ClientRequest::has_zero_stats,
-                    ClientRequest::get_zero_stats,
+                    "histogram",
+                    CacheStatistic::has_histogram,
+                    CacheStatistic::get_histogram,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<ClientRequest>(
-                    "ClientRequest",
+                ::protobuf::reflect::MessageDescriptor::new::<CacheStatistic>(
+                    "CacheStatistic",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1094,65 +2535,62 @@ impl ::protobuf::MessageStatic for ClientRequest {
     }
 }
 
-impl ::protobuf::Clear for ClientRequest {
+impl ::protobuf::Clear for CacheStatistic {
     fn clear(&mut self) {
-        self.clear_compile();
-        self.clear_get_stats();
-        self.clear_shutdown();
-        self.clear_zero_stats();
+        self.clear_name();
+        self.clear_count();
+        self.clear_str();
+        self.clear_size();
+        self.clear_duration();
+        self.clear_histogram();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for ClientRequest {
-    fn eq(&self, other: &ClientRequest) -> bool {
-        self.request == other.request &&
+impl ::std::cmp::PartialEq for CacheStatistic {
+    fn eq(&self, other: &CacheStatistic) -> bool {
+        self.name == other.name &&
+        self.value == other.value &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for ClientRequest {
+impl ::std::fmt::Debug for CacheStatistic {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 #[derive(Clone,Default)]
-pub struct CacheStatistic {
+pub struct CacheStatsBreakdown {
     // message fields
-    name: ::protobuf::SingularField<::std::string::String>,
-    // message oneof groups
-    value: ::std::option::Option<CacheStatistic_oneof_value>,
+    kind: ::protobuf::SingularField<::std::string::String>,
+    value: ::protobuf::SingularField<::std::string::String>,
+    stats: ::protobuf::RepeatedField<CacheStatistic>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for CacheStatistic {}
-
-#[derive(Clone,PartialEq)]
-pub enum CacheStatistic_oneof_value {
-    count(u64),
-    str(::std::string::String),
-    size(u64),
-}
+unsafe impl ::std::marker::Sync for CacheStatsBreakdown {}
 
-impl CacheStatistic {
-    pub fn new() -> CacheStatistic {
+impl CacheStatsBreakdown {
+    pub fn new() -> CacheStatsBreakdown {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static CacheStatistic {
-        static mut instance: ::protobuf::lazy::Lazy<CacheStatistic> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static CacheStatsBreakdown {
+        static mut instance: ::protobuf::lazy::Lazy<CacheStatsBreakdown> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const CacheStatistic,
+            ptr: 0 as *const CacheStatsBreakdown,
         };
         unsafe {
             instance.get(|| {
-                CacheStatistic {
-                    name: ::protobuf::SingularField::none(),
-                    value: ::std::option::Option::None,
+                CacheStatsBreakdown {
+                    kind: ::protobuf::SingularField::none(),
+                    value: ::protobuf::SingularField::none(),
+                    stats: ::protobuf::RepeatedField::new(),
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -1160,146 +2598,110 @@ impl CacheStatistic {
         }
     }
 
-    // required string name = 1;
+    // required string kind = 1;
 
-    pub fn clear_name(&mut self) {
-        self.name.clear();
+    pub fn clear_kind(&mut self) {
+        self.kind.clear();
     }
 
-    pub fn has_name(&self) -> bool {
-        self.name.is_some()
+    pub fn has_kind(&self) -> bool {
+        self.kind.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_name(&mut self, v: ::std::string::String) {
-        self.name = ::protobuf::SingularField::some(v);
+    pub fn set_kind(&mut self, v: ::std::string::String) {
+        self.kind = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_name<'a>(&'a mut self) -> &'a mut ::std::string::String {
-        if self.name.is_none() {
-            self.name.set_default();
+    pub fn mut_kind<'a>(&'a mut self) -> &'a mut ::std::string::String {
+        if self.kind.is_none() {
+            self.kind.set_default();
         };
-        self.name.as_mut().unwrap()
+        self.kind.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_name(&mut self) -> ::std::string::String {
-        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_kind(&mut self) -> ::std::string::String {
+        self.kind.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn get_name<'a>(&'a self) -> &'a str {
-        match self.name.as_ref() {
+    pub fn get_kind<'a>(&'a self) -> &'a str {
+        match self.kind.as_ref() {
             Some(v) => &v,
             None => "",
         }
     }
 
-    // optional uint64 count = 2;
-
-    pub fn clear_count(&mut self) {
-        self.value = ::std::option::Option::None;
-    }
-
-    pub fn has_count(&self) -> bool {
-        match self.value {
-            ::std::option::Option::Some(CacheStatistic_oneof_value::count(..)) => true,
-            _ => false,
-        }
-    }
-
-    // Param is passed by value, moved
-    pub fn set_count(&mut self, v: u64) {
-        self.value = ::std::option::Option::Some(CacheStatistic_oneof_value::count(v))
-    }
-
-    pub fn get_count<'a>(&self) -> u64 {
-        match self.value {
-            ::std::option::Option::Some(CacheStatistic_oneof_value::count(v)) => v,
-            _ => 0,
-        }
-    }
-
-    // optional string str = 3;
+    // required string value = 2;
 
-    pub fn clear_str(&mut self) {
-        self.value = ::std::option::Option::None;
+    pub fn clear_value(&mut self) {
+        self.value.clear();
     }
 
-    pub fn has_str(&self) -> bool {
-        match self.value {
-            ::std::option::Option::Some(CacheStatistic_oneof_value::str(..)) => true,
-            _ => false,
-        }
+    pub fn has_value(&self) -> bool {
+        self.value.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_str(&mut self, v: ::std::string::String) {
-        self.value = ::std::option::Option::Some(CacheStatistic_oneof_value::str(v))
+    pub fn set_value(&mut self, v: ::std::string::String) {
+        self.value = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_str<'a>(&'a mut self) -> &'a mut ::std::string::String {
-        if let ::std::option::Option::Some(CacheStatistic_oneof_value::str(_)) = self.value {
-        } else {
-            self.value = ::std::option::Option::Some(CacheStatistic_oneof_value::str(::std::string::String::new()));
-        }
-        match self.value {
-            ::std::option::Option::Some(CacheStatistic_oneof_value::str(ref mut v)) => v,
-            _ => panic!(),
-        }
+    pub fn mut_value<'a>(&'a mut self) -> &'a mut ::std::string::String {
+        if self.value.is_none() {
+            self.value.set_default();
+        };
+        self.value.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_str(&mut self) -> ::std::string::String {
-        if self.has_str() {
-            match self.value.take() {
-                ::std::option::Option::Some(CacheStatistic_oneof_value::str(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            ::std::string::String::new()
-        }
+    pub fn take_value(&mut self) -> ::std::string::String {
+        self.value.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn get_str<'a>(&'a self) -> &'a str {
-        match self.value {
-            ::std::option::Option::Some(CacheStatistic_oneof_value::str(ref v)) => v,
-            _ => "",
+    pub fn get_value<'a>(&'a self) -> &'a str {
+        match self.value.as_ref() {
+            Some(v) => &v,
+            None => "",
         }
     }
 
-    // optional uint64 size = 4;
+    // repeated .sccache.CacheStatistic stats = 3;
 
-    pub fn clear_size(&mut self) {
-        self.value = ::std::option::Option::None;
+    pub fn clear_stats(&mut self) {
+        self.stats.clear();
     }
 
-    pub fn has_size(&self) -> bool {
-        match self.value {
-            ::std::option::Option::Some(CacheStatistic_oneof_value::size(..)) => true,
-            _ => false,
-        }
+    // Param is passed by value, moved
+    pub fn set_stats(&mut self, v: ::protobuf::RepeatedField<CacheStatistic>) {
+        self.stats = v;
     }
 
-    // Param is passed by value, moved
-    pub fn set_size(&mut self, v: u64) {
-        self.value = ::std::option::Option::Some(CacheStatistic_oneof_value::size(v))
+    // Mutable pointer to the field.
+    pub fn mut_stats<'a>(&'a mut self) -> &'a mut ::protobuf::RepeatedField<CacheStatistic> {
+        &mut self.stats
     }
 
-    pub fn get_size<'a>(&self) -> u64 {
-        match self.value {
-            ::std::option::Option::Some(CacheStatistic_oneof_value::size(v)) => v,
-            _ => 0,
-        }
+    // Take field
+    pub fn take_stats(&mut self) -> ::protobuf::RepeatedField<CacheStatistic> {
+        ::std::mem::replace(&mut self.stats, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_stats<'a>(&'a self) -> &'a [CacheStatistic] {
+        &self.stats
     }
 }
 
-impl ::protobuf::Message for CacheStatistic {
+impl ::protobuf::Message for CacheStatsBreakdown {
     fn is_initialized(&self) -> bool {
-        if self.name.is_none() {
+        if self.kind.is_none() {
+            return false;
+        };
+        if self.value.is_none() {
             return false;
         };
         true
@@ -1310,25 +2712,13 @@ impl ::protobuf::Message for CacheStatistic {
             let (field_number, wire_type) = try!(is.read_tag_unpack());
             match field_number {
                 1 => {
-                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name));
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.kind));
                 },
                 2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    };
-                    self.value = ::std::option::Option::Some(CacheStatistic_oneof_value::count(try!(is.read_uint64())));
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.value));
                 },
                 3 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    };
-                    self.value = ::std::option::Option::Some(CacheStatistic_oneof_value::str(try!(is.read_string())));
-                },
-                4 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    };
-                    self.value = ::std::option::Option::Some(CacheStatistic_oneof_value::size(try!(is.read_uint64())));
+                    try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.stats));
                 },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
@@ -1342,21 +2732,15 @@ impl ::protobuf::Message for CacheStatistic {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in self.name.iter() {
+        for value in self.kind.iter() {
             my_size += ::protobuf::rt::string_size(1, &value);
         };
-        if let ::std::option::Option::Some(ref v) = self.value {
-            match v {
-                &CacheStatistic_oneof_value::count(v) => {
-                    my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
-                },
-                &CacheStatistic_oneof_value::str(ref v) => {
-                    my_size += ::protobuf::rt::string_size(3, &v);
-                },
-                &CacheStatistic_oneof_value::size(v) => {
-                    my_size += ::protobuf::rt::value_size(4, v, ::protobuf::wire_format::WireTypeVarint);
-                },
-            };
+        for value in self.value.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in self.stats.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -1364,21 +2748,16 @@ impl ::protobuf::Message for CacheStatistic {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.name.as_ref() {
+        if let Some(v) = self.kind.as_ref() {
             try!(os.write_string(1, &v));
         };
-        if let ::std::option::Option::Some(ref v) = self.value {
-            match v {
-                &CacheStatistic_oneof_value::count(v) => {
-                    try!(os.write_uint64(2, v));
-                },
-                &CacheStatistic_oneof_value::str(ref v) => {
-                    try!(os.write_string(3, v));
-                },
-                &CacheStatistic_oneof_value::size(v) => {
-                    try!(os.write_uint64(4, v));
-                },
-            };
+        if let Some(v) = self.value.as_ref() {
+            try!(os.write_string(2, &v));
+        };
+        for v in self.stats.iter() {
+            try!(os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
         };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
@@ -1397,7 +2776,7 @@ impl ::protobuf::Message for CacheStatistic {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<CacheStatistic>()
+        ::std::any::TypeId::of::<CacheStatsBreakdown>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -1409,12 +2788,12 @@ impl ::protobuf::Message for CacheStatistic {
     }
 }
 
-impl ::protobuf::MessageStatic for CacheStatistic {
-    fn new() -> CacheStatistic {
-        CacheStatistic::new()
+impl ::protobuf::MessageStatic for CacheStatsBreakdown {
+    fn new() -> CacheStatsBreakdown {
+        CacheStatsBreakdown::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<CacheStatistic>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<CacheStatsBreakdown>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -1423,27 +2802,21 @@ impl ::protobuf::MessageStatic for CacheStatistic {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
-                    "name",
-                    CacheStatistic::has_name,
-                    CacheStatistic::get_name,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
-                    "count",
-                    CacheStatistic::has_count,
-                    CacheStatistic::get_count,
+                    "kind",
+                    CacheStatsBreakdown::has_kind,
+                    CacheStatsBreakdown::get_kind,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
-                    "str",
-                    CacheStatistic::has_str,
-                    CacheStatistic::get_str,
+                    "value",
+                    CacheStatsBreakdown::has_value,
+                    CacheStatsBreakdown::get_value,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
-                    "size",
-                    CacheStatistic::has_size,
-                    CacheStatistic::get_size,
+                fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
+                    "stats",
+                    CacheStatsBreakdown::get_stats,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<CacheStatistic>(
-                    "CacheStatistic",
+                ::protobuf::reflect::MessageDescriptor::new::<CacheStatsBreakdown>(
+                    "CacheStatsBreakdown",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1452,25 +2825,25 @@ impl ::protobuf::MessageStatic for CacheStatistic {
     }
 }
 
-impl ::protobuf::Clear for CacheStatistic {
+impl ::protobuf::Clear for CacheStatsBreakdown {
     fn clear(&mut self) {
-        self.clear_name();
-        self.clear_count();
-        self.clear_str();
-        self.clear_size();
+        self.clear_kind();
+        self.clear_value();
+        self.clear_stats();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for CacheStatistic {
-    fn eq(&self, other: &CacheStatistic) -> bool {
-        self.name == other.name &&
+impl ::std::cmp::PartialEq for CacheStatsBreakdown {
+    fn eq(&self, other: &CacheStatsBreakdown) -> bool {
+        self.kind == other.kind &&
         self.value == other.value &&
+        self.stats == other.stats &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for CacheStatistic {
+impl ::std::fmt::Debug for CacheStatsBreakdown {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
@@ -1480,6 +2853,7 @@ impl ::std::fmt::Debug for CacheStatistic {
 pub struct CacheStats {
     // message fields
     stats: ::protobuf::RepeatedField<CacheStatistic>,
+    breakdowns: ::protobuf::RepeatedField<CacheStatsBreakdown>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
@@ -1502,6 +2876,7 @@ impl CacheStats {
             instance.get(|| {
                 CacheStats {
                     stats: ::protobuf::RepeatedField::new(),
+                    breakdowns: ::protobuf::RepeatedField::new(),
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -1511,27 +2886,52 @@ impl CacheStats {
 
     // repeated .sccache.CacheStatistic stats = 1;
 
-    pub fn clear_stats(&mut self) {
-        self.stats.clear();
+    pub fn clear_stats(&mut self) {
+        self.stats.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_stats(&mut self, v: ::protobuf::RepeatedField<CacheStatistic>) {
+        self.stats = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_stats<'a>(&'a mut self) -> &'a mut ::protobuf::RepeatedField<CacheStatistic> {
+        &mut self.stats
+    }
+
+    // Take field
+    pub fn take_stats(&mut self) -> ::protobuf::RepeatedField<CacheStatistic> {
+        ::std::mem::replace(&mut self.stats, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_stats<'a>(&'a self) -> &'a [CacheStatistic] {
+        &self.stats
+    }
+
+    // repeated .sccache.CacheStatsBreakdown breakdowns = 2;
+
+    pub fn clear_breakdowns(&mut self) {
+        self.breakdowns.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_stats(&mut self, v: ::protobuf::RepeatedField<CacheStatistic>) {
-        self.stats = v;
+    pub fn set_breakdowns(&mut self, v: ::protobuf::RepeatedField<CacheStatsBreakdown>) {
+        self.breakdowns = v;
     }
 
     // Mutable pointer to the field.
-    pub fn mut_stats<'a>(&'a mut self) -> &'a mut ::protobuf::RepeatedField<CacheStatistic> {
-        &mut self.stats
+    pub fn mut_breakdowns<'a>(&'a mut self) -> &'a mut ::protobuf::RepeatedField<CacheStatsBreakdown> {
+        &mut self.breakdowns
     }
 
     // Take field
-    pub fn take_stats(&mut self) -> ::protobuf::RepeatedField<CacheStatistic> {
-        ::std::mem::replace(&mut self.stats, ::protobuf::RepeatedField::new())
+    pub fn take_breakdowns(&mut self) -> ::protobuf::RepeatedField<CacheStatsBreakdown> {
+        ::std::mem::replace(&mut self.breakdowns, ::protobuf::RepeatedField::new())
     }
 
-    pub fn get_stats<'a>(&'a self) -> &'a [CacheStatistic] {
-        &self.stats
+    pub fn get_breakdowns<'a>(&'a self) -> &'a [CacheStatsBreakdown] {
+        &self.breakdowns
     }
 }
 
@@ -1547,6 +2947,9 @@ impl ::protobuf::Message for CacheStats {
                 1 => {
                     try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.stats));
                 },
+                2 => {
+                    try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.breakdowns));
+                },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
                 },
@@ -1563,6 +2966,10 @@ impl ::protobuf::Message for CacheStats {
             let len = value.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         };
+        for value in self.breakdowns.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -1574,6 +2981,11 @@ impl ::protobuf::Message for CacheStats {
             try!(os.write_raw_varint32(v.get_cached_size()));
             try!(v.write_to_with_cached_sizes(os));
         };
+        for v in self.breakdowns.iter() {
+            try!(os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
     }
@@ -1620,6 +3032,10 @@ impl ::protobuf::MessageStatic for CacheStats {
                     "stats",
                     CacheStats::get_stats,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
+                    "breakdowns",
+                    CacheStats::get_breakdowns,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<CacheStats>(
                     "CacheStats",
                     fields,
@@ -1633,6 +3049,7 @@ impl ::protobuf::MessageStatic for CacheStats {
 impl ::protobuf::Clear for CacheStats {
     fn clear(&mut self) {
         self.clear_stats();
+        self.clear_breakdowns();
         self.unknown_fields.clear();
     }
 }
@@ -1640,6 +3057,7 @@ impl ::protobuf::Clear for CacheStats {
 impl ::std::cmp::PartialEq for CacheStats {
     fn eq(&self, other: &CacheStats) -> bool {
         self.stats == other.stats &&
+        self.breakdowns == other.breakdowns &&
         self.unknown_fields == other.unknown_fields
     }
 }
@@ -1964,6 +3382,134 @@ impl ::std::fmt::Debug for CompileStarted {
     }
 }
 
+#[derive(Clone,Default)]
+pub struct CompileQueued {
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for CompileQueued {}
+
+impl CompileQueued {
+    pub fn new() -> CompileQueued {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static CompileQueued {
+        static mut instance: ::protobuf::lazy::Lazy<CompileQueued> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const CompileQueued,
+        };
+        unsafe {
+            instance.get(|| {
+                CompileQueued {
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+}
+
+impl ::protobuf::Message for CompileQueued {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<CompileQueued>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for CompileQueued {
+    fn new() -> CompileQueued {
+        CompileQueued::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<CompileQueued>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let fields = ::std::vec::Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new::<CompileQueued>(
+                    "CompileQueued",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for CompileQueued {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for CompileQueued {
+    fn eq(&self, other: &CompileQueued) -> bool {
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for CompileQueued {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
 #[derive(Clone,Default)]
 pub struct CompileFinished {
     // message fields
@@ -2306,28 +3852,156 @@ impl ::std::fmt::Debug for CompileFinished {
 }
 
 #[derive(Clone,Default)]
-pub struct UnhandledCompile {
+pub struct UnhandledCompile {
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for UnhandledCompile {}
+
+impl UnhandledCompile {
+    pub fn new() -> UnhandledCompile {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static UnhandledCompile {
+        static mut instance: ::protobuf::lazy::Lazy<UnhandledCompile> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const UnhandledCompile,
+        };
+        unsafe {
+            instance.get(|| {
+                UnhandledCompile {
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+}
+
+impl ::protobuf::Message for UnhandledCompile {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<UnhandledCompile>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for UnhandledCompile {
+    fn new() -> UnhandledCompile {
+        UnhandledCompile::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<UnhandledCompile>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let fields = ::std::vec::Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new::<UnhandledCompile>(
+                    "UnhandledCompile",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for UnhandledCompile {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for UnhandledCompile {
+    fn eq(&self, other: &UnhandledCompile) -> bool {
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for UnhandledCompile {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct UnknownCommand {
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for UnhandledCompile {}
+unsafe impl ::std::marker::Sync for UnknownCommand {}
 
-impl UnhandledCompile {
-    pub fn new() -> UnhandledCompile {
+impl UnknownCommand {
+    pub fn new() -> UnknownCommand {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static UnhandledCompile {
-        static mut instance: ::protobuf::lazy::Lazy<UnhandledCompile> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static UnknownCommand {
+        static mut instance: ::protobuf::lazy::Lazy<UnknownCommand> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const UnhandledCompile,
+            ptr: 0 as *const UnknownCommand,
         };
         unsafe {
             instance.get(|| {
-                UnhandledCompile {
+                UnknownCommand {
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -2336,7 +4010,7 @@ impl UnhandledCompile {
     }
 }
 
-impl ::protobuf::Message for UnhandledCompile {
+impl ::protobuf::Message for UnknownCommand {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -2380,7 +4054,7 @@ impl ::protobuf::Message for UnhandledCompile {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<UnhandledCompile>()
+        ::std::any::TypeId::of::<UnknownCommand>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -2392,12 +4066,12 @@ impl ::protobuf::Message for UnhandledCompile {
     }
 }
 
-impl ::protobuf::MessageStatic for UnhandledCompile {
-    fn new() -> UnhandledCompile {
-        UnhandledCompile::new()
+impl ::protobuf::MessageStatic for UnknownCommand {
+    fn new() -> UnknownCommand {
+        UnknownCommand::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<UnhandledCompile>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<UnknownCommand>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -2405,8 +4079,8 @@ impl ::protobuf::MessageStatic for UnhandledCompile {
         unsafe {
             descriptor.get(|| {
                 let fields = ::std::vec::Vec::new();
-                ::protobuf::reflect::MessageDescriptor::new::<UnhandledCompile>(
-                    "UnhandledCompile",
+                ::protobuf::reflect::MessageDescriptor::new::<UnknownCommand>(
+                    "UnknownCommand",
                     fields,
                     file_descriptor_proto()
                 )
@@ -2415,56 +4089,133 @@ impl ::protobuf::MessageStatic for UnhandledCompile {
     }
 }
 
-impl ::protobuf::Clear for UnhandledCompile {
+impl ::protobuf::Clear for UnknownCommand {
     fn clear(&mut self) {
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for UnhandledCompile {
-    fn eq(&self, other: &UnhandledCompile) -> bool {
+impl ::std::cmp::PartialEq for UnknownCommand {
+    fn eq(&self, other: &UnknownCommand) -> bool {
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for UnhandledCompile {
+impl ::std::fmt::Debug for UnknownCommand {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 #[derive(Clone,Default)]
-pub struct UnknownCommand {
+pub struct TraceDumped {
+    // message fields
+    path: ::protobuf::SingularField<::std::string::String>,
+    error: ::protobuf::SingularField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for UnknownCommand {}
+unsafe impl ::std::marker::Sync for TraceDumped {}
 
-impl UnknownCommand {
-    pub fn new() -> UnknownCommand {
+impl TraceDumped {
+    pub fn new() -> TraceDumped {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static UnknownCommand {
-        static mut instance: ::protobuf::lazy::Lazy<UnknownCommand> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static TraceDumped {
+        static mut instance: ::protobuf::lazy::Lazy<TraceDumped> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const UnknownCommand,
+            ptr: 0 as *const TraceDumped,
         };
         unsafe {
             instance.get(|| {
-                UnknownCommand {
+                TraceDumped {
+                    path: ::protobuf::SingularField::none(),
+                    error: ::protobuf::SingularField::none(),
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
             })
         }
     }
+
+    // optional string path = 1;
+
+    pub fn clear_path(&mut self) {
+        self.path.clear();
+    }
+
+    pub fn has_path(&self) -> bool {
+        self.path.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_path(&mut self, v: ::std::string::String) {
+        self.path = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_path<'a>(&'a mut self) -> &'a mut ::std::string::String {
+        if self.path.is_none() {
+            self.path.set_default();
+        };
+        self.path.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_path(&mut self) -> ::std::string::String {
+        self.path.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_path<'a>(&'a self) -> &'a str {
+        match self.path.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional string error = 2;
+
+    pub fn clear_error(&mut self) {
+        self.error.clear();
+    }
+
+    pub fn has_error(&self) -> bool {
+        self.error.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_error(&mut self, v: ::std::string::String) {
+        self.error = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_error<'a>(&'a mut self) -> &'a mut ::std::string::String {
+        if self.error.is_none() {
+            self.error.set_default();
+        };
+        self.error.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_error(&mut self) -> ::std::string::String {
+        self.error.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_error<'a>(&'a self) -> &'a str {
+        match self.error.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
 }
 
-impl ::protobuf::Message for UnknownCommand {
+impl ::protobuf::Message for TraceDumped {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -2473,6 +4224,12 @@ impl ::protobuf::Message for UnknownCommand {
         while !try!(is.eof()) {
             let (field_number, wire_type) = try!(is.read_tag_unpack());
             match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.path));
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.error));
+                },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
                 },
@@ -2485,12 +4242,24 @@ impl ::protobuf::Message for UnknownCommand {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
+        for value in self.path.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in self.error.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.path.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        if let Some(v) = self.error.as_ref() {
+            try!(os.write_string(2, &v));
+        };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
     }
@@ -2508,7 +4277,7 @@ impl ::protobuf::Message for UnknownCommand {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<UnknownCommand>()
+        ::std::any::TypeId::of::<TraceDumped>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -2520,21 +4289,31 @@ impl ::protobuf::Message for UnknownCommand {
     }
 }
 
-impl ::protobuf::MessageStatic for UnknownCommand {
-    fn new() -> UnknownCommand {
-        UnknownCommand::new()
+impl ::protobuf::MessageStatic for TraceDumped {
+    fn new() -> TraceDumped {
+        TraceDumped::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<UnknownCommand>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<TraceDumped>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
         };
         unsafe {
             descriptor.get(|| {
-                let fields = ::std::vec::Vec::new();
-                ::protobuf::reflect::MessageDescriptor::new::<UnknownCommand>(
-                    "UnknownCommand",
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "path",
+                    TraceDumped::has_path,
+                    TraceDumped::get_path,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "error",
+                    TraceDumped::has_error,
+                    TraceDumped::get_error,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<TraceDumped>(
+                    "TraceDumped",
                     fields,
                     file_descriptor_proto()
                 )
@@ -2543,19 +4322,23 @@ impl ::protobuf::MessageStatic for UnknownCommand {
     }
 }
 
-impl ::protobuf::Clear for UnknownCommand {
+impl ::protobuf::Clear for TraceDumped {
     fn clear(&mut self) {
+        self.clear_path();
+        self.clear_error();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for UnknownCommand {
-    fn eq(&self, other: &UnknownCommand) -> bool {
+impl ::std::cmp::PartialEq for TraceDumped {
+    fn eq(&self, other: &TraceDumped) -> bool {
+        self.path == other.path &&
+        self.error == other.error &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for UnknownCommand {
+impl ::std::fmt::Debug for TraceDumped {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
@@ -2581,6 +4364,9 @@ pub enum ServerResponse_oneof_response {
     compile_finished(CompileFinished),
     unhandled_compile(UnhandledCompile),
     unknown(UnknownCommand),
+    hello(Hello),
+    trace_dumped(TraceDumped),
+    compile_queued(CompileQueued),
 }
 
 impl ServerResponse {
@@ -2903,6 +4689,156 @@ impl ServerResponse {
             _ => UnknownCommand::default_instance(),
         }
     }
+
+    // optional .sccache.Hello hello = 7;
+
+    pub fn clear_hello(&mut self) {
+        self.response = ::std::option::Option::None;
+    }
+
+    pub fn has_hello(&self) -> bool {
+        match self.response {
+            ::std::option::Option::Some(ServerResponse_oneof_response::hello(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_hello(&mut self, v: Hello) {
+        self.response = ::std::option::Option::Some(ServerResponse_oneof_response::hello(v))
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_hello<'a>(&'a mut self) -> &'a mut Hello {
+        if let ::std::option::Option::Some(ServerResponse_oneof_response::hello(_)) = self.response {
+        } else {
+            self.response = ::std::option::Option::Some(ServerResponse_oneof_response::hello(Hello::new()));
+        }
+        match self.response {
+            ::std::option::Option::Some(ServerResponse_oneof_response::hello(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_hello(&mut self) -> Hello {
+        if self.has_hello() {
+            match self.response.take() {
+                ::std::option::Option::Some(ServerResponse_oneof_response::hello(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Hello::new()
+        }
+    }
+
+    pub fn get_hello<'a>(&'a self) -> &'a Hello {
+        match self.response {
+            ::std::option::Option::Some(ServerResponse_oneof_response::hello(ref v)) => v,
+            _ => Hello::default_instance(),
+        }
+    }
+
+    // optional .sccache.TraceDumped trace_dumped = 8;
+
+    pub fn clear_trace_dumped(&mut self) {
+        self.response = ::std::option::Option::None;
+    }
+
+    pub fn has_trace_dumped(&self) -> bool {
+        match self.response {
+            ::std::option::Option::Some(ServerResponse_oneof_response::trace_dumped(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_trace_dumped(&mut self, v: TraceDumped) {
+        self.response = ::std::option::Option::Some(ServerResponse_oneof_response::trace_dumped(v))
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_trace_dumped<'a>(&'a mut self) -> &'a mut TraceDumped {
+        if let ::std::option::Option::Some(ServerResponse_oneof_response::trace_dumped(_)) = self.response {
+        } else {
+            self.response = ::std::option::Option::Some(ServerResponse_oneof_response::trace_dumped(TraceDumped::new()));
+        }
+        match self.response {
+            ::std::option::Option::Some(ServerResponse_oneof_response::trace_dumped(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_trace_dumped(&mut self) -> TraceDumped {
+        if self.has_trace_dumped() {
+            match self.response.take() {
+                ::std::option::Option::Some(ServerResponse_oneof_response::trace_dumped(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            TraceDumped::new()
+        }
+    }
+
+    pub fn get_trace_dumped<'a>(&'a self) -> &'a TraceDumped {
+        match self.response {
+            ::std::option::Option::Some(ServerResponse_oneof_response::trace_dumped(ref v)) => v,
+            _ => TraceDumped::default_instance(),
+        }
+    }
+
+    // optional .sccache.CompileQueued compile_queued = 9;
+
+    pub fn clear_compile_queued(&mut self) {
+        self.response = ::std::option::Option::None;
+    }
+
+    pub fn has_compile_queued(&self) -> bool {
+        match self.response {
+            ::std::option::Option::Some(ServerResponse_oneof_response::compile_queued(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_compile_queued(&mut self, v: CompileQueued) {
+        self.response = ::std::option::Option::Some(ServerResponse_oneof_response::compile_queued(v))
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_compile_queued<'a>(&'a mut self) -> &'a mut CompileQueued {
+        if let ::std::option::Option::Some(ServerResponse_oneof_response::compile_queued(_)) = self.response {
+        } else {
+            self.response = ::std::option::Option::Some(ServerResponse_oneof_response::compile_queued(CompileQueued::new()));
+        }
+        match self.response {
+            ::std::option::Option::Some(ServerResponse_oneof_response::compile_queued(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_compile_queued(&mut self) -> CompileQueued {
+        if self.has_compile_queued() {
+            match self.response.take() {
+                ::std::option::Option::Some(ServerResponse_oneof_response::compile_queued(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            CompileQueued::new()
+        }
+    }
+
+    pub fn get_compile_queued<'a>(&'a self) -> &'a CompileQueued {
+        match self.response {
+            ::std::option::Option::Some(ServerResponse_oneof_response::compile_queued(ref v)) => v,
+            _ => CompileQueued::default_instance(),
+        }
+    }
 }
 
 impl ::protobuf::Message for ServerResponse {
@@ -2950,6 +4886,24 @@ impl ::protobuf::Message for ServerResponse {
                     };
                     self.response = ::std::option::Option::Some(ServerResponse_oneof_response::unknown(try!(is.read_message())));
                 },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    self.response = ::std::option::Option::Some(ServerResponse_oneof_response::hello(try!(is.read_message())));
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    self.response = ::std::option::Option::Some(ServerResponse_oneof_response::trace_dumped(try!(is.read_message())));
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    self.response = ::std::option::Option::Some(ServerResponse_oneof_response::compile_queued(try!(is.read_message())));
+                },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
                 },
@@ -2988,6 +4942,18 @@ impl ::protobuf::Message for ServerResponse {
                     let len = v.compute_size();
                     my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
                 },
+                &ServerResponse_oneof_response::hello(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ServerResponse_oneof_response::trace_dumped(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ServerResponse_oneof_response::compile_queued(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
             };
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
@@ -3028,6 +4994,21 @@ impl ::protobuf::Message for ServerResponse {
                     try!(os.write_raw_varint32(v.get_cached_size()));
                     try!(v.write_to_with_cached_sizes(os));
                 },
+                &ServerResponse_oneof_response::hello(ref v) => {
+                    try!(os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited));
+                    try!(os.write_raw_varint32(v.get_cached_size()));
+                    try!(v.write_to_with_cached_sizes(os));
+                },
+                &ServerResponse_oneof_response::trace_dumped(ref v) => {
+                    try!(os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited));
+                    try!(os.write_raw_varint32(v.get_cached_size()));
+                    try!(v.write_to_with_cached_sizes(os));
+                },
+                &ServerResponse_oneof_response::compile_queued(ref v) => {
+                    try!(os.write_tag(9, ::protobuf::wire_format::WireTypeLengthDelimited));
+                    try!(os.write_raw_varint32(v.get_cached_size()));
+                    try!(v.write_to_with_cached_sizes(os));
+                },
             };
         };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
@@ -3102,6 +5083,21 @@ impl ::protobuf::MessageStatic for ServerResponse {
                     ServerResponse::has_unknown,
                     ServerResponse::get_unknown,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
+                    "hello",
+                    ServerResponse::has_hello,
+                    ServerResponse::get_hello,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
+                    "trace_dumped",
+                    ServerResponse::has_trace_dumped,
+                    ServerResponse::get_trace_dumped,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
+                    "compile_queued",
+                    ServerResponse::has_compile_queued,
+                    ServerResponse::get_compile_queued,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<ServerResponse>(
                     "ServerResponse",
                     fields,
@@ -3120,6 +5116,9 @@ impl ::protobuf::Clear for ServerResponse {
         self.clear_compile_finished();
         self.clear_unhandled_compile();
         self.clear_unknown();
+        self.clear_hello();
+        self.clear_trace_dumped();
+        self.clear_compile_queued();
         self.unknown_fields.clear();
     }
 }
@@ -3141,224 +5140,450 @@ static file_descriptor_proto_data: &'static [u8] = &[
     0x0a, 0x0e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f, 0x6c, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
     0x12, 0x07, 0x73, 0x63, 0x63, 0x61, 0x63, 0x68, 0x65, 0x22, 0x0a, 0x0a, 0x08, 0x47, 0x65, 0x74,
     0x53, 0x74, 0x61, 0x74, 0x73, 0x22, 0x0a, 0x0a, 0x08, 0x53, 0x68, 0x75, 0x74, 0x64, 0x6f, 0x77,
-    0x6e, 0x22, 0x0b, 0x0a, 0x09, 0x5a, 0x65, 0x72, 0x6f, 0x53, 0x74, 0x61, 0x74, 0x73, 0x22, 0x34,
-    0x0a, 0x07, 0x43, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65, 0x12, 0x0b, 0x0a, 0x03, 0x63, 0x77, 0x64,
-    0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0b, 0x0a, 0x03, 0x65, 0x78, 0x65, 0x18, 0x02, 0x20,
-    0x02, 0x28, 0x09, 0x12, 0x0f, 0x0a, 0x07, 0x63, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x18, 0x03,
-    0x20, 0x03, 0x28, 0x09, 0x22, 0xb8, 0x01, 0x0a, 0x0d, 0x43, 0x6c, 0x69, 0x65, 0x6e, 0x74, 0x52,
-    0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x23, 0x0a, 0x07, 0x63, 0x6f, 0x6d, 0x70, 0x69, 0x6c,
-    0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x10, 0x2e, 0x73, 0x63, 0x63, 0x61, 0x63, 0x68,
-    0x65, 0x2e, 0x43, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65, 0x48, 0x00, 0x12, 0x26, 0x0a, 0x09, 0x67,
-    0x65, 0x74, 0x5f, 0x73, 0x74, 0x61, 0x74, 0x73, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x11,
-    0x2e, 0x73, 0x63, 0x63, 0x61, 0x63, 0x68, 0x65, 0x2e, 0x47, 0x65, 0x74, 0x53, 0x74, 0x61, 0x74,
-    0x73, 0x48, 0x00, 0x12, 0x25, 0x0a, 0x08, 0x73, 0x68, 0x75, 0x74, 0x64, 0x6f, 0x77, 0x6e, 0x18,
-    0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x11, 0x2e, 0x73, 0x63, 0x63, 0x61, 0x63, 0x68, 0x65, 0x2e,
-    0x53, 0x68, 0x75, 0x74, 0x64, 0x6f, 0x77, 0x6e, 0x48, 0x00, 0x12, 0x28, 0x0a, 0x0a, 0x7a, 0x65,
-    0x72, 0x6f, 0x5f, 0x73, 0x74, 0x61, 0x74, 0x73, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x12,
-    0x2e, 0x73, 0x63, 0x63, 0x61, 0x63, 0x68, 0x65, 0x2e, 0x5a, 0x65, 0x72, 0x6f, 0x53, 0x74, 0x61,
-    0x74, 0x73, 0x48, 0x00, 0x42, 0x09, 0x0a, 0x07, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x22,
-    0x57, 0x0a, 0x0e, 0x43, 0x61, 0x63, 0x68, 0x65, 0x53, 0x74, 0x61, 0x74, 0x69, 0x73, 0x74, 0x69,
-    0x63, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x12,
-    0x0f, 0x0a, 0x05, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x48, 0x00,
-    0x12, 0x0d, 0x0a, 0x03, 0x73, 0x74, 0x72, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x48, 0x00, 0x12,
-    0x0e, 0x0a, 0x04, 0x73, 0x69, 0x7a, 0x65, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04, 0x48, 0x00, 0x42,
-    0x07, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x22, 0x34, 0x0a, 0x0a, 0x43, 0x61, 0x63, 0x68,
-    0x65, 0x53, 0x74, 0x61, 0x74, 0x73, 0x12, 0x26, 0x0a, 0x05, 0x73, 0x74, 0x61, 0x74, 0x73, 0x18,
-    0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x17, 0x2e, 0x73, 0x63, 0x63, 0x61, 0x63, 0x68, 0x65, 0x2e,
-    0x43, 0x61, 0x63, 0x68, 0x65, 0x53, 0x74, 0x61, 0x74, 0x69, 0x73, 0x74, 0x69, 0x63, 0x22, 0x32,
-    0x0a, 0x0c, 0x53, 0x68, 0x75, 0x74, 0x74, 0x69, 0x6e, 0x67, 0x44, 0x6f, 0x77, 0x6e, 0x12, 0x22,
-    0x0a, 0x05, 0x73, 0x74, 0x61, 0x74, 0x73, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0b, 0x32, 0x13, 0x2e,
-    0x73, 0x63, 0x63, 0x61, 0x63, 0x68, 0x65, 0x2e, 0x43, 0x61, 0x63, 0x68, 0x65, 0x53, 0x74, 0x61,
-    0x74, 0x73, 0x22, 0x10, 0x0a, 0x0e, 0x43, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65, 0x53, 0x74, 0x61,
-    0x72, 0x74, 0x65, 0x64, 0x22, 0x65, 0x0a, 0x0f, 0x43, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65, 0x46,
-    0x69, 0x6e, 0x69, 0x73, 0x68, 0x65, 0x64, 0x12, 0x11, 0x0a, 0x07, 0x72, 0x65, 0x74, 0x63, 0x6f,
-    0x64, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x05, 0x48, 0x00, 0x12, 0x10, 0x0a, 0x06, 0x73, 0x69,
-    0x67, 0x6e, 0x61, 0x6c, 0x18, 0x02, 0x20, 0x01, 0x28, 0x05, 0x48, 0x00, 0x12, 0x0e, 0x0a, 0x06,
-    0x73, 0x74, 0x64, 0x6f, 0x75, 0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x12, 0x0e, 0x0a, 0x06,
-    0x73, 0x74, 0x64, 0x65, 0x72, 0x72, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0c, 0x42, 0x0d, 0x0a, 0x0b,
-    0x65, 0x78, 0x69, 0x74, 0x5f, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x22, 0x12, 0x0a, 0x10, 0x55,
-    0x6e, 0x68, 0x61, 0x6e, 0x64, 0x6c, 0x65, 0x64, 0x43, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65, 0x22,
-    0x10, 0x0a, 0x0e, 0x55, 0x6e, 0x6b, 0x6e, 0x6f, 0x77, 0x6e, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e,
-    0x64, 0x22, 0xc0, 0x02, 0x0a, 0x0e, 0x53, 0x65, 0x72, 0x76, 0x65, 0x72, 0x52, 0x65, 0x73, 0x70,
-    0x6f, 0x6e, 0x73, 0x65, 0x12, 0x24, 0x0a, 0x05, 0x73, 0x74, 0x61, 0x74, 0x73, 0x18, 0x01, 0x20,
-    0x01, 0x28, 0x0b, 0x32, 0x13, 0x2e, 0x73, 0x63, 0x63, 0x61, 0x63, 0x68, 0x65, 0x2e, 0x43, 0x61,
-    0x63, 0x68, 0x65, 0x53, 0x74, 0x61, 0x74, 0x73, 0x48, 0x00, 0x12, 0x2e, 0x0a, 0x0d, 0x73, 0x68,
-    0x75, 0x74, 0x74, 0x69, 0x6e, 0x67, 0x5f, 0x64, 0x6f, 0x77, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28,
-    0x0b, 0x32, 0x15, 0x2e, 0x73, 0x63, 0x63, 0x61, 0x63, 0x68, 0x65, 0x2e, 0x53, 0x68, 0x75, 0x74,
-    0x74, 0x69, 0x6e, 0x67, 0x44, 0x6f, 0x77, 0x6e, 0x48, 0x00, 0x12, 0x32, 0x0a, 0x0f, 0x63, 0x6f,
-    0x6d, 0x70, 0x69, 0x6c, 0x65, 0x5f, 0x73, 0x74, 0x61, 0x72, 0x74, 0x65, 0x64, 0x18, 0x03, 0x20,
-    0x01, 0x28, 0x0b, 0x32, 0x17, 0x2e, 0x73, 0x63, 0x63, 0x61, 0x63, 0x68, 0x65, 0x2e, 0x43, 0x6f,
-    0x6d, 0x70, 0x69, 0x6c, 0x65, 0x53, 0x74, 0x61, 0x72, 0x74, 0x65, 0x64, 0x48, 0x00, 0x12, 0x34,
-    0x0a, 0x10, 0x63, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65, 0x5f, 0x66, 0x69, 0x6e, 0x69, 0x73, 0x68,
-    0x65, 0x64, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x18, 0x2e, 0x73, 0x63, 0x63, 0x61, 0x63,
-    0x68, 0x65, 0x2e, 0x43, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65, 0x46, 0x69, 0x6e, 0x69, 0x73, 0x68,
-    0x65, 0x64, 0x48, 0x00, 0x12, 0x36, 0x0a, 0x11, 0x75, 0x6e, 0x68, 0x61, 0x6e, 0x64, 0x6c, 0x65,
-    0x64, 0x5f, 0x63, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32,
-    0x19, 0x2e, 0x73, 0x63, 0x63, 0x61, 0x63, 0x68, 0x65, 0x2e, 0x55, 0x6e, 0x68, 0x61, 0x6e, 0x64,
-    0x6c, 0x65, 0x64, 0x43, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65, 0x48, 0x00, 0x12, 0x2a, 0x0a, 0x07,
-    0x75, 0x6e, 0x6b, 0x6e, 0x6f, 0x77, 0x6e, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x17, 0x2e,
-    0x73, 0x63, 0x63, 0x61, 0x63, 0x68, 0x65, 0x2e, 0x55, 0x6e, 0x6b, 0x6e, 0x6f, 0x77, 0x6e, 0x43,
-    0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x48, 0x00, 0x42, 0x0a, 0x0a, 0x08, 0x72, 0x65, 0x73, 0x70,
-    0x6f, 0x6e, 0x73, 0x65, 0x4a, 0xed, 0x13, 0x0a, 0x06, 0x12, 0x04, 0x0e, 0x00, 0x64, 0x01, 0x0a,
-    0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x0e, 0x08, 0x0f, 0x0a, 0x22, 0x0a, 0x02, 0x04, 0x00, 0x12,
-    0x03, 0x11, 0x00, 0x13, 0x1a, 0x17, 0x20, 0x47, 0x65, 0x74, 0x20, 0x63, 0x61, 0x63, 0x68, 0x65,
-    0x20, 0x73, 0x74, 0x61, 0x74, 0x69, 0x73, 0x74, 0x69, 0x63, 0x73, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a,
-    0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x11, 0x08, 0x10, 0x0a, 0x22, 0x0a, 0x02, 0x04, 0x01, 0x12,
-    0x03, 0x14, 0x00, 0x13, 0x1a, 0x17, 0x20, 0x53, 0x68, 0x75, 0x74, 0x20, 0x64, 0x6f, 0x77, 0x6e,
-    0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a,
-    0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x14, 0x08, 0x10, 0x0a, 0x23, 0x0a, 0x02, 0x04, 0x02, 0x12,
-    0x03, 0x17, 0x00, 0x14, 0x1a, 0x18, 0x20, 0x5a, 0x65, 0x72, 0x6f, 0x20, 0x63, 0x61, 0x63, 0x68,
-    0x65, 0x20, 0x73, 0x74, 0x61, 0x74, 0x69, 0x73, 0x74, 0x69, 0x63, 0x73, 0x2e, 0x0a, 0x0a, 0x0a,
-    0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x17, 0x08, 0x11, 0x0a, 0x24, 0x0a, 0x02, 0x04, 0x03,
-    0x12, 0x04, 0x1a, 0x00, 0x21, 0x01, 0x1a, 0x18, 0x20, 0x52, 0x75, 0x6e, 0x20, 0x61, 0x20, 0x63,
-    0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65, 0x20, 0x63, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x2e, 0x0a,
-    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x1a, 0x08, 0x0f, 0x0a, 0x39, 0x0a, 0x04,
-    0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x1c, 0x02, 0x1a, 0x1a, 0x2c, 0x20, 0x54, 0x68, 0x65, 0x20,
-    0x64, 0x69, 0x72, 0x65, 0x63, 0x74, 0x6f, 0x72, 0x79, 0x20, 0x69, 0x6e, 0x20, 0x77, 0x68, 0x69,
-    0x63, 0x68, 0x20, 0x74, 0x6f, 0x20, 0x72, 0x75, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f,
-    0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x04,
-    0x12, 0x03, 0x1c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x05, 0x12, 0x03,
-    0x1c, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x1c, 0x12,
-    0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x1c, 0x18, 0x19, 0x0a,
-    0x25, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x01, 0x12, 0x03, 0x1e, 0x02, 0x1a, 0x1a, 0x18, 0x20, 0x54,
-    0x68, 0x65, 0x20, 0x65, 0x78, 0x65, 0x63, 0x75, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x20, 0x74, 0x6f,
-    0x20, 0x72, 0x75, 0x6e, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x04, 0x12,
-    0x03, 0x1e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x05, 0x12, 0x03, 0x1e,
-    0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x01, 0x12, 0x03, 0x1e, 0x12, 0x15,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x03, 0x12, 0x03, 0x1e, 0x18, 0x19, 0x0a, 0x2a,
-    0x0a, 0x04, 0x04, 0x03, 0x02, 0x02, 0x12, 0x03, 0x20, 0x02, 0x1e, 0x1a, 0x1d, 0x20, 0x54, 0x68,
-    0x65, 0x20, 0x63, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x20, 0x6c, 0x69, 0x6e, 0x65, 0x20, 0x61,
-    0x72, 0x67, 0x75, 0x6d, 0x65, 0x6e, 0x74, 0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03,
-    0x02, 0x02, 0x04, 0x12, 0x03, 0x20, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02,
-    0x05, 0x12, 0x03, 0x20, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x01, 0x12,
-    0x03, 0x20, 0x12, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x03, 0x12, 0x03, 0x20,
-    0x1c, 0x1d, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x04, 0x12, 0x04, 0x23, 0x00, 0x2b, 0x01, 0x0a, 0x0a,
-    0x0a, 0x03, 0x04, 0x04, 0x01, 0x12, 0x03, 0x23, 0x08, 0x15, 0x0a, 0x41, 0x0a, 0x04, 0x04, 0x04,
-    0x08, 0x00, 0x12, 0x04, 0x25, 0x02, 0x2a, 0x03, 0x1a, 0x33, 0x20, 0x41, 0x20, 0x63, 0x6c, 0x69,
-    0x65, 0x6e, 0x74, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x20, 0x63, 0x61, 0x6e, 0x20,
-    0x62, 0x65, 0x20, 0x61, 0x6e, 0x79, 0x20, 0x6f, 0x6e, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68,
-    0x65, 0x20, 0x66, 0x6f, 0x6c, 0x6c, 0x6f, 0x77, 0x69, 0x6e, 0x67, 0x3a, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x04, 0x08, 0x00, 0x01, 0x12, 0x03, 0x25, 0x08, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
-    0x04, 0x02, 0x00, 0x12, 0x03, 0x26, 0x04, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00,
-    0x06, 0x12, 0x03, 0x26, 0x04, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x01, 0x12,
-    0x03, 0x26, 0x0c, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x03, 0x12, 0x03, 0x26,
-    0x16, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x01, 0x12, 0x03, 0x27, 0x04, 0x1b, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x06, 0x12, 0x03, 0x27, 0x04, 0x0c, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x04, 0x02, 0x01, 0x01, 0x12, 0x03, 0x27, 0x0d, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x04, 0x02, 0x01, 0x03, 0x12, 0x03, 0x27, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02,
-    0x02, 0x12, 0x03, 0x28, 0x04, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x02, 0x06, 0x12,
-    0x03, 0x28, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12, 0x03, 0x28,
-    0x0d, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x02, 0x03, 0x12, 0x03, 0x28, 0x18, 0x19,
-    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x03, 0x12, 0x03, 0x29, 0x04, 0x1d, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x04, 0x02, 0x03, 0x06, 0x12, 0x03, 0x29, 0x04, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x04, 0x02, 0x03, 0x01, 0x12, 0x03, 0x29, 0x0e, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02,
-    0x03, 0x03, 0x12, 0x03, 0x29, 0x1b, 0x1c, 0x0a, 0x27, 0x0a, 0x02, 0x04, 0x05, 0x12, 0x04, 0x2e,
-    0x00, 0x38, 0x01, 0x1a, 0x1b, 0x20, 0x41, 0x20, 0x73, 0x69, 0x6e, 0x67, 0x6c, 0x65, 0x20, 0x63,
-    0x61, 0x63, 0x68, 0x65, 0x20, 0x73, 0x74, 0x61, 0x74, 0x69, 0x73, 0x74, 0x69, 0x63, 0x2e, 0x0a,
-    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12, 0x03, 0x2e, 0x08, 0x16, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x05, 0x02, 0x00, 0x12, 0x03, 0x2f, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02,
-    0x00, 0x04, 0x12, 0x03, 0x2f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x05,
-    0x12, 0x03, 0x2f, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x01, 0x12, 0x03,
-    0x2f, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x03, 0x12, 0x03, 0x2f, 0x19,
-    0x1a, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x05, 0x08, 0x00, 0x12, 0x04, 0x30, 0x02, 0x37, 0x03, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x05, 0x08, 0x00, 0x01, 0x12, 0x03, 0x30, 0x08, 0x0d, 0x0a, 0x20, 0x0a,
-    0x04, 0x04, 0x05, 0x02, 0x01, 0x12, 0x03, 0x32, 0x04, 0x15, 0x1a, 0x13, 0x20, 0x41, 0x20, 0x73,
-    0x69, 0x6d, 0x70, 0x6c, 0x65, 0x20, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x65, 0x72, 0x2e, 0x0a, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x05, 0x12, 0x03, 0x32, 0x04, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x05, 0x02, 0x01, 0x01, 0x12, 0x03, 0x32, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x05, 0x02, 0x01, 0x03, 0x12, 0x03, 0x32, 0x13, 0x14, 0x0a, 0x1e, 0x0a, 0x04, 0x04, 0x05, 0x02,
-    0x02, 0x12, 0x03, 0x34, 0x04, 0x13, 0x1a, 0x11, 0x20, 0x41, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e,
-    0x67, 0x20, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02,
-    0x02, 0x05, 0x12, 0x03, 0x34, 0x04, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x01,
-    0x12, 0x03, 0x34, 0x0b, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x03, 0x12, 0x03,
-    0x34, 0x11, 0x12, 0x0a, 0x20, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x03, 0x12, 0x03, 0x36, 0x04, 0x14,
-    0x1a, 0x13, 0x20, 0x41, 0x20, 0x73, 0x69, 0x7a, 0x65, 0x2c, 0x20, 0x69, 0x6e, 0x20, 0x62, 0x79,
-    0x74, 0x65, 0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03, 0x05, 0x12, 0x03,
-    0x36, 0x04, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03, 0x01, 0x12, 0x03, 0x36, 0x0b,
-    0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03, 0x03, 0x12, 0x03, 0x36, 0x12, 0x13, 0x0a,
-    0x29, 0x0a, 0x02, 0x04, 0x06, 0x12, 0x04, 0x3b, 0x00, 0x3d, 0x01, 0x1a, 0x1d, 0x20, 0x41, 0x20,
-    0x6c, 0x69, 0x73, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x63, 0x61, 0x63, 0x68, 0x65, 0x20, 0x73, 0x74,
-    0x61, 0x74, 0x69, 0x73, 0x74, 0x69, 0x63, 0x73, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x06,
-    0x01, 0x12, 0x03, 0x3b, 0x08, 0x12, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x00, 0x12, 0x03,
-    0x3c, 0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x04, 0x12, 0x03, 0x3c, 0x02,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x06, 0x12, 0x03, 0x3c, 0x0b, 0x19, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x01, 0x12, 0x03, 0x3c, 0x1a, 0x1f, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x06, 0x02, 0x00, 0x03, 0x12, 0x03, 0x3c, 0x22, 0x23, 0x0a, 0x2a, 0x0a, 0x02, 0x04,
-    0x07, 0x12, 0x04, 0x40, 0x00, 0x42, 0x01, 0x1a, 0x1e, 0x20, 0x54, 0x68, 0x65, 0x20, 0x73, 0x65,
-    0x72, 0x76, 0x65, 0x72, 0x20, 0x69, 0x73, 0x20, 0x73, 0x68, 0x75, 0x74, 0x74, 0x69, 0x6e, 0x67,
-    0x20, 0x64, 0x6f, 0x77, 0x6e, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x07, 0x01, 0x12, 0x03,
-    0x40, 0x08, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x00, 0x12, 0x03, 0x41, 0x02, 0x20,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x04, 0x12, 0x03, 0x41, 0x02, 0x0a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x06, 0x12, 0x03, 0x41, 0x0b, 0x15, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x07, 0x02, 0x00, 0x01, 0x12, 0x03, 0x41, 0x16, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07,
-    0x02, 0x00, 0x03, 0x12, 0x03, 0x41, 0x1e, 0x1f, 0x0a, 0x38, 0x0a, 0x02, 0x04, 0x08, 0x12, 0x03,
-    0x45, 0x00, 0x19, 0x1a, 0x2d, 0x20, 0x54, 0x68, 0x65, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72,
-    0x20, 0x73, 0x74, 0x61, 0x72, 0x74, 0x65, 0x64, 0x20, 0x65, 0x78, 0x65, 0x63, 0x75, 0x74, 0x69,
-    0x6e, 0x67, 0x20, 0x61, 0x20, 0x63, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x61, 0x74, 0x69, 0x6f, 0x6e,
-    0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x08, 0x01, 0x12, 0x03, 0x45, 0x08, 0x16, 0x0a, 0x2f,
-    0x0a, 0x02, 0x04, 0x09, 0x12, 0x04, 0x48, 0x00, 0x52, 0x01, 0x1a, 0x23, 0x20, 0x54, 0x68, 0x65,
-    0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72, 0x20, 0x72, 0x61, 0x6e, 0x20, 0x61, 0x20, 0x63, 0x6f,
-    0x6d, 0x70, 0x69, 0x6c, 0x65, 0x20, 0x63, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x2e, 0x0a, 0x0a,
-    0x0a, 0x0a, 0x03, 0x04, 0x09, 0x01, 0x12, 0x03, 0x48, 0x08, 0x17, 0x0a, 0x2f, 0x0a, 0x04, 0x04,
-    0x09, 0x08, 0x00, 0x12, 0x04, 0x4a, 0x02, 0x4f, 0x03, 0x1a, 0x21, 0x20, 0x54, 0x68, 0x65, 0x20,
-    0x72, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x20, 0x63, 0x6f, 0x64, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74,
-    0x68, 0x65, 0x20, 0x70, 0x72, 0x6f, 0x63, 0x65, 0x73, 0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x09, 0x08, 0x00, 0x01, 0x12, 0x03, 0x4a, 0x08, 0x13, 0x0a, 0x1b, 0x0a, 0x04, 0x04, 0x09,
-    0x02, 0x00, 0x12, 0x03, 0x4c, 0x04, 0x16, 0x1a, 0x0e, 0x20, 0x4e, 0x6f, 0x72, 0x6d, 0x61, 0x6c,
-    0x20, 0x65, 0x78, 0x69, 0x74, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x05,
-    0x12, 0x03, 0x4c, 0x04, 0x09, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x01, 0x12, 0x03,
-    0x4c, 0x0a, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x03, 0x12, 0x03, 0x4c, 0x14,
-    0x15, 0x0a, 0x26, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x01, 0x12, 0x03, 0x4e, 0x04, 0x15, 0x1a, 0x19,
-    0x20, 0x54, 0x65, 0x72, 0x6d, 0x69, 0x6e, 0x61, 0x74, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x61,
-    0x20, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x6c, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02,
-    0x01, 0x05, 0x12, 0x03, 0x4e, 0x04, 0x09, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x01,
-    0x12, 0x03, 0x4e, 0x0a, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x03, 0x12, 0x03,
-    0x4e, 0x13, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x02, 0x12, 0x03, 0x50, 0x02, 0x1c,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x04, 0x12, 0x03, 0x50, 0x02, 0x0a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x05, 0x12, 0x03, 0x50, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x09, 0x02, 0x02, 0x01, 0x12, 0x03, 0x50, 0x11, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09,
-    0x02, 0x02, 0x03, 0x12, 0x03, 0x50, 0x1a, 0x1b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x03,
-    0x12, 0x03, 0x51, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x03, 0x04, 0x12, 0x03,
-    0x51, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x03, 0x05, 0x12, 0x03, 0x51, 0x0b,
-    0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x03, 0x01, 0x12, 0x03, 0x51, 0x11, 0x17, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x03, 0x03, 0x12, 0x03, 0x51, 0x1a, 0x1b, 0x0a, 0x43, 0x0a,
-    0x02, 0x04, 0x0a, 0x12, 0x03, 0x55, 0x00, 0x1b, 0x1a, 0x38, 0x20, 0x54, 0x68, 0x65, 0x20, 0x73,
-    0x65, 0x72, 0x76, 0x65, 0x72, 0x20, 0x63, 0x6f, 0x75, 0x6c, 0x64, 0x20, 0x6e, 0x6f, 0x74, 0x20,
-    0x68, 0x61, 0x6e, 0x64, 0x6c, 0x65, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x63, 0x6f, 0x6d, 0x70,
-    0x69, 0x6c, 0x65, 0x20, 0x63, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x20, 0x6c, 0x69, 0x6e, 0x65,
-    0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0a, 0x01, 0x12, 0x03, 0x55, 0x08, 0x18, 0x0a, 0x34,
-    0x0a, 0x02, 0x04, 0x0b, 0x12, 0x03, 0x58, 0x00, 0x19, 0x1a, 0x29, 0x20, 0x54, 0x68, 0x69, 0x73,
-    0x20, 0x63, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x20, 0x77, 0x61, 0x73, 0x20, 0x75, 0x6e, 0x6b,
-    0x6e, 0x6f, 0x77, 0x6e, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x65, 0x72, 0x76,
-    0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0b, 0x01, 0x12, 0x03, 0x58, 0x08, 0x16,
-    0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0c, 0x12, 0x04, 0x5a, 0x00, 0x64, 0x01, 0x0a, 0x0a, 0x0a, 0x03,
-    0x04, 0x0c, 0x01, 0x12, 0x03, 0x5a, 0x08, 0x16, 0x0a, 0x42, 0x0a, 0x04, 0x04, 0x0c, 0x08, 0x00,
-    0x12, 0x04, 0x5c, 0x02, 0x63, 0x03, 0x1a, 0x34, 0x20, 0x41, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65,
-    0x72, 0x20, 0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x20, 0x63, 0x61, 0x6e, 0x20, 0x62,
-    0x65, 0x20, 0x61, 0x6e, 0x79, 0x20, 0x6f, 0x6e, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65,
-    0x20, 0x66, 0x6f, 0x6c, 0x6c, 0x6f, 0x77, 0x69, 0x6e, 0x67, 0x3a, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x0c, 0x08, 0x00, 0x01, 0x12, 0x03, 0x5c, 0x08, 0x10, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0c,
-    0x02, 0x00, 0x12, 0x03, 0x5d, 0x04, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x06,
-    0x12, 0x03, 0x5d, 0x04, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x01, 0x12, 0x03,
-    0x5d, 0x0f, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x03, 0x12, 0x03, 0x5d, 0x17,
-    0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0c, 0x02, 0x01, 0x12, 0x03, 0x5e, 0x04, 0x23, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x06, 0x12, 0x03, 0x5e, 0x04, 0x10, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x0c, 0x02, 0x01, 0x01, 0x12, 0x03, 0x5e, 0x11, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c,
-    0x02, 0x01, 0x03, 0x12, 0x03, 0x5e, 0x21, 0x22, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0c, 0x02, 0x02,
-    0x12, 0x03, 0x5f, 0x04, 0x27, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x02, 0x06, 0x12, 0x03,
-    0x5f, 0x04, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x02, 0x01, 0x12, 0x03, 0x5f, 0x13,
-    0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x02, 0x03, 0x12, 0x03, 0x5f, 0x25, 0x26, 0x0a,
-    0x0b, 0x0a, 0x04, 0x04, 0x0c, 0x02, 0x03, 0x12, 0x03, 0x60, 0x04, 0x29, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x0c, 0x02, 0x03, 0x06, 0x12, 0x03, 0x60, 0x04, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c,
-    0x02, 0x03, 0x01, 0x12, 0x03, 0x60, 0x14, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x03,
-    0x03, 0x12, 0x03, 0x60, 0x27, 0x28, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0c, 0x02, 0x04, 0x12, 0x03,
-    0x61, 0x04, 0x2b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x04, 0x06, 0x12, 0x03, 0x61, 0x04,
-    0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x04, 0x01, 0x12, 0x03, 0x61, 0x15, 0x26, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x04, 0x03, 0x12, 0x03, 0x61, 0x29, 0x2a, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x0c, 0x02, 0x05, 0x12, 0x03, 0x62, 0x04, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c,
-    0x02, 0x05, 0x06, 0x12, 0x03, 0x62, 0x04, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x05,
-    0x01, 0x12, 0x03, 0x62, 0x13, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x05, 0x03, 0x12,
-    0x03, 0x62, 0x1d, 0x1e,
+    0x6e, 0x22, 0x0b, 0x0a, 0x09, 0x5a, 0x65, 0x72, 0x6f, 0x53, 0x74, 0x61, 0x74, 0x73, 0x22, 0x0b,
+    0x0a, 0x09, 0x44, 0x75, 0x6d, 0x70, 0x54, 0x72, 0x61, 0x63, 0x65, 0x22, 0x2f, 0x0a, 0x05, 0x48,
+    0x65, 0x6c, 0x6c, 0x6f, 0x12, 0x0f, 0x0a, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18,
+    0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x15, 0x0a, 0x0d, 0x63, 0x61, 0x63, 0x68, 0x65, 0x5f, 0x76,
+    0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x22, 0x7a, 0x0a, 0x07,
+    0x43, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65, 0x12, 0x0b, 0x0a, 0x03, 0x63, 0x77, 0x64, 0x18, 0x01,
+    0x20, 0x02, 0x28, 0x09, 0x12, 0x0b, 0x0a, 0x03, 0x65, 0x78, 0x65, 0x18, 0x02, 0x20, 0x02, 0x28,
+    0x09, 0x12, 0x0f, 0x0a, 0x07, 0x63, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x18, 0x03, 0x20, 0x03,
+    0x28, 0x09, 0x12, 0x12, 0x0a, 0x0a, 0x63, 0x6c, 0x69, 0x65, 0x6e, 0x74, 0x5f, 0x70, 0x69, 0x64,
+    0x18, 0x04, 0x20, 0x01, 0x28, 0x0d, 0x12, 0x11, 0x0a, 0x09, 0x6d, 0x61, 0x6b, 0x65, 0x66, 0x6c,
+    0x61, 0x67, 0x73, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09, 0x12, 0x1d, 0x0a, 0x05, 0x68, 0x65, 0x6c,
+    0x6c, 0x6f, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x73, 0x63, 0x63, 0x61, 0x63,
+    0x68, 0x65, 0x2e, 0x48, 0x65, 0x6c, 0x6c, 0x6f, 0x22, 0x83, 0x02, 0x0a, 0x0d, 0x43, 0x6c, 0x69,
+    0x65, 0x6e, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x23, 0x0a, 0x07, 0x63, 0x6f,
+    0x6d, 0x70, 0x69, 0x6c, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x10, 0x2e, 0x73, 0x63,
+    0x63, 0x61, 0x63, 0x68, 0x65, 0x2e, 0x43, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65, 0x48, 0x00, 0x12,
+    0x26, 0x0a, 0x09, 0x67, 0x65, 0x74, 0x5f, 0x73, 0x74, 0x61, 0x74, 0x73, 0x18, 0x02, 0x20, 0x01,
+    0x28, 0x0b, 0x32, 0x11, 0x2e, 0x73, 0x63, 0x63, 0x61, 0x63, 0x68, 0x65, 0x2e, 0x47, 0x65, 0x74,
+    0x53, 0x74, 0x61, 0x74, 0x73, 0x48, 0x00, 0x12, 0x25, 0x0a, 0x08, 0x73, 0x68, 0x75, 0x74, 0x64,
+    0x6f, 0x77, 0x6e, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x11, 0x2e, 0x73, 0x63, 0x63, 0x61,
+    0x63, 0x68, 0x65, 0x2e, 0x53, 0x68, 0x75, 0x74, 0x64, 0x6f, 0x77, 0x6e, 0x48, 0x00, 0x12, 0x28,
+    0x0a, 0x0a, 0x7a, 0x65, 0x72, 0x6f, 0x5f, 0x73, 0x74, 0x61, 0x74, 0x73, 0x18, 0x04, 0x20, 0x01,
+    0x28, 0x0b, 0x32, 0x12, 0x2e, 0x73, 0x63, 0x63, 0x61, 0x63, 0x68, 0x65, 0x2e, 0x5a, 0x65, 0x72,
+    0x6f, 0x53, 0x74, 0x61, 0x74, 0x73, 0x48, 0x00, 0x12, 0x1f, 0x0a, 0x05, 0x68, 0x65, 0x6c, 0x6c,
+    0x6f, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x73, 0x63, 0x63, 0x61, 0x63, 0x68,
+    0x65, 0x2e, 0x48, 0x65, 0x6c, 0x6c, 0x6f, 0x48, 0x00, 0x12, 0x28, 0x0a, 0x0a, 0x64, 0x75, 0x6d,
+    0x70, 0x5f, 0x74, 0x72, 0x61, 0x63, 0x65, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x12, 0x2e,
+    0x73, 0x63, 0x63, 0x61, 0x63, 0x68, 0x65, 0x2e, 0x44, 0x75, 0x6d, 0x70, 0x54, 0x72, 0x61, 0x63,
+    0x65, 0x48, 0x00, 0x42, 0x09, 0x0a, 0x07, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x22, 0x41,
+    0x0a, 0x09, 0x48, 0x69, 0x73, 0x74, 0x6f, 0x67, 0x72, 0x61, 0x6d, 0x12, 0x0d, 0x0a, 0x05, 0x63,
+    0x6f, 0x75, 0x6e, 0x74, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0b, 0x0a, 0x03, 0x70, 0x35,
+    0x30, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0b, 0x0a, 0x03, 0x70, 0x39, 0x30, 0x18, 0x03,
+    0x20, 0x02, 0x28, 0x04, 0x12, 0x0b, 0x0a, 0x03, 0x70, 0x39, 0x39, 0x18, 0x04, 0x20, 0x02, 0x28,
+    0x04, 0x22, 0x94, 0x01, 0x0a, 0x0e, 0x43, 0x61, 0x63, 0x68, 0x65, 0x53, 0x74, 0x61, 0x74, 0x69,
+    0x73, 0x74, 0x69, 0x63, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x02,
+    0x28, 0x09, 0x12, 0x0f, 0x0a, 0x05, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28,
+    0x04, 0x48, 0x00, 0x12, 0x0d, 0x0a, 0x03, 0x73, 0x74, 0x72, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09,
+    0x48, 0x00, 0x12, 0x0e, 0x0a, 0x04, 0x73, 0x69, 0x7a, 0x65, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04,
+    0x48, 0x00, 0x12, 0x12, 0x0a, 0x08, 0x64, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x05,
+    0x20, 0x01, 0x28, 0x04, 0x48, 0x00, 0x12, 0x27, 0x0a, 0x09, 0x68, 0x69, 0x73, 0x74, 0x6f, 0x67,
+    0x72, 0x61, 0x6d, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x12, 0x2e, 0x73, 0x63, 0x63, 0x61,
+    0x63, 0x68, 0x65, 0x2e, 0x48, 0x69, 0x73, 0x74, 0x6f, 0x67, 0x72, 0x61, 0x6d, 0x48, 0x00, 0x42,
+    0x07, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x22, 0x5a, 0x0a, 0x13, 0x43, 0x61, 0x63, 0x68,
+    0x65, 0x53, 0x74, 0x61, 0x74, 0x73, 0x42, 0x72, 0x65, 0x61, 0x6b, 0x64, 0x6f, 0x77, 0x6e, 0x12,
+    0x0c, 0x0a, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0d, 0x0a,
+    0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x12, 0x26, 0x0a, 0x05,
+    0x73, 0x74, 0x61, 0x74, 0x73, 0x18, 0x03, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x17, 0x2e, 0x73, 0x63,
+    0x63, 0x61, 0x63, 0x68, 0x65, 0x2e, 0x43, 0x61, 0x63, 0x68, 0x65, 0x53, 0x74, 0x61, 0x74, 0x69,
+    0x73, 0x74, 0x69, 0x63, 0x22, 0x66, 0x0a, 0x0a, 0x43, 0x61, 0x63, 0x68, 0x65, 0x53, 0x74, 0x61,
+    0x74, 0x73, 0x12, 0x26, 0x0a, 0x05, 0x73, 0x74, 0x61, 0x74, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28,
+    0x0b, 0x32, 0x17, 0x2e, 0x73, 0x63, 0x63, 0x61, 0x63, 0x68, 0x65, 0x2e, 0x43, 0x61, 0x63, 0x68,
+    0x65, 0x53, 0x74, 0x61, 0x74, 0x69, 0x73, 0x74, 0x69, 0x63, 0x12, 0x30, 0x0a, 0x0a, 0x62, 0x72,
+    0x65, 0x61, 0x6b, 0x64, 0x6f, 0x77, 0x6e, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1c,
+    0x2e, 0x73, 0x63, 0x63, 0x61, 0x63, 0x68, 0x65, 0x2e, 0x43, 0x61, 0x63, 0x68, 0x65, 0x53, 0x74,
+    0x61, 0x74, 0x73, 0x42, 0x72, 0x65, 0x61, 0x6b, 0x64, 0x6f, 0x77, 0x6e, 0x22, 0x32, 0x0a, 0x0c,
+    0x53, 0x68, 0x75, 0x74, 0x74, 0x69, 0x6e, 0x67, 0x44, 0x6f, 0x77, 0x6e, 0x12, 0x22, 0x0a, 0x05,
+    0x73, 0x74, 0x61, 0x74, 0x73, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0b, 0x32, 0x13, 0x2e, 0x73, 0x63,
+    0x63, 0x61, 0x63, 0x68, 0x65, 0x2e, 0x43, 0x61, 0x63, 0x68, 0x65, 0x53, 0x74, 0x61, 0x74, 0x73,
+    0x22, 0x10, 0x0a, 0x0e, 0x43, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65, 0x53, 0x74, 0x61, 0x72, 0x74,
+    0x65, 0x64, 0x22, 0x0f, 0x0a, 0x0d, 0x43, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65, 0x51, 0x75, 0x65,
+    0x75, 0x65, 0x64, 0x22, 0x65, 0x0a, 0x0f, 0x43, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65, 0x46, 0x69,
+    0x6e, 0x69, 0x73, 0x68, 0x65, 0x64, 0x12, 0x11, 0x0a, 0x07, 0x72, 0x65, 0x74, 0x63, 0x6f, 0x64,
+    0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x05, 0x48, 0x00, 0x12, 0x10, 0x0a, 0x06, 0x73, 0x69, 0x67,
+    0x6e, 0x61, 0x6c, 0x18, 0x02, 0x20, 0x01, 0x28, 0x05, 0x48, 0x00, 0x12, 0x0e, 0x0a, 0x06, 0x73,
+    0x74, 0x64, 0x6f, 0x75, 0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x12, 0x0e, 0x0a, 0x06, 0x73,
+    0x74, 0x64, 0x65, 0x72, 0x72, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0c, 0x42, 0x0d, 0x0a, 0x0b, 0x65,
+    0x78, 0x69, 0x74, 0x5f, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x22, 0x12, 0x0a, 0x10, 0x55, 0x6e,
+    0x68, 0x61, 0x6e, 0x64, 0x6c, 0x65, 0x64, 0x43, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65, 0x22, 0x10,
+    0x0a, 0x0e, 0x55, 0x6e, 0x6b, 0x6e, 0x6f, 0x77, 0x6e, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64,
+    0x22, 0x2a, 0x0a, 0x0b, 0x54, 0x72, 0x61, 0x63, 0x65, 0x44, 0x75, 0x6d, 0x70, 0x65, 0x64, 0x12,
+    0x0c, 0x0a, 0x04, 0x70, 0x61, 0x74, 0x68, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x12, 0x0d, 0x0a,
+    0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x22, 0xc1, 0x03, 0x0a,
+    0x0e, 0x53, 0x65, 0x72, 0x76, 0x65, 0x72, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12,
+    0x24, 0x0a, 0x05, 0x73, 0x74, 0x61, 0x74, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x13,
+    0x2e, 0x73, 0x63, 0x63, 0x61, 0x63, 0x68, 0x65, 0x2e, 0x43, 0x61, 0x63, 0x68, 0x65, 0x53, 0x74,
+    0x61, 0x74, 0x73, 0x48, 0x00, 0x12, 0x2e, 0x0a, 0x0d, 0x73, 0x68, 0x75, 0x74, 0x74, 0x69, 0x6e,
+    0x67, 0x5f, 0x64, 0x6f, 0x77, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x15, 0x2e, 0x73,
+    0x63, 0x63, 0x61, 0x63, 0x68, 0x65, 0x2e, 0x53, 0x68, 0x75, 0x74, 0x74, 0x69, 0x6e, 0x67, 0x44,
+    0x6f, 0x77, 0x6e, 0x48, 0x00, 0x12, 0x32, 0x0a, 0x0f, 0x63, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65,
+    0x5f, 0x73, 0x74, 0x61, 0x72, 0x74, 0x65, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x17,
+    0x2e, 0x73, 0x63, 0x63, 0x61, 0x63, 0x68, 0x65, 0x2e, 0x43, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65,
+    0x53, 0x74, 0x61, 0x72, 0x74, 0x65, 0x64, 0x48, 0x00, 0x12, 0x34, 0x0a, 0x10, 0x63, 0x6f, 0x6d,
+    0x70, 0x69, 0x6c, 0x65, 0x5f, 0x66, 0x69, 0x6e, 0x69, 0x73, 0x68, 0x65, 0x64, 0x18, 0x04, 0x20,
+    0x01, 0x28, 0x0b, 0x32, 0x18, 0x2e, 0x73, 0x63, 0x63, 0x61, 0x63, 0x68, 0x65, 0x2e, 0x43, 0x6f,
+    0x6d, 0x70, 0x69, 0x6c, 0x65, 0x46, 0x69, 0x6e, 0x69, 0x73, 0x68, 0x65, 0x64, 0x48, 0x00, 0x12,
+    0x36, 0x0a, 0x11, 0x75, 0x6e, 0x68, 0x61, 0x6e, 0x64, 0x6c, 0x65, 0x64, 0x5f, 0x63, 0x6f, 0x6d,
+    0x70, 0x69, 0x6c, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x19, 0x2e, 0x73, 0x63, 0x63,
+    0x61, 0x63, 0x68, 0x65, 0x2e, 0x55, 0x6e, 0x68, 0x61, 0x6e, 0x64, 0x6c, 0x65, 0x64, 0x43, 0x6f,
+    0x6d, 0x70, 0x69, 0x6c, 0x65, 0x48, 0x00, 0x12, 0x2a, 0x0a, 0x07, 0x75, 0x6e, 0x6b, 0x6e, 0x6f,
+    0x77, 0x6e, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x17, 0x2e, 0x73, 0x63, 0x63, 0x61, 0x63,
+    0x68, 0x65, 0x2e, 0x55, 0x6e, 0x6b, 0x6e, 0x6f, 0x77, 0x6e, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e,
+    0x64, 0x48, 0x00, 0x12, 0x1f, 0x0a, 0x05, 0x68, 0x65, 0x6c, 0x6c, 0x6f, 0x18, 0x07, 0x20, 0x01,
+    0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x73, 0x63, 0x63, 0x61, 0x63, 0x68, 0x65, 0x2e, 0x48, 0x65, 0x6c,
+    0x6c, 0x6f, 0x48, 0x00, 0x12, 0x2c, 0x0a, 0x0c, 0x74, 0x72, 0x61, 0x63, 0x65, 0x5f, 0x64, 0x75,
+    0x6d, 0x70, 0x65, 0x64, 0x18, 0x08, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x14, 0x2e, 0x73, 0x63, 0x63,
+    0x61, 0x63, 0x68, 0x65, 0x2e, 0x54, 0x72, 0x61, 0x63, 0x65, 0x44, 0x75, 0x6d, 0x70, 0x65, 0x64,
+    0x48, 0x00, 0x12, 0x30, 0x0a, 0x0e, 0x63, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65, 0x5f, 0x71, 0x75,
+    0x65, 0x75, 0x65, 0x64, 0x18, 0x09, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x16, 0x2e, 0x73, 0x63, 0x63,
+    0x61, 0x63, 0x68, 0x65, 0x2e, 0x43, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65, 0x51, 0x75, 0x65, 0x75,
+    0x65, 0x64, 0x48, 0x00, 0x42, 0x0a, 0x0a, 0x08, 0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+    0x4a, 0xf9, 0x2a, 0x0a, 0x07, 0x12, 0x05, 0x0e, 0x00, 0xa2, 0x01, 0x01, 0x0a, 0x08, 0x0a, 0x01,
+    0x02, 0x12, 0x03, 0x0e, 0x08, 0x0f, 0x0a, 0x22, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x03, 0x11, 0x00,
+    0x13, 0x1a, 0x17, 0x20, 0x47, 0x65, 0x74, 0x20, 0x63, 0x61, 0x63, 0x68, 0x65, 0x20, 0x73, 0x74,
+    0x61, 0x74, 0x69, 0x73, 0x74, 0x69, 0x63, 0x73, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00,
+    0x01, 0x12, 0x03, 0x11, 0x08, 0x10, 0x0a, 0x22, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x03, 0x14, 0x00,
+    0x13, 0x1a, 0x17, 0x20, 0x53, 0x68, 0x75, 0x74, 0x20, 0x64, 0x6f, 0x77, 0x6e, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01,
+    0x01, 0x12, 0x03, 0x14, 0x08, 0x10, 0x0a, 0x23, 0x0a, 0x02, 0x04, 0x02, 0x12, 0x03, 0x17, 0x00,
+    0x14, 0x1a, 0x18, 0x20, 0x5a, 0x65, 0x72, 0x6f, 0x20, 0x63, 0x61, 0x63, 0x68, 0x65, 0x20, 0x73,
+    0x74, 0x61, 0x74, 0x69, 0x73, 0x74, 0x69, 0x63, 0x73, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04,
+    0x02, 0x01, 0x12, 0x03, 0x17, 0x08, 0x11, 0x0a, 0x52, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x03, 0x1a,
+    0x00, 0x14, 0x1a, 0x47, 0x20, 0x57, 0x72, 0x69, 0x74, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74,
+    0x69, 0x6d, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62,
+    0x75, 0x69, 0x6c, 0x64, 0x20, 0x73, 0x6f, 0x20, 0x66, 0x61, 0x72, 0x20, 0x74, 0x6f, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72, 0x27, 0x73, 0x20, 0x74, 0x69, 0x6d, 0x65,
+    0x6c, 0x69, 0x6e, 0x65, 0x20, 0x66, 0x69, 0x6c, 0x65, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04,
+    0x03, 0x01, 0x12, 0x03, 0x1a, 0x08, 0x11, 0x0a, 0x82, 0x01, 0x0a, 0x02, 0x04, 0x04, 0x12, 0x04,
+    0x1e, 0x00, 0x23, 0x01, 0x1a, 0x76, 0x20, 0x54, 0x68, 0x65, 0x20, 0x76, 0x65, 0x72, 0x73, 0x69,
+    0x6f, 0x6e, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x73, 0x63, 0x63, 0x61, 0x63, 0x68, 0x65, 0x20, 0x6f,
+    0x6e, 0x20, 0x65, 0x69, 0x74, 0x68, 0x65, 0x72, 0x20, 0x65, 0x6e, 0x64, 0x20, 0x6f, 0x66, 0x20,
+    0x61, 0x20, 0x63, 0x6f, 0x6e, 0x6e, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x20, 0x41, 0x20,
+    0x63, 0x6c, 0x69, 0x65, 0x6e, 0x74, 0x20, 0x73, 0x65, 0x6e, 0x64, 0x73, 0x0a, 0x20, 0x69, 0x74,
+    0x73, 0x20, 0x6f, 0x77, 0x6e, 0x2c, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73,
+    0x65, 0x72, 0x76, 0x65, 0x72, 0x20, 0x72, 0x65, 0x70, 0x6c, 0x69, 0x65, 0x73, 0x20, 0x77, 0x69,
+    0x74, 0x68, 0x20, 0x69, 0x74, 0x73, 0x20, 0x6f, 0x77, 0x6e, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03,
+    0x04, 0x04, 0x01, 0x12, 0x03, 0x1e, 0x08, 0x0d, 0x0a, 0x31, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x00,
+    0x12, 0x03, 0x20, 0x02, 0x1e, 0x1a, 0x24, 0x20, 0x54, 0x68, 0x65, 0x20, 0x76, 0x65, 0x72, 0x73,
+    0x69, 0x6f, 0x6e, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x63, 0x63, 0x61, 0x63,
+    0x68, 0x65, 0x20, 0x62, 0x69, 0x6e, 0x61, 0x72, 0x79, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x04, 0x02, 0x00, 0x04, 0x12, 0x03, 0x20, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02,
+    0x00, 0x05, 0x12, 0x03, 0x20, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x01,
+    0x12, 0x03, 0x20, 0x12, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x03, 0x12, 0x03,
+    0x20, 0x1c, 0x1d, 0x0a, 0x35, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x01, 0x12, 0x03, 0x22, 0x02, 0x24,
+    0x1a, 0x28, 0x20, 0x54, 0x68, 0x65, 0x20, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x6f,
+    0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x61, 0x63, 0x68, 0x65, 0x27, 0x73, 0x20, 0x6b, 0x65,
+    0x79, 0x20, 0x66, 0x6f, 0x72, 0x6d, 0x61, 0x74, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04,
+    0x02, 0x01, 0x04, 0x12, 0x03, 0x22, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01,
+    0x05, 0x12, 0x03, 0x22, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x01, 0x12,
+    0x03, 0x22, 0x12, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x03, 0x12, 0x03, 0x22,
+    0x22, 0x23, 0x0a, 0x24, 0x0a, 0x02, 0x04, 0x05, 0x12, 0x04, 0x26, 0x00, 0x35, 0x01, 0x1a, 0x18,
+    0x20, 0x52, 0x75, 0x6e, 0x20, 0x61, 0x20, 0x63, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65, 0x20, 0x63,
+    0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12,
+    0x03, 0x26, 0x08, 0x0f, 0x0a, 0x39, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x03, 0x28, 0x02,
+    0x1a, 0x1a, 0x2c, 0x20, 0x54, 0x68, 0x65, 0x20, 0x64, 0x69, 0x72, 0x65, 0x63, 0x74, 0x6f, 0x72,
+    0x79, 0x20, 0x69, 0x6e, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x74, 0x6f, 0x20, 0x72, 0x75,
+    0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x2e, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x04, 0x12, 0x03, 0x28, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x05, 0x02, 0x00, 0x05, 0x12, 0x03, 0x28, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x05, 0x02, 0x00, 0x01, 0x12, 0x03, 0x28, 0x12, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02,
+    0x00, 0x03, 0x12, 0x03, 0x28, 0x18, 0x19, 0x0a, 0x25, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x01, 0x12,
+    0x03, 0x2a, 0x02, 0x1a, 0x1a, 0x18, 0x20, 0x54, 0x68, 0x65, 0x20, 0x65, 0x78, 0x65, 0x63, 0x75,
+    0x74, 0x61, 0x62, 0x6c, 0x65, 0x20, 0x74, 0x6f, 0x20, 0x72, 0x75, 0x6e, 0x2e, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x04, 0x12, 0x03, 0x2a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x05, 0x02, 0x01, 0x05, 0x12, 0x03, 0x2a, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
+    0x02, 0x01, 0x01, 0x12, 0x03, 0x2a, 0x12, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01,
+    0x03, 0x12, 0x03, 0x2a, 0x18, 0x19, 0x0a, 0x2a, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x02, 0x12, 0x03,
+    0x2c, 0x02, 0x1e, 0x1a, 0x1d, 0x20, 0x54, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6d, 0x6d, 0x61, 0x6e,
+    0x64, 0x20, 0x6c, 0x69, 0x6e, 0x65, 0x20, 0x61, 0x72, 0x67, 0x75, 0x6d, 0x65, 0x6e, 0x74, 0x73,
+    0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x04, 0x12, 0x03, 0x2c, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x05, 0x12, 0x03, 0x2c, 0x0b, 0x11, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x01, 0x12, 0x03, 0x2c, 0x12, 0x19, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x05, 0x02, 0x02, 0x03, 0x12, 0x03, 0x2c, 0x1c, 0x1d, 0x0a, 0x2c, 0x0a, 0x04, 0x04, 0x05,
+    0x02, 0x03, 0x12, 0x03, 0x2e, 0x02, 0x21, 0x1a, 0x1f, 0x20, 0x54, 0x68, 0x65, 0x20, 0x70, 0x72,
+    0x6f, 0x63, 0x65, 0x73, 0x73, 0x20, 0x49, 0x44, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x63, 0x6c, 0x69, 0x65, 0x6e, 0x74, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03,
+    0x04, 0x12, 0x03, 0x2e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03, 0x05, 0x12,
+    0x03, 0x2e, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03, 0x01, 0x12, 0x03, 0x2e,
+    0x12, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03, 0x03, 0x12, 0x03, 0x2e, 0x1f, 0x20,
+    0x0a, 0x82, 0x01, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x04, 0x12, 0x03, 0x31, 0x02, 0x20, 0x1a, 0x75,
+    0x20, 0x54, 0x68, 0x65, 0x20, 0x4d, 0x41, 0x4b, 0x45, 0x46, 0x4c, 0x41, 0x47, 0x53, 0x20, 0x6e,
+    0x61, 0x6d, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x47, 0x4e, 0x55, 0x20, 0x6d, 0x61,
+    0x6b, 0x65, 0x20, 0x6a, 0x6f, 0x62, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x63, 0x6c, 0x69, 0x65, 0x6e, 0x74, 0x20, 0x6c, 0x65, 0x6e, 0x64, 0x73, 0x20, 0x69, 0x74,
+    0x73, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x0a, 0x20, 0x74, 0x6f, 0x20, 0x77, 0x68, 0x65, 0x6e,
+    0x20, 0x74, 0x6f, 0x6c, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6d, 0x70, 0x69, 0x6c,
+    0x65, 0x20, 0x69, 0x73, 0x20, 0x71, 0x75, 0x65, 0x75, 0x65, 0x64, 0x2c, 0x20, 0x69, 0x66, 0x20,
+    0x61, 0x6e, 0x79, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x04, 0x04, 0x12, 0x03,
+    0x31, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x04, 0x05, 0x12, 0x03, 0x31, 0x0b,
+    0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x04, 0x01, 0x12, 0x03, 0x31, 0x12, 0x1b, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x04, 0x03, 0x12, 0x03, 0x31, 0x1e, 0x1f, 0x0a, 0x87, 0x01,
+    0x0a, 0x04, 0x04, 0x05, 0x02, 0x05, 0x12, 0x03, 0x34, 0x02, 0x1b, 0x1a, 0x7a, 0x20, 0x54, 0x68,
+    0x65, 0x20, 0x63, 0x6c, 0x69, 0x65, 0x6e, 0x74, 0x27, 0x73, 0x20, 0x76, 0x65, 0x72, 0x73, 0x69,
+    0x6f, 0x6e, 0x2e, 0x20, 0x41, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72, 0x20, 0x72, 0x75, 0x6e,
+    0x6e, 0x69, 0x6e, 0x67, 0x20, 0x61, 0x20, 0x64, 0x69, 0x66, 0x66, 0x65, 0x72, 0x65, 0x6e, 0x74,
+    0x20, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x72, 0x65, 0x70, 0x6c, 0x69, 0x65, 0x73,
+    0x0a, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x69, 0x74, 0x73, 0x20, 0x6f, 0x77, 0x6e, 0x20, 0x60,
+    0x48, 0x65, 0x6c, 0x6c, 0x6f, 0x60, 0x20, 0x69, 0x6e, 0x73, 0x74, 0x65, 0x61, 0x64, 0x20, 0x6f,
+    0x66, 0x20, 0x72, 0x75, 0x6e, 0x6e, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f,
+    0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x05, 0x04,
+    0x12, 0x03, 0x34, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x05, 0x06, 0x12, 0x03,
+    0x34, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x05, 0x01, 0x12, 0x03, 0x34, 0x11,
+    0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x05, 0x03, 0x12, 0x03, 0x34, 0x19, 0x1a, 0x0a,
+    0x0a, 0x0a, 0x02, 0x04, 0x06, 0x12, 0x04, 0x37, 0x00, 0x41, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04,
+    0x06, 0x01, 0x12, 0x03, 0x37, 0x08, 0x15, 0x0a, 0x41, 0x0a, 0x04, 0x04, 0x06, 0x08, 0x00, 0x12,
+    0x04, 0x39, 0x02, 0x40, 0x03, 0x1a, 0x33, 0x20, 0x41, 0x20, 0x63, 0x6c, 0x69, 0x65, 0x6e, 0x74,
+    0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x20, 0x63, 0x61, 0x6e, 0x20, 0x62, 0x65, 0x20,
+    0x61, 0x6e, 0x79, 0x20, 0x6f, 0x6e, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x66,
+    0x6f, 0x6c, 0x6c, 0x6f, 0x77, 0x69, 0x6e, 0x67, 0x3a, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06,
+    0x08, 0x00, 0x01, 0x12, 0x03, 0x39, 0x08, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x00,
+    0x12, 0x03, 0x3a, 0x04, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x06, 0x12, 0x03,
+    0x3a, 0x04, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x01, 0x12, 0x03, 0x3a, 0x0c,
+    0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x03, 0x12, 0x03, 0x3a, 0x16, 0x17, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x01, 0x12, 0x03, 0x3b, 0x04, 0x1b, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x06, 0x02, 0x01, 0x06, 0x12, 0x03, 0x3b, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06,
+    0x02, 0x01, 0x01, 0x12, 0x03, 0x3b, 0x0d, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01,
+    0x03, 0x12, 0x03, 0x3b, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x02, 0x12, 0x03,
+    0x3c, 0x04, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x06, 0x12, 0x03, 0x3c, 0x04,
+    0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x01, 0x12, 0x03, 0x3c, 0x0d, 0x15, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x03, 0x12, 0x03, 0x3c, 0x18, 0x19, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x06, 0x02, 0x03, 0x12, 0x03, 0x3d, 0x04, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06,
+    0x02, 0x03, 0x06, 0x12, 0x03, 0x3d, 0x04, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x03,
+    0x01, 0x12, 0x03, 0x3d, 0x0e, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x03, 0x03, 0x12,
+    0x03, 0x3d, 0x1b, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x04, 0x12, 0x03, 0x3e, 0x04,
+    0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x04, 0x06, 0x12, 0x03, 0x3e, 0x04, 0x09, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x04, 0x01, 0x12, 0x03, 0x3e, 0x0a, 0x0f, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x06, 0x02, 0x04, 0x03, 0x12, 0x03, 0x3e, 0x12, 0x13, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x06, 0x02, 0x05, 0x12, 0x03, 0x3f, 0x04, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x05,
+    0x06, 0x12, 0x03, 0x3f, 0x04, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x05, 0x01, 0x12,
+    0x03, 0x3f, 0x0e, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x05, 0x03, 0x12, 0x03, 0x3f,
+    0x1b, 0x1c, 0x0a, 0x49, 0x0a, 0x02, 0x04, 0x07, 0x12, 0x04, 0x44, 0x00, 0x4a, 0x01, 0x1a, 0x3d,
+    0x20, 0x50, 0x65, 0x72, 0x63, 0x65, 0x6e, 0x74, 0x69, 0x6c, 0x65, 0x73, 0x20, 0x6f, 0x66, 0x20,
+    0x61, 0x20, 0x64, 0x69, 0x73, 0x74, 0x72, 0x69, 0x62, 0x75, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x6f,
+    0x66, 0x20, 0x64, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x2c, 0x20, 0x69, 0x6e, 0x20,
+    0x6e, 0x61, 0x6e, 0x6f, 0x73, 0x65, 0x63, 0x6f, 0x6e, 0x64, 0x73, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a,
+    0x03, 0x04, 0x07, 0x01, 0x12, 0x03, 0x44, 0x08, 0x11, 0x0a, 0x27, 0x0a, 0x04, 0x04, 0x07, 0x02,
+    0x00, 0x12, 0x03, 0x46, 0x02, 0x1c, 0x1a, 0x1a, 0x20, 0x54, 0x68, 0x65, 0x20, 0x6e, 0x75, 0x6d,
+    0x62, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x64, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73,
+    0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x04, 0x12, 0x03, 0x46, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x05, 0x12, 0x03, 0x46, 0x0b, 0x11, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x01, 0x12, 0x03, 0x46, 0x12, 0x17, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x07, 0x02, 0x00, 0x03, 0x12, 0x03, 0x46, 0x1a, 0x1b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07,
+    0x02, 0x01, 0x12, 0x03, 0x47, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x04,
+    0x12, 0x03, 0x47, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x05, 0x12, 0x03,
+    0x47, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x01, 0x12, 0x03, 0x47, 0x12,
+    0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x03, 0x12, 0x03, 0x47, 0x18, 0x19, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x02, 0x12, 0x03, 0x48, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x07, 0x02, 0x02, 0x04, 0x12, 0x03, 0x48, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07,
+    0x02, 0x02, 0x05, 0x12, 0x03, 0x48, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02,
+    0x01, 0x12, 0x03, 0x48, 0x12, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x03, 0x12,
+    0x03, 0x48, 0x18, 0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x03, 0x12, 0x03, 0x49, 0x02,
+    0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x03, 0x04, 0x12, 0x03, 0x49, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x03, 0x05, 0x12, 0x03, 0x49, 0x0b, 0x11, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x07, 0x02, 0x03, 0x01, 0x12, 0x03, 0x49, 0x12, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x07, 0x02, 0x03, 0x03, 0x12, 0x03, 0x49, 0x18, 0x19, 0x0a, 0x27, 0x0a, 0x02, 0x04, 0x08, 0x12,
+    0x04, 0x4d, 0x00, 0x5b, 0x01, 0x1a, 0x1b, 0x20, 0x41, 0x20, 0x73, 0x69, 0x6e, 0x67, 0x6c, 0x65,
+    0x20, 0x63, 0x61, 0x63, 0x68, 0x65, 0x20, 0x73, 0x74, 0x61, 0x74, 0x69, 0x73, 0x74, 0x69, 0x63,
+    0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x08, 0x01, 0x12, 0x03, 0x4d, 0x08, 0x16, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x08, 0x02, 0x00, 0x12, 0x03, 0x4e, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x08, 0x02, 0x00, 0x04, 0x12, 0x03, 0x4e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02,
+    0x00, 0x05, 0x12, 0x03, 0x4e, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x01,
+    0x12, 0x03, 0x4e, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x03, 0x12, 0x03,
+    0x4e, 0x19, 0x1a, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x08, 0x08, 0x00, 0x12, 0x04, 0x4f, 0x02, 0x5a,
+    0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x08, 0x00, 0x01, 0x12, 0x03, 0x4f, 0x08, 0x0d, 0x0a,
+    0x20, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x01, 0x12, 0x03, 0x51, 0x04, 0x15, 0x1a, 0x13, 0x20, 0x41,
+    0x20, 0x73, 0x69, 0x6d, 0x70, 0x6c, 0x65, 0x20, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x65, 0x72, 0x2e,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x05, 0x12, 0x03, 0x51, 0x04, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x01, 0x12, 0x03, 0x51, 0x0b, 0x10, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x08, 0x02, 0x01, 0x03, 0x12, 0x03, 0x51, 0x13, 0x14, 0x0a, 0x1e, 0x0a, 0x04, 0x04,
+    0x08, 0x02, 0x02, 0x12, 0x03, 0x53, 0x04, 0x13, 0x1a, 0x11, 0x20, 0x41, 0x20, 0x73, 0x74, 0x72,
+    0x69, 0x6e, 0x67, 0x20, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x08, 0x02, 0x02, 0x05, 0x12, 0x03, 0x53, 0x04, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02,
+    0x02, 0x01, 0x12, 0x03, 0x53, 0x0b, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x03,
+    0x12, 0x03, 0x53, 0x11, 0x12, 0x0a, 0x20, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x03, 0x12, 0x03, 0x55,
+    0x04, 0x14, 0x1a, 0x13, 0x20, 0x41, 0x20, 0x73, 0x69, 0x7a, 0x65, 0x2c, 0x20, 0x69, 0x6e, 0x20,
+    0x62, 0x79, 0x74, 0x65, 0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x05,
+    0x12, 0x03, 0x55, 0x04, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x01, 0x12, 0x03,
+    0x55, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x03, 0x12, 0x03, 0x55, 0x12,
+    0x13, 0x0a, 0x2a, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x04, 0x12, 0x03, 0x57, 0x04, 0x18, 0x1a, 0x1d,
+    0x20, 0x41, 0x20, 0x64, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2c, 0x20, 0x69, 0x6e, 0x20,
+    0x6e, 0x61, 0x6e, 0x6f, 0x73, 0x65, 0x63, 0x6f, 0x6e, 0x64, 0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x08, 0x02, 0x04, 0x05, 0x12, 0x03, 0x57, 0x04, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x08, 0x02, 0x04, 0x01, 0x12, 0x03, 0x57, 0x0b, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02,
+    0x04, 0x03, 0x12, 0x03, 0x57, 0x16, 0x17, 0x0a, 0x2b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x05, 0x12,
+    0x03, 0x59, 0x04, 0x1c, 0x1a, 0x1e, 0x20, 0x41, 0x20, 0x64, 0x69, 0x73, 0x74, 0x72, 0x69, 0x62,
+    0x75, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x6f, 0x66, 0x20, 0x64, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f,
+    0x6e, 0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x05, 0x06, 0x12, 0x03, 0x59,
+    0x04, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x05, 0x01, 0x12, 0x03, 0x59, 0x0e, 0x17,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x05, 0x03, 0x12, 0x03, 0x59, 0x1a, 0x1b, 0x0a, 0x56,
+    0x0a, 0x02, 0x04, 0x09, 0x12, 0x04, 0x5e, 0x00, 0x64, 0x01, 0x1a, 0x4a, 0x20, 0x43, 0x61, 0x63,
+    0x68, 0x65, 0x20, 0x73, 0x74, 0x61, 0x74, 0x69, 0x73, 0x74, 0x69, 0x63, 0x73, 0x20, 0x66, 0x6f,
+    0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65, 0x20, 0x72, 0x65,
+    0x71, 0x75, 0x65, 0x73, 0x74, 0x73, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x68, 0x61, 0x76, 0x65,
+    0x20, 0x73, 0x6f, 0x6d, 0x65, 0x74, 0x68, 0x69, 0x6e, 0x67, 0x20, 0x69, 0x6e, 0x20, 0x63, 0x6f,
+    0x6d, 0x6d, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x09, 0x01, 0x12, 0x03, 0x5e,
+    0x08, 0x1b, 0x0a, 0x52, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x00, 0x12, 0x03, 0x60, 0x02, 0x1b, 0x1a,
+    0x45, 0x20, 0x57, 0x68, 0x61, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65,
+    0x73, 0x74, 0x73, 0x20, 0x68, 0x61, 0x76, 0x65, 0x20, 0x69, 0x6e, 0x20, 0x63, 0x6f, 0x6d, 0x6d,
+    0x6f, 0x6e, 0x2c, 0x20, 0x65, 0x69, 0x74, 0x68, 0x65, 0x72, 0x20, 0x22, 0x63, 0x6f, 0x6d, 0x70,
+    0x69, 0x6c, 0x65, 0x72, 0x22, 0x20, 0x6f, 0x72, 0x20, 0x22, 0x65, 0x78, 0x74, 0x65, 0x6e, 0x73,
+    0x69, 0x6f, 0x6e, 0x22, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x04, 0x12,
+    0x03, 0x60, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x05, 0x12, 0x03, 0x60,
+    0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x01, 0x12, 0x03, 0x60, 0x12, 0x16,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x03, 0x12, 0x03, 0x60, 0x19, 0x1a, 0x0a, 0x41,
+    0x0a, 0x04, 0x04, 0x09, 0x02, 0x01, 0x12, 0x03, 0x62, 0x02, 0x1c, 0x1a, 0x34, 0x20, 0x54, 0x68,
+    0x65, 0x20, 0x6b, 0x69, 0x6e, 0x64, 0x20, 0x6f, 0x66, 0x20, 0x63, 0x6f, 0x6d, 0x70, 0x69, 0x6c,
+    0x65, 0x72, 0x20, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x6f, 0x75, 0x72, 0x63, 0x65,
+    0x20, 0x66, 0x69, 0x6c, 0x65, 0x20, 0x65, 0x78, 0x74, 0x65, 0x6e, 0x73, 0x69, 0x6f, 0x6e, 0x2e,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x04, 0x12, 0x03, 0x62, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x05, 0x12, 0x03, 0x62, 0x0b, 0x11, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x09, 0x02, 0x01, 0x01, 0x12, 0x03, 0x62, 0x12, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x09, 0x02, 0x01, 0x03, 0x12, 0x03, 0x62, 0x1a, 0x1b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02,
+    0x02, 0x12, 0x03, 0x63, 0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x04, 0x12,
+    0x03, 0x63, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x06, 0x12, 0x03, 0x63,
+    0x0b, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x01, 0x12, 0x03, 0x63, 0x1a, 0x1f,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x03, 0x12, 0x03, 0x63, 0x22, 0x23, 0x0a, 0x29,
+    0x0a, 0x02, 0x04, 0x0a, 0x12, 0x04, 0x67, 0x00, 0x6b, 0x01, 0x1a, 0x1d, 0x20, 0x41, 0x20, 0x6c,
+    0x69, 0x73, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x63, 0x61, 0x63, 0x68, 0x65, 0x20, 0x73, 0x74, 0x61,
+    0x74, 0x69, 0x73, 0x74, 0x69, 0x63, 0x73, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0a, 0x01,
+    0x12, 0x03, 0x67, 0x08, 0x12, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x00, 0x12, 0x03, 0x68,
+    0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x04, 0x12, 0x03, 0x68, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x06, 0x12, 0x03, 0x68, 0x0b, 0x19, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x01, 0x12, 0x03, 0x68, 0x1a, 0x1f, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x0a, 0x02, 0x00, 0x03, 0x12, 0x03, 0x68, 0x22, 0x23, 0x0a, 0x52, 0x0a, 0x04, 0x04, 0x0a,
+    0x02, 0x01, 0x12, 0x03, 0x6a, 0x02, 0x2e, 0x1a, 0x45, 0x20, 0x54, 0x68, 0x65, 0x20, 0x73, 0x74,
+    0x61, 0x74, 0x69, 0x73, 0x74, 0x69, 0x63, 0x73, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x65, 0x61, 0x63,
+    0x68, 0x20, 0x6b, 0x69, 0x6e, 0x64, 0x20, 0x6f, 0x66, 0x20, 0x63, 0x6f, 0x6d, 0x70, 0x69, 0x6c,
+    0x65, 0x72, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x73, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x20, 0x66, 0x69,
+    0x6c, 0x65, 0x20, 0x65, 0x78, 0x74, 0x65, 0x6e, 0x73, 0x69, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x04, 0x12, 0x03, 0x6a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x0a, 0x02, 0x01, 0x06, 0x12, 0x03, 0x6a, 0x0b, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a,
+    0x02, 0x01, 0x01, 0x12, 0x03, 0x6a, 0x1f, 0x29, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01,
+    0x03, 0x12, 0x03, 0x6a, 0x2c, 0x2d, 0x0a, 0x2a, 0x0a, 0x02, 0x04, 0x0b, 0x12, 0x04, 0x6e, 0x00,
+    0x70, 0x01, 0x1a, 0x1e, 0x20, 0x54, 0x68, 0x65, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72, 0x20,
+    0x69, 0x73, 0x20, 0x73, 0x68, 0x75, 0x74, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x64, 0x6f, 0x77, 0x6e,
+    0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0b, 0x01, 0x12, 0x03, 0x6e, 0x08, 0x14, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x0b, 0x02, 0x00, 0x12, 0x03, 0x6f, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0b, 0x02, 0x00, 0x04, 0x12, 0x03, 0x6f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02,
+    0x00, 0x06, 0x12, 0x03, 0x6f, 0x0b, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x01,
+    0x12, 0x03, 0x6f, 0x16, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x03, 0x12, 0x03,
+    0x6f, 0x1e, 0x1f, 0x0a, 0x38, 0x0a, 0x02, 0x04, 0x0c, 0x12, 0x03, 0x73, 0x00, 0x19, 0x1a, 0x2d,
+    0x20, 0x54, 0x68, 0x65, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72, 0x20, 0x73, 0x74, 0x61, 0x72,
+    0x74, 0x65, 0x64, 0x20, 0x65, 0x78, 0x65, 0x63, 0x75, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x61, 0x20,
+    0x63, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a,
+    0x03, 0x04, 0x0c, 0x01, 0x12, 0x03, 0x73, 0x08, 0x16, 0x0a, 0x9a, 0x01, 0x0a, 0x02, 0x04, 0x0d,
+    0x12, 0x03, 0x77, 0x00, 0x18, 0x1a, 0x8e, 0x01, 0x20, 0x54, 0x68, 0x65, 0x20, 0x73, 0x65, 0x72,
+    0x76, 0x65, 0x72, 0x20, 0x6d, 0x69, 0x73, 0x73, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63,
+    0x61, 0x63, 0x68, 0x65, 0x2c, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x69, 0x73, 0x20, 0x77, 0x61, 0x69,
+    0x74, 0x69, 0x6e, 0x67, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x20, 0x6a, 0x6f, 0x62, 0x73, 0x65,
+    0x72, 0x76, 0x65, 0x72, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x74, 0x6f, 0x20, 0x72, 0x75,
+    0x6e, 0x0a, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65, 0x72, 0x2e,
+    0x20, 0x54, 0x68, 0x65, 0x20, 0x63, 0x6c, 0x69, 0x65, 0x6e, 0x74, 0x20, 0x6c, 0x65, 0x6e, 0x64,
+    0x73, 0x20, 0x69, 0x74, 0x73, 0x20, 0x6f, 0x77, 0x6e, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20,
+    0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6a, 0x6f, 0x62, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72,
+    0x20, 0x6e, 0x6f, 0x77, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0d, 0x01, 0x12, 0x03, 0x77,
+    0x08, 0x15, 0x0a, 0x30, 0x0a, 0x02, 0x04, 0x0e, 0x12, 0x05, 0x7a, 0x00, 0x84, 0x01, 0x01, 0x1a,
+    0x23, 0x20, 0x54, 0x68, 0x65, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72, 0x20, 0x72, 0x61, 0x6e,
+    0x20, 0x61, 0x20, 0x63, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65, 0x20, 0x63, 0x6f, 0x6d, 0x6d, 0x61,
+    0x6e, 0x64, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0e, 0x01, 0x12, 0x03, 0x7a, 0x08, 0x17,
+    0x0a, 0x30, 0x0a, 0x04, 0x04, 0x0e, 0x08, 0x00, 0x12, 0x05, 0x7c, 0x02, 0x81, 0x01, 0x03, 0x1a,
+    0x21, 0x20, 0x54, 0x68, 0x65, 0x20, 0x72, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x20, 0x63, 0x6f, 0x64,
+    0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x72, 0x6f, 0x63, 0x65, 0x73, 0x73,
+    0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x08, 0x00, 0x01, 0x12, 0x03, 0x7c, 0x08, 0x13,
+    0x0a, 0x1b, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x00, 0x12, 0x03, 0x7e, 0x04, 0x16, 0x1a, 0x0e, 0x20,
+    0x4e, 0x6f, 0x72, 0x6d, 0x61, 0x6c, 0x20, 0x65, 0x78, 0x69, 0x74, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0e, 0x02, 0x00, 0x05, 0x12, 0x03, 0x7e, 0x04, 0x09, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0e, 0x02, 0x00, 0x01, 0x12, 0x03, 0x7e, 0x0a, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02,
+    0x00, 0x03, 0x12, 0x03, 0x7e, 0x14, 0x15, 0x0a, 0x27, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x01, 0x12,
+    0x04, 0x80, 0x01, 0x04, 0x15, 0x1a, 0x19, 0x20, 0x54, 0x65, 0x72, 0x6d, 0x69, 0x6e, 0x61, 0x74,
+    0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x61, 0x20, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x6c, 0x2e, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x05, 0x12, 0x04, 0x80, 0x01, 0x04, 0x09, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x01, 0x12, 0x04, 0x80, 0x01, 0x0a, 0x10, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x03, 0x12, 0x04, 0x80, 0x01, 0x13, 0x14, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x0e, 0x02, 0x02, 0x12, 0x04, 0x82, 0x01, 0x02, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0e, 0x02, 0x02, 0x04, 0x12, 0x04, 0x82, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e,
+    0x02, 0x02, 0x05, 0x12, 0x04, 0x82, 0x01, 0x0b, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02,
+    0x02, 0x01, 0x12, 0x04, 0x82, 0x01, 0x11, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x02,
+    0x03, 0x12, 0x04, 0x82, 0x01, 0x1a, 0x1b, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x03, 0x12,
+    0x04, 0x83, 0x01, 0x02, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x03, 0x04, 0x12, 0x04,
+    0x83, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x03, 0x05, 0x12, 0x04, 0x83,
+    0x01, 0x0b, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x03, 0x01, 0x12, 0x04, 0x83, 0x01,
+    0x11, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x03, 0x03, 0x12, 0x04, 0x83, 0x01, 0x1a,
+    0x1b, 0x0a, 0x44, 0x0a, 0x02, 0x04, 0x0f, 0x12, 0x04, 0x87, 0x01, 0x00, 0x1b, 0x1a, 0x38, 0x20,
+    0x54, 0x68, 0x65, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72, 0x20, 0x63, 0x6f, 0x75, 0x6c, 0x64,
+    0x20, 0x6e, 0x6f, 0x74, 0x20, 0x68, 0x61, 0x6e, 0x64, 0x6c, 0x65, 0x20, 0x74, 0x68, 0x69, 0x73,
+    0x20, 0x63, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65, 0x20, 0x63, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64,
+    0x20, 0x6c, 0x69, 0x6e, 0x65, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0f, 0x01, 0x12, 0x04,
+    0x87, 0x01, 0x08, 0x18, 0x0a, 0x35, 0x0a, 0x02, 0x04, 0x10, 0x12, 0x04, 0x8a, 0x01, 0x00, 0x19,
+    0x1a, 0x29, 0x20, 0x54, 0x68, 0x69, 0x73, 0x20, 0x63, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x20,
+    0x77, 0x61, 0x73, 0x20, 0x75, 0x6e, 0x6b, 0x6e, 0x6f, 0x77, 0x6e, 0x20, 0x74, 0x6f, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04,
+    0x10, 0x01, 0x12, 0x04, 0x8a, 0x01, 0x08, 0x16, 0x0a, 0x3b, 0x0a, 0x02, 0x04, 0x11, 0x12, 0x06,
+    0x8d, 0x01, 0x00, 0x93, 0x01, 0x01, 0x1a, 0x2d, 0x20, 0x54, 0x68, 0x65, 0x20, 0x73, 0x65, 0x72,
+    0x76, 0x65, 0x72, 0x20, 0x77, 0x72, 0x6f, 0x74, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x69,
+    0x6d, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x75,
+    0x69, 0x6c, 0x64, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x11, 0x01, 0x12, 0x04, 0x8d, 0x01,
+    0x08, 0x13, 0x0a, 0x66, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x00, 0x12, 0x04, 0x90, 0x01, 0x02, 0x1b,
+    0x1a, 0x58, 0x20, 0x54, 0x68, 0x65, 0x20, 0x66, 0x69, 0x6c, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x74, 0x69, 0x6d, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x20, 0x77, 0x61, 0x73, 0x20, 0x77, 0x72, 0x69,
+    0x74, 0x74, 0x65, 0x6e, 0x20, 0x74, 0x6f, 0x2e, 0x20, 0x55, 0x6e, 0x73, 0x65, 0x74, 0x20, 0x69,
+    0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72, 0x20, 0x69, 0x73, 0x6e,
+    0x27, 0x74, 0x0a, 0x20, 0x72, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x67, 0x20, 0x61, 0x20,
+    0x74, 0x69, 0x6d, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11,
+    0x02, 0x00, 0x04, 0x12, 0x04, 0x90, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02,
+    0x00, 0x05, 0x12, 0x04, 0x90, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00,
+    0x01, 0x12, 0x04, 0x90, 0x01, 0x12, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00, 0x03,
+    0x12, 0x04, 0x90, 0x01, 0x19, 0x1a, 0x0a, 0x45, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x01, 0x12, 0x04,
+    0x92, 0x01, 0x02, 0x1c, 0x1a, 0x37, 0x20, 0x57, 0x68, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74,
+    0x69, 0x6d, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x20, 0x63, 0x6f, 0x75, 0x6c, 0x64, 0x6e, 0x27, 0x74,
+    0x20, 0x62, 0x65, 0x20, 0x77, 0x72, 0x69, 0x74, 0x74, 0x65, 0x6e, 0x2c, 0x20, 0x69, 0x66, 0x20,
+    0x69, 0x74, 0x20, 0x63, 0x6f, 0x75, 0x6c, 0x64, 0x6e, 0x27, 0x74, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x11, 0x02, 0x01, 0x04, 0x12, 0x04, 0x92, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x11, 0x02, 0x01, 0x05, 0x12, 0x04, 0x92, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x11, 0x02, 0x01, 0x01, 0x12, 0x04, 0x92, 0x01, 0x12, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11,
+    0x02, 0x01, 0x03, 0x12, 0x04, 0x92, 0x01, 0x1a, 0x1b, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x12, 0x12,
+    0x06, 0x95, 0x01, 0x00, 0xa2, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x12, 0x01, 0x12, 0x04,
+    0x95, 0x01, 0x08, 0x16, 0x0a, 0x44, 0x0a, 0x04, 0x04, 0x12, 0x08, 0x00, 0x12, 0x06, 0x97, 0x01,
+    0x02, 0xa1, 0x01, 0x03, 0x1a, 0x34, 0x20, 0x41, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72, 0x20,
+    0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x20, 0x63, 0x61, 0x6e, 0x20, 0x62, 0x65, 0x20,
+    0x61, 0x6e, 0x79, 0x20, 0x6f, 0x6e, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x66,
+    0x6f, 0x6c, 0x6c, 0x6f, 0x77, 0x69, 0x6e, 0x67, 0x3a, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12,
+    0x08, 0x00, 0x01, 0x12, 0x04, 0x97, 0x01, 0x08, 0x10, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x12, 0x02,
+    0x00, 0x12, 0x04, 0x98, 0x01, 0x04, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x00, 0x06,
+    0x12, 0x04, 0x98, 0x01, 0x04, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x00, 0x01, 0x12,
+    0x04, 0x98, 0x01, 0x0f, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x00, 0x03, 0x12, 0x04,
+    0x98, 0x01, 0x17, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x12, 0x02, 0x01, 0x12, 0x04, 0x99, 0x01,
+    0x04, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x01, 0x06, 0x12, 0x04, 0x99, 0x01, 0x04,
+    0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x01, 0x01, 0x12, 0x04, 0x99, 0x01, 0x11, 0x1e,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x01, 0x03, 0x12, 0x04, 0x99, 0x01, 0x21, 0x22, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x12, 0x02, 0x02, 0x12, 0x04, 0x9a, 0x01, 0x04, 0x27, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x12, 0x02, 0x02, 0x06, 0x12, 0x04, 0x9a, 0x01, 0x04, 0x12, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x12, 0x02, 0x02, 0x01, 0x12, 0x04, 0x9a, 0x01, 0x13, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x12, 0x02, 0x02, 0x03, 0x12, 0x04, 0x9a, 0x01, 0x25, 0x26, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x12,
+    0x02, 0x03, 0x12, 0x04, 0x9b, 0x01, 0x04, 0x29, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x03,
+    0x06, 0x12, 0x04, 0x9b, 0x01, 0x04, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x03, 0x01,
+    0x12, 0x04, 0x9b, 0x01, 0x14, 0x24, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x03, 0x03, 0x12,
+    0x04, 0x9b, 0x01, 0x27, 0x28, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x12, 0x02, 0x04, 0x12, 0x04, 0x9c,
+    0x01, 0x04, 0x2b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x04, 0x06, 0x12, 0x04, 0x9c, 0x01,
+    0x04, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x04, 0x01, 0x12, 0x04, 0x9c, 0x01, 0x15,
+    0x26, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x04, 0x03, 0x12, 0x04, 0x9c, 0x01, 0x29, 0x2a,
+    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x12, 0x02, 0x05, 0x12, 0x04, 0x9d, 0x01, 0x04, 0x1f, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x12, 0x02, 0x05, 0x06, 0x12, 0x04, 0x9d, 0x01, 0x04, 0x12, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x12, 0x02, 0x05, 0x01, 0x12, 0x04, 0x9d, 0x01, 0x13, 0x1a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x12, 0x02, 0x05, 0x03, 0x12, 0x04, 0x9d, 0x01, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x12, 0x02, 0x06, 0x12, 0x04, 0x9e, 0x01, 0x04, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02,
+    0x06, 0x06, 0x12, 0x04, 0x9e, 0x01, 0x04, 0x09, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x06,
+    0x01, 0x12, 0x04, 0x9e, 0x01, 0x0a, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x06, 0x03,
+    0x12, 0x04, 0x9e, 0x01, 0x12, 0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x12, 0x02, 0x07, 0x12, 0x04,
+    0x9f, 0x01, 0x04, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x07, 0x06, 0x12, 0x04, 0x9f,
+    0x01, 0x04, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x07, 0x01, 0x12, 0x04, 0x9f, 0x01,
+    0x10, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x07, 0x03, 0x12, 0x04, 0x9f, 0x01, 0x1f,
+    0x20, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x12, 0x02, 0x08, 0x12, 0x04, 0xa0, 0x01, 0x04, 0x25, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x08, 0x06, 0x12, 0x04, 0xa0, 0x01, 0x04, 0x11, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x12, 0x02, 0x08, 0x01, 0x12, 0x04, 0xa0, 0x01, 0x12, 0x20, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x12, 0x02, 0x08, 0x03, 0x12, 0x04, 0xa0, 0x01, 0x23, 0x24,
 ];
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
@@ -13,24 +13,41 @@
 // limitations under the License.
 
 use cache::{
+    CACHE_VERSION,
+    CacheMode,
     Storage,
-    storage_from_environment,
+    cache_mode_from_config,
+    disk_cache_dir,
+    parse_size,
+    storage_from_config,
 };
+use client::ServerAddress;
 use compiler::{
     CacheControl,
     Compiler,
     CompilerArguments,
+    CompileContext,
     CompileResult,
+    DirectMode,
+    MISSES_LOG,
+    MissExplainer,
     MissType,
+    Operation,
     ParsedArguments,
+    Phase,
+    Timeline,
     get_compiler_info,
 };
+use chrono::UTC;
+use config::Config;
 use filetime::FileTime;
 use futures::future;
 use futures::sync::mpsc;
 use futures::task::{self, Task};
 use futures::{Stream, Sink, Async, AsyncSink, Poll, StartSend, Future};
 use futures_cpupool::CpuPool;
+use jobserver::Jobserver;
+use metrics::{self, Exposition, MetricType};
 use mock_command::{
     CommandCreatorSync,
     ProcessCommandCreator,
@@ -45,39 +62,69 @@ use protocol::{
     ClientRequest,
     CacheStats,
     CacheStatistic,
+    CacheStatsBreakdown,
+    Histogram,
     Compile,
     CompileFinished,
+    CompileQueued,
     CompileStarted,
+    Hello,
     ServerResponse,
     ShuttingDown,
+    TraceDumped,
     UnhandledCompile,
     UnknownCommand,
 };
+use serde_json;
 use std::collections::HashMap;
 use std::cell::RefCell;
 use std::env;
 use std::ffi::OsString;
-use std::fs::metadata;
-use std::io::{self, Write};
+use std::fs::{self, metadata, File};
+use std::io::{self, BufRead, BufReader, Write};
 use std::marker;
 use std::net::{SocketAddr, SocketAddrV4, Ipv4Addr};
+use std::path::{Path, PathBuf};
 use std::process::Output;
 use std::rc::Rc;
 use std::sync::Arc;
-use std::time::Duration;
-use tokio_core::reactor::{Handle, Core, Timeout};
+use std::time::{Duration, Instant};
+use tokio_core::reactor::{Handle, Core, Interval, Timeout};
 use tokio_core::io::{Codec, EasyBuf, Io, Framed};
 use tokio_core::net::TcpListener;
+#[cfg(unix)]
+use tokio_uds::UnixListener;
 use tokio_proto::BindServer;
 use tokio_proto::streaming::pipeline::{Frame, ServerProto, Transport};
 use tokio_proto::streaming::{Body, Message};
 use tokio_service::Service;
+use tracelog::{TraceLog, TraceRecord};
 
 use errors::*;
 
 /// If the server is idle for this many milliseconds, shut down.
 const DEFAULT_IDLE_TIMEOUT: u64 = 600_000;
 
+/// Save stats to the stats file this often, in seconds.
+const STATS_SAVE_INTERVAL: u64 = 60;
+
+/// Rotate the compile trace log once it grows past this many bytes.
+const DEFAULT_TRACE_LOG_SIZE: u64 = 10 * 1024 * 1024;
+
+/// Get the path of a file next to the disk cache, named after it with
+/// `suffix` appended.
+fn cache_sibling(config: &Config, suffix: &str) -> PathBuf {
+    let dir = disk_cache_dir(config);
+    let mut name = dir.file_name().map(|n| n.to_owned()).unwrap_or_else(|| "sccache".into());
+    name.push(suffix);
+    dir.with_file_name(name)
+}
+
+/// Get the path of the stats file, which lives next to the disk cache.
+fn stats_file(config: &Config) -> PathBuf {
+    cache_sibling(config, ".stats")
+}
+
 fn notify_server_startup_internal<W: Write>(mut w: W, success: bool) -> io::Result<()> {
     let data = [ if success { 0 } else { 1 }; 1];
     try!(w.write_all(&data));
@@ -108,18 +155,90 @@ fn notify_server_startup(name: &Option<OsString>, success: bool) -> io::Result<(
     notify_server_startup_internal(pipe, success)
 }
 
-/// Start an sccache server, listening on `port`.
+/// The versions of this sccache binary and its cache key format, which
+/// clients and servers exchange to make sure they match.
+pub fn hello() -> Hello {
+    let mut hello = Hello::new();
+    hello.set_version(env!("CARGO_PKG_VERSION").to_owned());
+    hello.set_cache_version(String::from_utf8_lossy(CACHE_VERSION).into_owned());
+    hello
+}
+
+/// Whether the sccache binaries that sent `a` and `b` can work together.
+pub fn same_version(a: &Hello, b: &Hello) -> bool {
+    a.get_version() == b.get_version() && a.get_cache_version() == b.get_cache_version()
+}
+
+/// Start an sccache server with the settings in `config`, listening on `addr`.
 ///
 /// Spins an event loop handling client connections until a client
 /// requests a shutdown.
-pub fn start_server(port: u16) -> Result<()> {
+pub fn start_server(config: &Config, addr: &ServerAddress) -> Result<()> {
     let core = Core::new()?;
     let pool = CpuPool::new(20);
-    let storage = storage_from_environment(&pool, &core.handle());
-    let res = SccacheServer::<ProcessCommandCreator>::new(port, pool, core, storage);
+    let storage = storage_from_config(config, &pool, &core.handle());
+    let stats_file = Some(stats_file(config));
+    let res = match *addr {
+        ServerAddress::Tcp(port) => {
+            SccacheServer::<ProcessCommandCreator>::new(port, pool, core, storage, stats_file)
+        }
+        #[cfg(unix)]
+        ServerAddress::Unix(ref path) => {
+            SccacheServer::<ProcessCommandCreator>::new_unix(path, pool, core, storage, stats_file)
+        }
+    };
     let notify = env::var_os("SCCACHE_STARTUP_NOTIFY");
     match res {
-        Ok(srv) => {
+        Ok(mut srv) => {
+            srv.set_force_recache(config.is_set("SCCACHE_RECACHE"));
+            srv.set_direct_mode(config.is_set("SCCACHE_DIRECT"));
+            srv.set_cache_mode(cache_mode_from_config(config));
+            srv.set_base_dir(config.get("SCCACHE_BASEDIR").map(PathBuf::from).and_then(|p| {
+                if p.is_absolute() {
+                    Some(p)
+                } else {
+                    warn!("Ignoring SCCACHE_BASEDIR {:?}, it is not an absolute path", p);
+                    None
+                }
+            }));
+            srv.set_max_jobs(config.get("SCCACHE_MAX_JOBS").and_then(|n| {
+                match n.parse() {
+                    Ok(0) => None,
+                    Ok(n) => Some(n),
+                    Err(_) => {
+                        warn!("Ignoring SCCACHE_MAX_JOBS {:?}, it is not a number", n);
+                        None
+                    }
+                }
+            }));
+            if config.is_set("SCCACHE_EXPLAIN_MISSES") {
+                let dir = cache_sibling(config, ".explain");
+                info!("Explaining cache misses in {:?}", dir.join(MISSES_LOG));
+                srv.set_explain_misses(Some(dir));
+            }
+            srv.set_timeline(config.get("SCCACHE_TIMELINE").map(PathBuf::from).and_then(|p| {
+                if p.is_absolute() {
+                    Some(p)
+                } else {
+                    warn!("Ignoring SCCACHE_TIMELINE {:?}, it is not an absolute path", p);
+                    None
+                }
+            }));
+            if let Some(path) = config.get("SCCACHE_TRACE_LOG") {
+                let max_size = config.get("SCCACHE_TRACE_LOG_SIZE")
+                    .and_then(|s| parse_size(&s))
+                    .map_or(DEFAULT_TRACE_LOG_SIZE, |s| s as u64);
+                match TraceLog::open(Path::new(&path), max_size) {
+                    Ok(log) => srv.set_trace_log(Some(log)),
+                    Err(e) => warn!("Failed to open trace log {:?}: {}", path, e),
+                }
+            }
+            if let Some(addr) = config.get("SCCACHE_METRICS_ADDR") {
+                match addr.parse() {
+                    Ok(addr) => srv.listen_metrics(&addr)?,
+                    Err(_) => warn!("Ignoring SCCACHE_METRICS_ADDR {:?}, it is not an address", addr),
+                }
+            }
             notify_server_startup(&notify, true)?;
             srv.run(future::empty::<(), ()>())?;
             Ok(())
@@ -131,9 +250,19 @@ pub fn start_server(port: u16) -> Result<()> {
     }
 }
 
+/// The socket a server listens for connections on.
+enum Listener {
+    Tcp(TcpListener),
+    /// A Unix domain socket, and its path.
+    #[cfg(unix)]
+    Unix(UnixListener, PathBuf),
+}
+
 pub struct SccacheServer<C: CommandCreatorSync> {
     core: Core,
-    listener: TcpListener,
+    listener: Listener,
+    /// The listener for HTTP requests for metrics, if any.
+    metrics: Option<TcpListener>,
     rx: mpsc::Receiver<ServerMessage>,
     timeout: Duration,
     service: SccacheService<C>,
@@ -144,20 +273,63 @@ impl<C: CommandCreatorSync> SccacheServer<C> {
     pub fn new(port: u16,
                pool: CpuPool,
                core: Core,
-               storage: Arc<Storage>) -> Result<SccacheServer<C>> {
-        let handle = core.handle();
+               storage: Arc<Storage>,
+               stats_file: Option<PathBuf>) -> Result<SccacheServer<C>> {
         let addr = SocketAddrV4::new(Ipv4Addr::new(127, 0, 0, 1), port);
-        let listener = TcpListener::bind(&SocketAddr::V4(addr), &handle)?;
+        let listener = TcpListener::bind(&SocketAddr::V4(addr), &core.handle())?;
+        SccacheServer::with_listener(Listener::Tcp(listener), pool, core, storage, stats_file)
+    }
 
+    /// Create a server listening on the Unix socket `path`, which only the
+    /// current user can connect to.
+    #[cfg(unix)]
+    pub fn new_unix(path: &Path,
+                    pool: CpuPool,
+                    core: Core,
+                    storage: Arc<Storage>,
+                    stats_file: Option<PathBuf>) -> Result<SccacheServer<C>> {
+        use libc;
+        use std::os::unix::fs::PermissionsExt;
+        use std::os::unix::net::UnixStream;
+
+        // A server that went away without cleaning up leaves its socket
+        // behind, which would keep us from binding.
+        if path.exists() {
+            if UnixStream::connect(path).is_ok() {
+                bail!(io::Error::new(io::ErrorKind::AddrInUse,
+                                     format!("a server is already listening on {}", path.display())))
+            }
+            fs::remove_file(path)?;
+        }
+        // Create the socket without access for anyone else, rather than
+        // restricting it afterwards, when they might already have connected.
+        let umask = unsafe { libc::umask(0o077) };
+        let listener = UnixListener::bind(path, &core.handle());
+        unsafe { libc::umask(umask) };
+        let listener = listener?;
+        fs::set_permissions(path, fs::Permissions::from_mode(0o600))?;
+        SccacheServer::with_listener(Listener::Unix(listener, path.to_owned()),
+                                     pool,
+                                     core,
+                                     storage,
+                                     stats_file)
+    }
+
+    fn with_listener(listener: Listener,
+                     pool: CpuPool,
+                     core: Core,
+                     storage: Arc<Storage>,
+                     stats_file: Option<PathBuf>) -> Result<SccacheServer<C>> {
         // Prepare the service which we'll use to service all incoming TCP
         // connections.
         let (tx, rx) = mpsc::channel(1);
         let (wait, info) = WaitUntilZero::new();
-        let service = SccacheService::new(storage, core.handle(), pool, tx, info);
+        let service = SccacheService::new(storage, stats_file, core.handle(), pool, tx, info);
 
         Ok(SccacheServer {
             core: core,
             listener: listener,
+            metrics: None,
             rx: rx,
             service: service,
             timeout: Duration::from_millis(DEFAULT_IDLE_TIMEOUT),
@@ -172,11 +344,69 @@ impl<C: CommandCreatorSync> SccacheServer<C> {
     }
 
     /// Set the `force_recache` setting.
-    #[allow(dead_code)]
     pub fn set_force_recache(&mut self, force_recache: bool) {
         self.service.force_recache = force_recache;
     }
 
+    /// Set the `direct_mode` setting.
+    pub fn set_direct_mode(&mut self, direct_mode: bool) {
+        self.service.context.direct_mode = if direct_mode {
+            DirectMode::Enabled
+        } else {
+            DirectMode::Disabled
+        };
+    }
+
+    /// Set the `cache_mode` setting.
+    pub fn set_cache_mode(&mut self, cache_mode: CacheMode) {
+        self.service.context.cache_mode = cache_mode;
+    }
+
+    /// Set the `base_dir` setting.
+    pub fn set_base_dir(&mut self, base_dir: Option<PathBuf>) {
+        self.service.context.base_dir = base_dir;
+    }
+
+    /// Record the inputs to hash keys in `dir` and explain cache misses
+    /// there, or don't if `None`.
+    pub fn set_explain_misses(&mut self, dir: Option<PathBuf>) {
+        self.service.context.explainer = dir.map(MissExplainer::new);
+    }
+
+    /// Write every compile request to `trace_log`, or don't if `None`.
+    pub fn set_trace_log(&mut self, trace_log: Option<TraceLog>) {
+        self.service.trace_log = trace_log;
+    }
+
+    /// Record a timeline of the build, to be written to `path` on request
+    /// and when the server shuts down, or don't if `None`.
+    pub fn set_timeline(&mut self, path: Option<PathBuf>) {
+        self.service.context.timeline = if path.is_some() {
+            Timeline::new()
+        } else {
+            Timeline::disabled()
+        };
+        self.service.timeline_file = path;
+    }
+
+    /// Set the most compiler processes to run at once, or `None` for no
+    /// limit.
+    pub fn set_max_jobs(&mut self, max_jobs: Option<usize>) {
+        self.service.context.jobs.set_limit(max_jobs);
+    }
+
+    /// Serve metrics in the Prometheus format over HTTP on `addr`.
+    ///
+    /// Metrics requests don't keep the server from shutting down when idle.
+    pub fn listen_metrics(&mut self, addr: &SocketAddr) -> Result<()> {
+        let listener = TcpListener::bind(addr, &self.core.handle()).chain_err(|| {
+            format!("failed to listen for metrics requests on {}", addr)
+        })?;
+        info!("Serving metrics on http://{}/metrics", addr);
+        self.metrics = Some(listener);
+        Ok(())
+    }
+
     /// Set the storage this server will use.
     #[allow(dead_code)]
     pub fn set_storage(&mut self, storage: Arc<Storage>) {
@@ -195,10 +425,15 @@ impl<C: CommandCreatorSync> SccacheServer<C> {
         &self.service.creator
     }
 
-    /// Returns the port that this server is bound to
+    /// Returns the port that this server is bound to, or 0 if it's
+    /// listening on a Unix socket.
     #[allow(dead_code)]
     pub fn port(&self) -> u16 {
-        self.listener.local_addr().unwrap().port()
+        match self.listener {
+            Listener::Tcp(ref listener) => listener.local_addr().unwrap().port(),
+            #[cfg(unix)]
+            Listener::Unix(..) => 0,
+        }
     }
 
     /// Runs this server to completion.
@@ -215,15 +450,55 @@ impl<C: CommandCreatorSync> SccacheServer<C> {
     fn _run<'a>(self, shutdown: Box<Future<Item = (), Error = ()> + 'a>)
                 -> io::Result<()>
     {
-        let SccacheServer { mut core, listener, rx, service, timeout, wait } = self;
+        let SccacheServer { mut core, listener, metrics, rx, service, timeout, wait } = self;
+
+        // Periodically save stats, so they're not all lost if the server
+        // doesn't get to shut down cleanly. This only holds on to the stats,
+        // not the service, so it doesn't keep the server from shutting down.
+        let handle = core.handle();
+        let stats = service.stats.clone();
+        let stats_file = service.stats_file.clone();
+        if let Some(ref path) = stats_file {
+            let stats = stats.clone();
+            let path = path.clone();
+            let save = Interval::new(Duration::from_secs(STATS_SAVE_INTERVAL), &handle)?;
+            handle.spawn(save.for_each(move |()| {
+                save_stats(&stats.borrow(), &path);
+                Ok(())
+            }).map_err(|e| warn!("Failed to schedule saving stats: {}", e)));
+        }
+
+        // Like saving stats, serving metrics doesn't hold on to the service.
+        if let Some(listener) = metrics {
+            let stats = stats.clone();
+            let storage = service.storage.clone();
+            let render = Rc::new(move || stats.borrow().to_metrics(&*storage));
+            handle.spawn(metrics::serve(listener, &handle, render));
+        }
 
         // Create our "server future" which will simply handle all incoming
         // connections in separate tasks.
         let handle = core.handle();
-        let server = listener.incoming().for_each(move |(socket, _addr)| {
-            SccacheProto.bind_server(&handle, socket, service.clone());
-            Ok(())
-        });
+        let socket_path: Option<PathBuf> = match listener {
+            #[cfg(unix)]
+            Listener::Unix(_, ref path) => Some(path.clone()),
+            _ => None,
+        };
+        let server = match listener {
+            Listener::Tcp(listener) => {
+                Box::new(listener.incoming().for_each(move |(socket, _addr)| {
+                    SccacheProto.bind_server(&handle, socket, service.clone());
+                    Ok(())
+                })) as Box<Future<Item=_, Error=_>>
+            }
+            #[cfg(unix)]
+            Listener::Unix(listener, _) => {
+                Box::new(listener.incoming().for_each(move |(socket, _addr)| {
+                    SccacheProto.bind_server(&handle, socket, service.clone());
+                    Ok(())
+                }))
+            }
+        };
 
         // Right now there's a whole bunch of ways to shut down this server for
         // various purposes. These include:
@@ -254,7 +529,7 @@ impl<C: CommandCreatorSync> SccacheServer<C> {
         });
 
         let server = future::select_all(vec![
-            Box::new(server) as Box<Future<Item=_, Error=_>>,
+            server,
             Box::new(shutdown_idle),
             Box::new(shutdown.map_err(|()| {
                 io::Error::new(io::ErrorKind::Other, "shutdown signal failed")
@@ -276,6 +551,13 @@ impl<C: CommandCreatorSync> SccacheServer<C> {
         core.run(wait.select(Timeout::new(Duration::new(10, 0), &handle)?))
             .map_err(|p| p.0)?;
 
+        if let Some(ref path) = stats_file {
+            save_stats(&stats.borrow(), path);
+        }
+        if let Some(ref path) = socket_path {
+            drop(fs::remove_file(path));
+        }
+
         info!("ok, fully shutting down now");
 
         Ok(())
@@ -288,6 +570,9 @@ struct SccacheService<C: CommandCreatorSync> {
     /// Server statistics.
     stats: Rc<RefCell<ServerStats>>,
 
+    /// The file stats are saved to, if any.
+    stats_file: Option<PathBuf>,
+
     /// Cache storage.
     storage: Arc<Storage>,
 
@@ -296,9 +581,26 @@ struct SccacheService<C: CommandCreatorSync> {
 
     /// True if all compiles should be forced, ignoring existing cache entries.
     ///
-    /// This can be controlled with the `SCCACHE_RECACHE` environment variable.
+    /// This can be controlled with the `SCCACHE_RECACHE` setting.
     force_recache: bool,
 
+    /// Settings and shared state for every compile.
+    ///
+    /// These can be controlled with the `SCCACHE_DIRECT`,
+    /// `SCCACHE_CACHE_MODE`, `SCCACHE_BASEDIR`, `SCCACHE_MAX_JOBS`,
+    /// `SCCACHE_EXPLAIN_MISSES` and `SCCACHE_TIMELINE` settings.
+    context: CompileContext,
+
+    /// A log of every compile request, if enabled.
+    ///
+    /// This can be controlled with the `SCCACHE_TRACE_LOG` setting.
+    trace_log: Option<TraceLog>,
+
+    /// Where to write `timeline`, if it's being recorded.
+    ///
+    /// This can be controlled with the `SCCACHE_TIMELINE` setting.
+    timeline_file: Option<PathBuf>,
+
     /// Thread pool to execute work in
     pool: CpuPool,
 
@@ -361,14 +663,23 @@ impl<C> Service for SccacheService<C>
         } else {
             // Simple requests that can generate responses right away.
             let mut res = ServerResponse::new();
-            if req.has_get_stats() {
+            if req.has_hello() {
+                let client = req.take_hello();
+                debug!("handle_client: hello from client version {} (cache version {})",
+                       client.get_version(), client.get_cache_version());
+                res.set_hello(hello());
+            } else if req.has_get_stats() {
                 debug!("handle_client: get_stats");
                 res.set_stats(self.get_stats());
             } else if req.has_zero_stats() {
                 debug!("handle_client: zero_stats");
                 res.set_stats(self.zero_stats());
+            } else if req.has_dump_trace() {
+                debug!("handle_client: dump_trace");
+                res.set_trace_dumped(self.dump_trace());
             } else if req.has_shutdown() {
                 debug!("handle_client: shutdown");
+                self.dump_trace();
                 let future = self.tx.clone().send(ServerMessage::Shutdown);
                 let me = self.clone();
                 return Box::new(future.then(move |_| {
@@ -391,15 +702,31 @@ impl<C> SccacheService<C>
     where C: CommandCreatorSync,
 {
     pub fn new(storage: Arc<Storage>,
+               stats_file: Option<PathBuf>,
                handle: Handle,
                pool: CpuPool,
                tx: mpsc::Sender<ServerMessage>,
                info: ActiveInfo) -> SccacheService<C> {
+        // Pick up where the last server left off.
+        let stats = stats_file.as_ref().and_then(|path| {
+            match ServerStats::load(path) {
+                Ok(stats) => Some(stats),
+                Err(ref e) if e.kind() == io::ErrorKind::NotFound => None,
+                Err(e) => {
+                    warn!("Failed to load stats from {:?}: {}", path, e);
+                    None
+                }
+            }
+        }).unwrap_or_default();
         SccacheService {
-            stats: Rc::new(RefCell::new(ServerStats::default())),
+            stats: Rc::new(RefCell::new(stats)),
+            stats_file: stats_file,
             storage: storage,
             compilers: Rc::new(RefCell::new(HashMap::new())),
-            force_recache: env::var("SCCACHE_RECACHE").is_ok(),
+            force_recache: false,
+            context: CompileContext::default(),
+            trace_log: None,
+            timeline_file: None,
             pool: pool,
             creator: C::new(&handle),
             handle: handle,
@@ -428,13 +755,81 @@ impl<C> SccacheService<C>
             });
         }
 
+        for (name, count) in self.storage.tier_hits() {
+            let mut stat = CacheStatistic::new();
+            stat.set_name(String::from(name));
+            stat.set_count(count);
+            stats_vec.push(stat);
+        }
+
+        let jobs = self.context.jobs.stats();
+        for &(name, count) in [("Compile jobs queued", jobs.queued as u64),
+                               ("Max compile jobs queued", jobs.max_queued as u64),
+                               ("Compile jobs that waited", jobs.waited)].iter() {
+            let mut stat = CacheStatistic::new();
+            stat.set_name(String::from(name));
+            stat.set_count(count);
+            stats_vec.push(stat);
+        }
+        let mut stat = CacheStatistic::new();
+        stat.set_name(String::from("Average compile queue wait"));
+        let wait = if jobs.waited > 0 {
+            jobs.wait_duration / jobs.waited as u32
+        } else {
+            Duration::new(0, 0)
+        };
+        stat.set_duration(duration_nanos(wait));
+        stats_vec.push(stat);
+
+        for &op in Operation::all() {
+            let latencies = self.context.latencies.histogram(op);
+            let mut histogram = Histogram::new();
+            histogram.set_count(latencies.count());
+            histogram.set_p50(duration_nanos(latencies.percentile(0.5)));
+            histogram.set_p90(duration_nanos(latencies.percentile(0.9)));
+            histogram.set_p99(duration_nanos(latencies.percentile(0.99)));
+            let mut stat = CacheStatistic::new();
+            stat.set_name(format!("{} latency", op.name()));
+            stat.set_histogram(histogram);
+            stats_vec.push(stat);
+        }
+
         stats.set_stats(RepeatedField::from_vec(stats_vec));
+        stats.set_breakdowns(RepeatedField::from_vec(self.stats.borrow().breakdowns_to_cache_statistics()));
         stats
     }
 
+    /// Write the timeline of the build so far to the timeline file, if
+    /// there is one.
+    fn dump_trace(&self) -> TraceDumped {
+        let mut dumped = TraceDumped::new();
+        if let Some(ref path) = self.timeline_file {
+            let trace = self.context.timeline.to_trace_events();
+            let res = serde_json::to_string(&trace)
+                .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))
+                .and_then(|json| File::create(path)?.write_all(json.as_bytes()));
+            match res {
+                Ok(()) => {
+                    info!("Wrote build timeline to {:?}", path);
+                    dumped.set_path(path.to_string_lossy().into_owned());
+                }
+                Err(e) => {
+                    warn!("Failed to write build timeline to {:?}: {}", path, e);
+                    dumped.set_error(format!("failed to write {}: {}", path.display(), e));
+                }
+            }
+        }
+        dumped
+    }
+
     /// Zero and return stats about the cache.
     fn zero_stats(&self) -> CacheStats {
         *self.stats.borrow_mut() = ServerStats::default();
+        self.context.jobs.zero_stats();
+        self.context.latencies.zero();
+        if let Some(ref path) = self.stats_file {
+            save_stats(&self.stats.borrow(), path);
+        }
         self.get_stats()
     }
 
@@ -447,12 +842,28 @@ impl<C> SccacheService<C>
     fn handle_compile(&self, mut compile: Compile)
                       -> SFuture<SccacheResponse>
     {
+        // A client of a different version may not agree on the cache keys,
+        // so it has to restart the server first.
+        if compile.has_hello() && !same_version(compile.get_hello(), &hello()) {
+            debug!("handle_compile: client is version {}", compile.get_hello().get_version());
+            let mut res = ServerResponse::new();
+            res.set_hello(hello());
+            return Box::new(future::ok(Message::WithoutBody(res)))
+        }
         let exe = compile.take_exe();
         let cmd = compile.take_command().into_vec();
         let cwd = compile.take_cwd();
+        // The client lends its token to this jobserver if the compile has
+        // to wait for one.
+        let jobserver = if compile.has_makeflags() {
+            Jobserver::for_client(compile.get_client_pid(), compile.get_makeflags())
+        } else {
+            None
+        };
         let me = self.clone();
+        let detect_start = Instant::now();
         Box::new(self.compiler_info(&exe).map(move |info| {
-            me.check_compiler(info, cmd, cwd)
+            me.check_compiler(info, cmd, cwd, jobserver, detect_start)
         }))
     }
 
@@ -497,7 +908,9 @@ impl<C> SccacheService<C>
     fn check_compiler(&self,
                       compiler: Option<Compiler>,
                       cmd: Vec<String>,
-                      cwd: String)
+                      cwd: String,
+                      jobserver: Option<Jobserver>,
+                      detect_start: Instant)
                       -> SccacheResponse {
         let mut res = ServerResponse::new();
         let mut stats = self.stats.borrow_mut();
@@ -513,9 +926,13 @@ impl<C> SccacheService<C>
                 match c.parse_arguments(&cmd, cwd.as_ref()) {
                     CompilerArguments::Ok(args) => {
                         stats.requests_executed += 1;
+                        self.context.timeline.record(Phase::DetectCompiler,
+                                             &args.output_file(),
+                                             detect_start,
+                                             Instant::now());
                         res.set_compile_started(CompileStarted::new());
                         let (tx, rx) = Body::pair();
-                        self.start_compile_task(c, args, cmd, cwd, tx);
+                        self.start_compile_task(c, args, cmd, cwd, jobserver, tx);
                         return Message::WithBody(res, rx)
                     }
                     CompilerArguments::CannotCache => {
@@ -538,35 +955,78 @@ impl<C> SccacheService<C>
                           parsed_arguments: ParsedArguments,
                           arguments: Vec<String>,
                           cwd: String,
+                          jobserver: Option<Jobserver>,
                           tx: mpsc::Sender<Result<ServerResponse>>) {
         let cache_control = if self.force_recache {
             CacheControl::ForceRecache
         } else {
             CacheControl::Default
         };
+        // Tell the client when the compile waits for a jobserver token, so
+        // it can lend its own.
+        let jobserver = jobserver.map(|jobserver| {
+            let tx = tx.clone();
+            let handle = self.handle.clone();
+            jobserver.on_acquire(move || {
+                let mut res = ServerResponse::new();
+                res.set_compile_queued(CompileQueued::new());
+                handle.spawn(tx.clone().send(Ok(res)).map(|_| ()).map_err(|_| ()));
+            })
+        });
+        let timestamp = UTC::now();
+        let start = Instant::now();
         let result = compiler.get_cached_or_compile(&self.creator,
                                                     &self.storage,
                                                     &arguments,
                                                     &parsed_arguments,
                                                     &cwd,
                                                     cache_control,
+                                                    jobserver.as_ref(),
+                                                    &self.context,
                                                     &self.pool,
                                                     &self.handle);
+        let compiler_name = compiler.kind.name();
+        let extension = parsed_arguments.extension.clone();
+        let compiler_path = compiler.executable.clone();
         let me = self.clone();
         let task = result.then(move |result| {
+            let trace = me.trace_log.as_ref().map(|_| {
+                let (key, (name, miss_type, read_duration)) = match result {
+                    Ok((ref compiled, _, ref key)) => (key.clone(), trace_result(compiled)),
+                    Err(_) => (None, ("Error", None, None)),
+                };
+                TraceRecord {
+                    timestamp: timestamp,
+                    cwd: cwd,
+                    compiler: compiler_path,
+                    output_file: parsed_arguments.output_file().into_owned(),
+                    key: key,
+                    result: name,
+                    miss_type: miss_type,
+                    duration: start.elapsed(),
+                    cache_read_duration: read_duration,
+                    cache_write_duration: None,
+                }
+            });
             let mut res = ServerResponse::new();
             let mut finish = CompileFinished::new();
             let mut cache_write = None;
             let mut stats = me.stats.borrow_mut();
             match result {
-                Ok((compiled, out)) => {
+                Ok((compiled, out, _)) => {
                     match compiled {
                         CompileResult::Error => {
                             stats.cache_errors += 1;
+                            stats.record(compiler_name, &extension, |s| s.cache_errors += 1);
                         }
                         CompileResult::CacheHit(duration) => {
                             stats.cache_hits += 1;
                             stats.cache_read_hit_duration += duration;
+                            me.context.latencies.record(Operation::CacheReadHit, duration);
+                            stats.record(compiler_name, &extension, |s| {
+                                s.cache_hits += 1;
+                                s.cache_read_hit_duration += duration;
+                            });
                         },
                         CompileResult::CacheMiss(miss_type, duration, future) => {
                             match miss_type {
@@ -582,14 +1042,27 @@ impl<C> SccacheService<C>
                                 }
                             }
                             stats.cache_read_miss_duration += duration;
-                            cache_write = Some(future);
+                            me.context.latencies.record(Operation::CacheReadMiss, duration);
+                            stats.record(compiler_name, &extension, |s| {
+                                s.cache_misses += 1;
+                                s.cache_read_miss_duration += duration;
+                            });
+                            match future {
+                                Some(future) => cache_write = Some(future),
+                                None => stats.cache_writes_skipped += 1,
+                            }
                         }
                         CompileResult::NotCacheable => {
                             stats.cache_misses += 1;
+                            stats.record(compiler_name, &extension, |s| s.cache_misses += 1);
                             stats.non_cacheable_compilations += 1;
                         }
                         CompileResult::CompileFailed => {
                             stats.compile_fails += 1;
+                            stats.record(compiler_name, &extension, |s| s.compile_fails += 1);
+                        }
+                        CompileResult::Coalesced => {
+                            stats.coalesced_compiles += 1;
                         }
                     };
                     let Output { status, stdout, stderr } = out;
@@ -609,6 +1082,7 @@ impl<C> SccacheService<C>
                         error!("[{:?}] \t{}", e, parsed_arguments.output_file());
                     }
                     stats.cache_errors += 1;
+                    stats.record(compiler_name, &extension, |s| s.cache_errors += 1);
                     //TODO: figure out a better way to communicate this?
                     finish.set_retcode(-2);
                 }
@@ -618,19 +1092,35 @@ impl<C> SccacheService<C>
 
             let me = me.clone();
             let cache_write = cache_write.then(move |result| {
-                match result {
+                let write_duration = match result {
                     Err(e) => {
                         debug!("Error executing cache write: {}", e);
                         me.stats.borrow_mut().cache_write_errors += 1;
+                        None
                     }
                     //TODO: save cache stats!
                     Ok(Some(info)) => {
                         debug!("[{}]: Cache write finished in {}.{:03}s", info.object_file, info.duration.as_secs(), info.duration.subsec_nanos() / 1000_000);
-                        me.stats.borrow_mut().cache_writes += 1;
-                        me.stats.borrow_mut().cache_write_duration += info.duration;
+                        let mut stats = me.stats.borrow_mut();
+                        stats.cache_writes += 1;
+                        stats.cache_write_duration += info.duration;
+                        stats.cache_write_bytes += info.size;
+                        stats.cache_write_stored_bytes += info.stored_size;
+                        me.context.latencies.record(Operation::CacheWrite, info.duration);
+                        stats.record(compiler_name, &extension, |s| {
+                            s.cache_writes += 1;
+                            s.cache_write_duration += info.duration;
+                        });
+                        Some(info.duration)
                     }
 
-                    Ok(None) => {}
+                    Ok(None) => None,
+                };
+                if let (Some(log), Some(mut trace)) = (me.trace_log.as_ref(), trace) {
+                    trace.cache_write_duration = write_duration;
+                    if let Err(e) = log.write(&trace) {
+                        warn!("Failed to write to trace log: {}", e);
+                    }
                 }
                 Ok(())
             });
@@ -642,7 +1132,68 @@ impl<C> SccacheService<C>
     }
 }
 
+/// The `CompileResult` variant and `MissType` of `result`, and how long
+/// its cache lookup took, for the trace log.
+fn trace_result(result: &CompileResult) -> (&'static str, Option<&'static str>, Option<Duration>) {
+    match *result {
+        CompileResult::Error => ("Error", None, None),
+        CompileResult::CacheHit(duration) => ("CacheHit", None, Some(duration)),
+        CompileResult::CacheMiss(ref miss_type, duration, _) => {
+            let miss_type = match *miss_type {
+                MissType::Normal => "Normal",
+                MissType::ForcedRecache => "ForcedRecache",
+                MissType::TimedOut => "TimedOut",
+            };
+            ("CacheMiss", Some(miss_type), Some(duration))
+        }
+        CompileResult::NotCacheable => ("NotCacheable", None, None),
+        CompileResult::CompileFailed => ("CompileFailed", None, None),
+        CompileResult::Coalesced => ("Coalesced", None, None),
+    }
+}
+
+/// Save `stats` to `path`, logging any failure.
+fn save_stats(stats: &ServerStats, path: &Path) {
+    trace!("Saving stats to {:?}", path);
+    if let Err(e) = stats.save(path) {
+        warn!("Failed to save stats to {:?}: {}", path, e);
+    }
+}
+
+/// `duration` in nanoseconds.
+fn duration_nanos(duration: Duration) -> u64 {
+    duration.as_secs() * 1_000_000_000 + duration.subsec_nanos() as u64
+}
+
+/// A duration of `nanos` nanoseconds.
+fn nanos_duration(nanos: u64) -> Duration {
+    Duration::new(nanos / 1_000_000_000, (nanos % 1_000_000_000) as u32)
+}
+
+macro_rules! set_stat {
+    ($vec:ident, $var:expr, $name:expr) => {{
+        let mut stat = CacheStatistic::new();
+        stat.set_name(String::from($name));
+        stat.set_count($var);
+        $vec.push(stat);
+    }};
+}
+
+macro_rules! set_duration_stat {
+    ($vec:ident, $dur:expr, $num:expr, $name:expr) => {{
+        let mut stat = CacheStatistic::new();
+        stat.set_name(String::from($name));
+        if $num > 0 {
+            stat.set_duration(duration_nanos($dur / $num as u32));
+        } else {
+            stat.set_duration(0);
+        }
+        $vec.push(stat);
+    }};
+}
+
 /// Statistics about the cache.
+#[derive(Clone)]
 struct ServerStats {
     /// The count of client compile requests.
     pub compile_requests: u64,
@@ -668,6 +1219,12 @@ struct ServerStats {
     pub cache_write_errors: u64,
     /// The number of successful cache writes.
     pub cache_writes: u64,
+    /// The count of cache writes skipped because the cache is read-only.
+    pub cache_writes_skipped: u64,
+    /// The total size of the cache entries written, before compression.
+    pub cache_write_bytes: u64,
+    /// The total size of the cache entries written, as they were stored.
+    pub cache_write_stored_bytes: u64,
     /// The total time spent writing cache entries.
     pub cache_write_duration: Duration,
     /// The total time spent reading cache hits.
@@ -676,6 +1233,80 @@ struct ServerStats {
     pub cache_read_miss_duration: Duration,
     /// The count of compilation failures.
     pub compile_fails: u64,
+    /// The count of compilations that used the result of an identical
+    /// compilation that was already running.
+    pub coalesced_compiles: u64,
+    /// The stats for each kind of compiler, by name.
+    pub compilers: HashMap<String, BreakdownStats>,
+    /// The stats for each source file extension.
+    pub extensions: HashMap<String, BreakdownStats>,
+}
+
+/// Statistics about the compile requests for one kind of compiler, or one
+/// source file extension.
+#[derive(Clone)]
+struct BreakdownStats {
+    /// The count of errors handling compile requests.
+    pub cache_errors: u64,
+    /// The count of cache hits.
+    pub cache_hits: u64,
+    /// The count of cache misses.
+    pub cache_misses: u64,
+    /// The count of compilation failures.
+    pub compile_fails: u64,
+    /// The number of successful cache writes.
+    pub cache_writes: u64,
+    /// The total time spent writing cache entries.
+    pub cache_write_duration: Duration,
+    /// The total time spent reading cache hits.
+    pub cache_read_hit_duration: Duration,
+    /// The total time spent reading cache misses.
+    pub cache_read_miss_duration: Duration,
+}
+
+impl Default for BreakdownStats {
+    fn default() -> BreakdownStats {
+        BreakdownStats {
+            cache_errors: u64::default(),
+            cache_hits: u64::default(),
+            cache_misses: u64::default(),
+            compile_fails: u64::default(),
+            cache_writes: u64::default(),
+            cache_write_duration: Duration::new(0, 0),
+            cache_read_hit_duration: Duration::new(0, 0),
+            cache_read_miss_duration: Duration::new(0, 0),
+        }
+    }
+}
+
+impl BreakdownStats {
+    /// The counters in these stats, by name.
+    fn counters_mut(&mut self) -> Vec<(&'static str, &mut u64)> {
+        vec!(("cache_errors", &mut self.cache_errors),
+             ("cache_hits", &mut self.cache_hits),
+             ("cache_misses", &mut self.cache_misses),
+             ("compile_fails", &mut self.compile_fails),
+             ("cache_writes", &mut self.cache_writes))
+    }
+
+    /// The durations in these stats, by name.
+    fn durations_mut(&mut self) -> Vec<(&'static str, &mut Duration)> {
+        vec!(("cache_write_duration", &mut self.cache_write_duration),
+             ("cache_read_hit_duration", &mut self.cache_read_hit_duration),
+             ("cache_read_miss_duration", &mut self.cache_read_miss_duration))
+    }
+
+    fn to_cache_statistics(&self) -> Vec<CacheStatistic> {
+        let mut stats_vec = vec!();
+        set_stat!(stats_vec, self.cache_hits, "Cache hits");
+        set_stat!(stats_vec, self.cache_misses, "Cache misses");
+        set_stat!(stats_vec, self.compile_fails, "Compilation failures");
+        set_stat!(stats_vec, self.cache_errors, "Cache errors");
+        set_duration_stat!(stats_vec, self.cache_write_duration, self.cache_writes, "Average cache write");
+        set_duration_stat!(stats_vec, self.cache_read_miss_duration, self.cache_misses, "Average cache read miss");
+        set_duration_stat!(stats_vec, self.cache_read_hit_duration, self.cache_hits, "Average cache read hit");
+        stats_vec
+    }
 }
 
 impl Default for ServerStats {
@@ -693,46 +1324,245 @@ impl Default for ServerStats {
             forced_recaches: u64::default(),
             cache_write_errors: u64::default(),
             cache_writes: u64::default(),
+            cache_writes_skipped: u64::default(),
+            cache_write_bytes: u64::default(),
+            cache_write_stored_bytes: u64::default(),
             cache_write_duration: Duration::new(0, 0),
             cache_read_hit_duration: Duration::new(0, 0),
             cache_read_miss_duration: Duration::new(0, 0),
             compile_fails: u64::default(),
+            coalesced_compiles: u64::default(),
+            compilers: HashMap::new(),
+            extensions: HashMap::new(),
         }
     }
 }
 
 impl ServerStats {
-    fn to_cache_statistics(&self) -> Vec<CacheStatistic> {
-        macro_rules! set_stat {
-            ($vec:ident, $var:expr, $name:expr) => {{
-                let mut stat = CacheStatistic::new();
-                stat.set_name(String::from($name));
-                stat.set_count($var);
-                $vec.push(stat);
-            }};
+    /// Update the stats for the kind of compiler named `compiler` and for
+    /// the source file extension `extension` with `f`.
+    fn record<F>(&mut self, compiler: &str, extension: &str, f: F)
+        where F: Fn(&mut BreakdownStats)
+    {
+        f(self.compilers.entry(compiler.to_owned()).or_insert_with(BreakdownStats::default));
+        f(self.extensions.entry(extension.to_owned()).or_insert_with(BreakdownStats::default));
+    }
+
+    /// The stats for each kind of compiler and each source file extension,
+    /// sorted by what they're for.
+    fn breakdowns(&self) -> Vec<(&'static str, &str, &BreakdownStats)> {
+        let mut breakdowns = vec!();
+        for &(kind, map) in [("compiler", &self.compilers), ("extension", &self.extensions)].iter() {
+            let mut stats = map.iter().map(|(value, stats)| (kind, &value[..], stats)).collect::<Vec<_>>();
+            stats.sort_by_key(|&(_, value, _)| value);
+            breakdowns.extend(stats);
         }
+        breakdowns
+    }
 
-        macro_rules! set_duration_stat {
-            ($vec:ident, $dur:expr, $num:expr, $name:expr) => {{
-                let mut stat = CacheStatistic::new();
-                stat.set_name(String::from($name));
-                if $num > 0 {
-                    let duration = $dur / $num as u32;
-                    stat.set_str(format!("{}.{:03} s", duration.as_secs(), duration.subsec_nanos() / 1000_000));
-                } else {
-                    stat.set_str("0.000 s".to_owned());
+    /// The counters in these stats, by name.
+    fn counters_mut(&mut self) -> Vec<(&'static str, &mut u64)> {
+        vec!(("compile_requests", &mut self.compile_requests),
+             ("requests_unsupported_compiler", &mut self.requests_unsupported_compiler),
+             ("requests_not_compile", &mut self.requests_not_compile),
+             ("requests_not_cacheable", &mut self.requests_not_cacheable),
+             ("requests_executed", &mut self.requests_executed),
+             ("cache_errors", &mut self.cache_errors),
+             ("cache_hits", &mut self.cache_hits),
+             ("cache_misses", &mut self.cache_misses),
+             ("non_cacheable_compilations", &mut self.non_cacheable_compilations),
+             ("forced_recaches", &mut self.forced_recaches),
+             ("cache_write_errors", &mut self.cache_write_errors),
+             ("cache_writes", &mut self.cache_writes),
+             ("cache_writes_skipped", &mut self.cache_writes_skipped),
+             ("cache_write_bytes", &mut self.cache_write_bytes),
+             ("cache_write_stored_bytes", &mut self.cache_write_stored_bytes),
+             ("compile_fails", &mut self.compile_fails),
+             ("coalesced_compiles", &mut self.coalesced_compiles))
+    }
+
+    /// The durations in these stats, by name.
+    fn durations_mut(&mut self) -> Vec<(&'static str, &mut Duration)> {
+        vec!(("cache_write_duration", &mut self.cache_write_duration),
+             ("cache_read_hit_duration", &mut self.cache_read_hit_duration),
+             ("cache_read_miss_duration", &mut self.cache_read_miss_duration))
+    }
+
+    /// Write these stats to `path`, one `name value` line per statistic,
+    /// with durations in nanoseconds.
+    fn save(&self, path: &Path) -> io::Result<()> {
+        let mut stats = self.clone();
+        let mut contents = String::new();
+        for (name, value) in stats.counters_mut() {
+            contents.push_str(&format!("{} {}\n", name, value));
+        }
+        for (name, value) in stats.durations_mut() {
+            contents.push_str(&format!("{} {}\n", name, duration_nanos(*value)));
+        }
+        // Breakdowns are saved as `kind.value.name value` lines.
+        for (kind, map) in vec!(("compiler", &mut stats.compilers),
+                                ("extension", &mut stats.extensions)) {
+            for (value, breakdown) in map.iter_mut() {
+                if value.contains(char::is_whitespace) {
+                    continue
+                }
+                for (name, counter) in breakdown.counters_mut() {
+                    contents.push_str(&format!("{}.{}.{} {}\n", kind, value, name, counter));
+                }
+                for (name, duration) in breakdown.durations_mut() {
+                    contents.push_str(&format!("{}.{}.{} {}\n", kind, value, name, duration_nanos(*duration)));
+                }
+            }
+        }
+        // Write to a temporary file first so a crash doesn't leave a
+        // truncated stats file behind.
+        let mut tmp = path.as_os_str().to_owned();
+        tmp.push(".tmp");
+        File::create(&tmp)?.write_all(contents.as_bytes())?;
+        fs::rename(&tmp, path)
+    }
+
+    /// Read stats written by `save` from `path`.
+    ///
+    /// Unknown statistics are ignored, and missing ones are zero.
+    fn load(path: &Path) -> io::Result<ServerStats> {
+        let mut stats = ServerStats::default();
+        let mut values = HashMap::new();
+        for line in BufReader::new(File::open(path)?).lines() {
+            let line = line?;
+            let mut parts = line.splitn(2, ' ');
+            if let (Some(name), Some(value)) = (parts.next(), parts.next()) {
+                if let Ok(value) = value.parse::<u64>() {
+                    values.insert(name.to_owned(), value);
+                }
+            }
+        }
+        for (name, counter) in stats.counters_mut() {
+            *counter = values.get(name).cloned().unwrap_or(0);
+        }
+        for (name, duration) in stats.durations_mut() {
+            let nanos = values.get(name).cloned().unwrap_or(0);
+            *duration = nanos_duration(nanos);
+        }
+        for (name, &value) in &values {
+            let mut parts = name.splitn(2, '.');
+            let map = match parts.next() {
+                Some("compiler") => &mut stats.compilers,
+                Some("extension") => &mut stats.extensions,
+                _ => continue,
+            };
+            let mut parts = match parts.next() {
+                Some(rest) => rest.rsplitn(2, '.'),
+                None => continue,
+            };
+            let (name, key) = match (parts.next(), parts.next()) {
+                (Some(name), Some(key)) => (name, key),
+                _ => continue,
+            };
+            let breakdown = map.entry(key.to_owned()).or_insert_with(BreakdownStats::default);
+            for (n, counter) in breakdown.counters_mut() {
+                if n == name {
+                    *counter = value;
+                }
+            }
+            for (n, duration) in breakdown.durations_mut() {
+                if n == name {
+                    *duration = nanos_duration(value);
+                }
+            }
+        }
+        Ok(stats)
+    }
+
+    /// Render these stats, and the size and location of `storage`, in the
+    /// Prometheus text format.
+    ///
+    /// Every sample is labelled with the cache backend, and the outcomes of
+    /// compile requests with the kind of compiler as well.
+    fn to_metrics(&self, storage: &Storage) -> String {
+        let mut metrics = Exposition::new(vec!(("backend", storage.backend().to_owned())));
+        for &(name, value, help) in [
+            ("sccache_compile_requests_total", self.compile_requests, "Compile requests."),
+            ("sccache_requests_unsupported_compiler_total", self.requests_unsupported_compiler, "Compile requests using an unsupported compiler."),
+            ("sccache_requests_not_compile_total", self.requests_not_compile, "Compile requests that weren't compilations."),
+            ("sccache_requests_not_cacheable_total", self.requests_not_cacheable, "Compile requests that weren't cacheable."),
+            ("sccache_requests_executed_total", self.requests_executed, "Compile requests executed."),
+            ("sccache_non_cacheable_compilations_total", self.non_cacheable_compilations, "Successful compilations which could not be cached."),
+            ("sccache_forced_recaches_total", self.forced_recaches, "Compilations which forcibly ignored the cache."),
+            ("sccache_cache_writes_total", self.cache_writes, "Cache writes."),
+            ("sccache_cache_write_errors_total", self.cache_write_errors, "Cache write errors."),
+            ("sccache_cache_writes_skipped_total", self.cache_writes_skipped, "Cache writes skipped because the cache is read-only."),
+            ("sccache_cache_write_bytes_total", self.cache_write_bytes, "Size of the cache entries written, before compression."),
+            ("sccache_cache_write_stored_bytes_total", self.cache_write_stored_bytes, "Size of the cache entries written, as stored."),
+            ("sccache_coalesced_compiles_total", self.coalesced_compiles, "Compilations that waited for an identical one."),
+        ].iter() {
+            metrics.single(name, MetricType::Counter, help, value as f64);
+        }
+
+        let compilers = self.breakdowns().into_iter()
+            .filter(|&(kind, _, _)| kind == "compiler")
+            .collect::<Vec<_>>();
+        macro_rules! compiler_counter {
+            ($name:expr, $help:expr, $field:ident) => {{
+                metrics.metric($name, MetricType::Counter, $help);
+                for &(_, compiler, stats) in &compilers {
+                    metrics.sample($name, &[("compiler", compiler)], stats.$field as f64);
                 }
-                $vec.push(stat);
             }};
         }
+        compiler_counter!("sccache_cache_hits_total", "Cache hits.", cache_hits);
+        compiler_counter!("sccache_cache_misses_total", "Cache misses.", cache_misses);
+        compiler_counter!("sccache_cache_errors_total", "Errors handling compile requests.", cache_errors);
+        compiler_counter!("sccache_compile_fails_total", "Compilation failures.", compile_fails);
+
+        for &(name, duration, help) in [
+            ("sccache_cache_write_seconds_total", self.cache_write_duration, "Time spent writing cache entries."),
+            ("sccache_cache_read_hit_seconds_total", self.cache_read_hit_duration, "Time spent reading cache hits."),
+            ("sccache_cache_read_miss_seconds_total", self.cache_read_miss_duration, "Time spent looking up cache misses."),
+        ].iter() {
+            metrics.single(name, MetricType::Counter, help, duration_nanos(duration) as f64 / 1e9);
+        }
 
+        if let Some(size) = storage.current_size() {
+            metrics.single("sccache_cache_size_bytes", MetricType::Gauge, "Size of the cache.", size as f64);
+        }
+        if let Some(size) = storage.max_size() {
+            metrics.single("sccache_cache_max_size_bytes", MetricType::Gauge, "Maximum size of the cache.", size as f64);
+        }
+        metrics.metric("sccache_cache_info", MetricType::Gauge, "Where the cache is, in the location label.");
+        let location = storage.location();
+        metrics.sample("sccache_cache_info", &[("location", &location[..])], 1.0);
+        metrics.into_string()
+    }
+
+    fn breakdowns_to_cache_statistics(&self) -> Vec<CacheStatsBreakdown> {
+        self.breakdowns().into_iter().map(|(kind, value, stats)| {
+            let mut breakdown = CacheStatsBreakdown::new();
+            breakdown.set_kind(kind.to_owned());
+            breakdown.set_value(value.to_owned());
+            breakdown.set_stats(RepeatedField::from_vec(stats.to_cache_statistics()));
+            breakdown
+        }).collect()
+    }
+
+    fn to_cache_statistics(&self) -> Vec<CacheStatistic> {
         let mut stats_vec = vec!();
         set_stat!(stats_vec, self.compile_requests, "Compile requests");
         set_stat!(stats_vec, self.requests_executed, "Compile requests executed");
         set_stat!(stats_vec, self.cache_hits, "Cache hits");
         set_stat!(stats_vec, self.cache_misses, "Cache misses");
         set_stat!(stats_vec, self.forced_recaches, "Forced recaches");
+        set_stat!(stats_vec, self.coalesced_compiles, "Coalesced compilations");
         set_stat!(stats_vec, self.cache_write_errors, "Cache write errors");
+        set_stat!(stats_vec, self.cache_writes_skipped, "Cache writes skipped");
+        let mut stat = CacheStatistic::new();
+        stat.set_name(String::from("Cache write compression ratio"));
+        if self.cache_write_stored_bytes > 0 {
+            stat.set_str(format!("{:.2}", self.cache_write_bytes as f64 / self.cache_write_stored_bytes as f64));
+        } else {
+            stat.set_str(String::from("-"));
+        }
+        stats_vec.push(stat);
         set_stat!(stats_vec, self.compile_fails, "Compilation failures");
         set_stat!(stats_vec, self.cache_errors, "Cache errors");
         set_stat!(stats_vec, self.non_cacheable_compilations, "Successful compilations which could not be cached");
@@ -982,3 +1812,58 @@ impl Future for WaitUntilZero {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use tempdir::TempDir;
+
+    #[test]
+    fn test_stats_save_load() {
+        let td = TempDir::new("sccache").unwrap();
+        let path = td.path().join("stats");
+        let mut stats = ServerStats::default();
+        stats.compile_requests = 10;
+        stats.cache_hits = 7;
+        stats.cache_write_duration = Duration::new(3, 500);
+        stats.cache_write_bytes = 3000;
+        stats.cache_write_stored_bytes = 1000;
+        stats.record("gcc", "cpp", |s| {
+            s.cache_hits += 2;
+            s.cache_read_hit_duration += Duration::new(1, 0);
+        });
+        stats.save(&path).unwrap();
+        let loaded = ServerStats::load(&path).unwrap();
+        assert_eq!(10, loaded.compile_requests);
+        assert_eq!(7, loaded.cache_hits);
+        assert_eq!(0, loaded.cache_misses);
+        assert_eq!(Duration::new(3, 500), loaded.cache_write_duration);
+        assert_eq!(3000, loaded.cache_write_bytes);
+        assert_eq!(1000, loaded.cache_write_stored_bytes);
+        for breakdown in &[&loaded.compilers["gcc"], &loaded.extensions["cpp"]] {
+            assert_eq!(2, breakdown.cache_hits);
+            assert_eq!(Duration::new(1, 0), breakdown.cache_read_hit_duration);
+        }
+    }
+
+    #[test]
+    fn test_stats_to_metrics() {
+        use cache::disk::DiskCache;
+
+        let td = TempDir::new("sccache").unwrap();
+        let pool = CpuPool::new(1);
+        let storage = DiskCache::new(&td.path(), 1024, &pool);
+        let mut stats = ServerStats::default();
+        stats.compile_requests = 3;
+        stats.cache_hits = 2;
+        stats.record("gcc", "c", |s| s.cache_hits = 2);
+        stats.record("clang", "c", |s| s.cache_misses = 1);
+        let metrics = stats.to_metrics(&storage);
+        let lines = metrics.lines().collect::<Vec<_>>();
+        assert!(lines.contains(&"# TYPE sccache_compile_requests_total counter"));
+        assert!(lines.contains(&"sccache_compile_requests_total{backend=\"disk\"} 3"));
+        assert!(lines.contains(&"sccache_cache_hits_total{backend=\"disk\",compiler=\"gcc\"} 2"));
+        assert!(lines.contains(&"sccache_cache_hits_total{backend=\"disk\",compiler=\"clang\"} 0"));
+        assert!(lines.contains(&"sccache_cache_max_size_bytes{backend=\"disk\"} 1024"));
+    }
+}
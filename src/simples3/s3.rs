@@ -10,7 +10,7 @@ use crypto::mac::Mac;
 use crypto::sha1::Sha1;
 use futures::{Future, Stream};
 use hyper::{self, header};
-use hyper::Method;
+use hyper::{Body, Method};
 use hyper::client::{Client, Request};
 use hyper_tls::HttpsConnector;
 use rustc_serialize::base64::{ToBase64, STANDARD};
@@ -49,6 +49,13 @@ fn signature(string_to_sign: &str, signing_key: &str) -> String {
     hmac(Sha1::new(), signing_key.as_bytes(), string_to_sign.as_bytes()).to_base64(STANDARD)
 }
 
+/// Create a hyper client for talking to both HTTP and HTTPS servers.
+pub fn https_client(handle: &Handle) -> Client<HttpsConnector> {
+    Client::configure()
+        .connector(HttpsConnector::new(1, handle))
+        .build(handle)
+}
+
 /// An S3 bucket.
 pub struct Bucket {
     name: String,
@@ -68,9 +75,7 @@ impl Bucket {
         Bucket {
             name: name.to_owned(),
             base_url: base_url,
-            client: Client::configure()
-                        .connector(HttpsConnector::new(1, handle))
-                        .build(handle),
+            client: https_client(handle),
         }
     }
 
@@ -95,7 +100,8 @@ impl Bucket {
         }))
     }
 
-    pub fn put(&self, key: &str, content: Vec<u8>, creds: &AwsCredentials)
+    /// Upload `content`, which is `content_length` bytes long, to `key`.
+    pub fn put(&self, key: &str, content: Body, content_length: u64, creds: &AwsCredentials)
                -> SFuture<()> {
         let url = format!("{}{}", self.base_url, key);
         debug!("PUT {}", url);
@@ -119,7 +125,7 @@ impl Bucket {
         let auth = self.auth("PUT", &date, key, "", &canonical_headers, content_type, creds);
         request.headers_mut().set_raw("Date", vec!(date.into_bytes()));
         request.headers_mut().set(header::ContentType(content_type.parse().unwrap()));
-        request.headers_mut().set(header::ContentLength(content.len() as u64));
+        request.headers_mut().set(header::ContentLength(content_length));
         request.headers_mut().set(header::CacheControl(vec![
             // Two weeks
             header::CacheDirective::MaxAge(1296000)
@@ -14,7 +14,10 @@
 
 #![allow(dead_code, unused_imports)]
 
-use client::connect_with_retry;
+use client::{
+    connect_with_retry,
+    ServerAddress,
+};
 use commands::{
     DEFAULT_PORT,
     request_stats,
@@ -136,7 +139,7 @@ fn run_sccache_command_test<T: AsRef<OsStr>>(sccache: &Path, compiler: &str, exe
     assert_eq!(true, run(sccache, &compile_cmdline(compiler, exe.as_ref(), &input, &output), tempdir));
     assert_eq!(true, fs::metadata(&out_file).and_then(|m| Ok(m.len() > 0)).unwrap());
     trace!("connect");
-    let conn = connect_with_retry(DEFAULT_PORT).unwrap();
+    let conn = connect_with_retry(&ServerAddress::Tcp(DEFAULT_PORT)).unwrap();
     trace!("request stats");
     let stats = cache_stats_map(request_stats(conn).unwrap());
     if log_enabled!(Trace) {
@@ -152,7 +155,7 @@ fn run_sccache_command_test<T: AsRef<OsStr>>(sccache: &Path, compiler: &str, exe
     assert_eq!(true, run(sccache, &compile_cmdline(compiler, exe.as_ref(), &input, &output), tempdir));
     assert_eq!(true, fs::metadata(&out_file).and_then(|m| Ok(m.len() > 0)).unwrap());
     trace!("connect");
-    let conn = connect_with_retry(DEFAULT_PORT).unwrap();
+    let conn = connect_with_retry(&ServerAddress::Tcp(DEFAULT_PORT)).unwrap();
     trace!("request stats");
     let stats = cache_stats_map(request_stats(conn).unwrap());
     if log_enabled!(Trace) {
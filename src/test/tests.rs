@@ -15,27 +15,40 @@
 use ::cache::disk::DiskCache;
 use ::client::{
     connect_to_server,
+    ServerAddress,
 };
 use ::commands::{
+    check_server_version,
     do_compile,
+    request_dump_trace,
     request_shutdown,
     request_stats,
+    stats_to_json,
 };
 use env_logger;
+use serde_json;
 use futures::sync::oneshot::{self, Sender};
 use futures_cpupool::CpuPool;
 use ::mock_command::*;
+use protobuf::RepeatedField;
+use ::protocol::{
+    ClientRequest,
+    Compile,
+};
 use ::server::{
     ServerMessage,
     SccacheServer,
+    hello,
 };
+use std::collections::HashMap;
 use std::fs::File;
 use std::io::{
     Cursor,
+    Read,
     Write,
 };
 use std::net::TcpListener;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::sync::{Arc,Mutex,mpsc};
 use std::thread;
@@ -51,6 +64,8 @@ struct ServerOptions {
     idle_timeout: Option<u64>,
     /// The maximum size of the disk cache.
     cache_size: Option<usize>,
+    /// Where to write the timeline of the build.
+    timeline: Option<PathBuf>,
 }
 
 /// Run a server on a background thread, and return a tuple of useful things.
@@ -80,13 +95,14 @@ fn run_server_thread<T>(cache_dir: &Path, options: T)
     let (shutdown_tx, shutdown_rx) = oneshot::channel();
     let handle = thread::spawn(move || {
         let core = Core::new().unwrap();
-        let srv = SccacheServer::new(0, pool, core, storage).unwrap();
+        let srv = SccacheServer::new(0, pool, core, storage, None).unwrap();
         let mut srv: SccacheServer<Arc<Mutex<MockCommandCreator>>> = srv;
         assert!(srv.port() > 0);
         if let Some(options) = options {
             if let Some(timeout) = options.idle_timeout {
                  srv.set_idle_timeout(Duration::from_millis(timeout));
             }
+            srv.set_timeline(options.timeline);
         }
         let port = srv.port();
         let creator = srv.command_creator().clone();
@@ -102,7 +118,7 @@ fn test_server_shutdown() {
     let f = TestFixture::new();
     let (port, _sender, _storage, child) = run_server_thread(&f.tempdir.path(), None);
     // Connect to the server.
-    let conn = connect_to_server(port).unwrap();
+    let conn = connect_to_server(&ServerAddress::Tcp(port)).unwrap();
     // Ask it to shut down
     request_shutdown(conn).unwrap();
     // Ensure that it shuts down.
@@ -126,7 +142,7 @@ fn test_server_stats() {
     let f = TestFixture::new();
     let (port, sender, _storage, child) = run_server_thread(&f.tempdir.path(), None);
     // Connect to the server.
-    let conn = connect_to_server(port).unwrap();
+    let conn = connect_to_server(&ServerAddress::Tcp(port)).unwrap();
     // Ask it for stats.
     let stats = cache_stats_map(request_stats(conn).unwrap());
     assert_eq!(&CacheStat::Count(0), stats.get("Compile requests").unwrap());
@@ -136,12 +152,130 @@ fn test_server_stats() {
     child.join().unwrap();
 }
 
+#[test]
+fn test_server_stats_json() {
+    let f = TestFixture::new();
+    let (port, sender, _storage, child) = run_server_thread(&f.tempdir.path(), None);
+    let conn = connect_to_server(&ServerAddress::Tcp(port)).unwrap();
+    let stats = request_stats(conn).unwrap();
+    let json = stats_to_json(&stats);
+    let stats = json.as_array().unwrap();
+    let stat = |name: &str| {
+        stats.iter()
+            .map(|s| s.as_object().unwrap())
+            .find(|s| s.get("name").and_then(|n| n.as_str()) == Some(name))
+            .unwrap()
+    };
+    // Each statistic has its value under its type.
+    assert_eq!(Some(0), stat("Compile requests").get("count").and_then(|v| v.as_u64()));
+    assert!(stat("Cache location").get("str").and_then(|v| v.as_str()).is_some());
+    assert_eq!(Some(0.0), stat("Average cache write").get("duration").and_then(|v| v.as_f64()));
+    sender.send(ServerMessage::Shutdown).ok().unwrap();
+    child.join().unwrap();
+}
+
+#[test]
+fn test_server_dump_trace() {
+    let f = TestFixture::new();
+    let path = f.tempdir.path().join("trace.json");
+    let options = ServerOptions {
+        timeline: Some(path.clone()),
+        ..Default::default()
+    };
+    let (port, sender, _storage, child) = run_server_thread(&f.tempdir.path(), options);
+    let conn = connect_to_server(&ServerAddress::Tcp(port)).unwrap();
+    let dumped = request_dump_trace(conn).unwrap();
+    assert_eq!(path.to_str().unwrap(), dumped.get_path());
+    let mut trace = String::new();
+    File::open(&path).unwrap().read_to_string(&mut trace).unwrap();
+    let trace: serde_json::Value = serde_json::from_str(&trace).unwrap();
+    assert!(trace.find("traceEvents").and_then(|e| e.as_array()).is_some());
+    sender.send(ServerMessage::Shutdown).ok().unwrap();
+    child.join().unwrap();
+
+    // A server that isn't recording a timeline doesn't write one.
+    let (port, sender, _storage, child) = run_server_thread(&f.tempdir.path(), None);
+    let conn = connect_to_server(&ServerAddress::Tcp(port)).unwrap();
+    let dumped = request_dump_trace(conn).unwrap();
+    assert!(!dumped.has_path());
+    sender.send(ServerMessage::Shutdown).ok().unwrap();
+    child.join().unwrap();
+}
+
+#[test]
+fn test_server_version() {
+    let f = TestFixture::new();
+    let (port, sender, _storage, child) = run_server_thread(&f.tempdir.path(), None);
+    let mut conn = connect_to_server(&ServerAddress::Tcp(port)).unwrap();
+    // The server is running this very version.
+    assert!(check_server_version(&mut conn).unwrap());
+    // The connection can still be used afterwards.
+    let stats = cache_stats_map(request_stats(conn).unwrap());
+    assert_eq!(&CacheStat::Count(0), stats.get("Compile requests").unwrap());
+    sender.send(ServerMessage::Shutdown).ok().unwrap();
+    child.join().unwrap();
+}
+
+#[test]
+fn test_server_version_in_compile() {
+    let f = TestFixture::new();
+    let (port, sender, _creator, child) = run_server_thread(&f.tempdir.path(), None);
+    let mut conn = connect_to_server(&ServerAddress::Tcp(port)).unwrap();
+    // A compile from a client of a different version gets the server's
+    // version back instead of running.
+    let mut client = hello();
+    client.set_cache_version("0".to_owned());
+    let mut compile = Compile::new();
+    compile.set_exe(f.bins[0].to_str().unwrap().to_owned());
+    compile.set_cwd(f.tempdir.path().to_str().unwrap().to_owned());
+    compile.set_command(RepeatedField::from_vec(vec!("-c".to_owned(), "file.c".to_owned())));
+    compile.set_hello(client);
+    let mut req = ClientRequest::new();
+    req.set_compile(compile);
+    let response = conn.request(req).unwrap();
+    assert_eq!(&hello(), response.get_hello());
+    sender.send(ServerMessage::Shutdown).ok().unwrap();
+    child.join().unwrap();
+}
+
+#[cfg(unix)]
+#[test]
+fn test_server_unix_socket() {
+    use std::fs;
+    use std::os::unix::fs::PermissionsExt;
+
+    let f = TestFixture::new();
+    let socket = f.tempdir.path().join("server.sock");
+    let pool = CpuPool::new(1);
+    let storage = Arc::new(DiskCache::new(&f.tempdir.path().join("cache"), usize::MAX, &pool));
+    let (tx, rx) = mpsc::channel();
+    let (shutdown_tx, shutdown_rx) = oneshot::channel();
+    let server_socket = socket.clone();
+    let child = thread::spawn(move || {
+        let core = Core::new().unwrap();
+        let srv = SccacheServer::new_unix(&server_socket, pool, core, storage, None).unwrap();
+        let srv: SccacheServer<Arc<Mutex<MockCommandCreator>>> = srv;
+        tx.send(()).unwrap();
+        srv.run(shutdown_rx).unwrap();
+    });
+    rx.recv().unwrap();
+    // Only we can connect to the socket.
+    assert_eq!(0o600, fs::metadata(&socket).unwrap().permissions().mode() & 0o777);
+    let conn = connect_to_server(&ServerAddress::Unix(socket.clone())).unwrap();
+    let stats = cache_stats_map(request_stats(conn).unwrap());
+    assert_eq!(&CacheStat::Count(0), stats.get("Compile requests").unwrap());
+    shutdown_tx.send(ServerMessage::Shutdown).ok().unwrap();
+    child.join().unwrap();
+    // The socket is removed when the server shuts down.
+    assert!(!socket.exists());
+}
+
 #[test]
 fn test_server_unsupported_compiler() {
     let f = TestFixture::new();
     let (port, sender, server_creator, child) = run_server_thread(&f.tempdir.path(), None);
     // Connect to the server.
-    let conn = connect_to_server(port).unwrap();
+    let conn = connect_to_server(&ServerAddress::Tcp(port)).unwrap();
     {
         let mut c = server_creator.lock().unwrap();
         // The server will check the compiler, so pretend to be an unsupported
@@ -165,7 +299,7 @@ fn test_server_unsupported_compiler() {
     let mut stderr = Cursor::new(Vec::new());
     let path = Some(f.paths);
     let mut core = Core::new().unwrap();
-    assert_eq!(0, do_compile(client_creator.clone(), &mut core, conn, exe, cmdline, cwd, path, &mut stdout, &mut stderr).unwrap());
+    assert_eq!(0, do_compile(client_creator.clone(), &mut core, conn, exe, cmdline, cwd, path, None, &mut stdout, &mut stderr).unwrap());
     // Make sure we ran the mock processes.
     assert_eq!(0, server_creator.lock().unwrap().children.len());
     assert_eq!(0, client_creator.lock().unwrap().children.len());
@@ -190,7 +324,7 @@ fn test_server_compile() {
     const PREPROCESSOR_STDERR : &'static [u8] = b"preprocessor stderr";
     const STDOUT : &'static [u8] = b"some stdout";
     const STDERR : &'static [u8] = b"some stderr";
-    let conn = connect_to_server(port).unwrap();
+    let conn = connect_to_server(&ServerAddress::Tcp(port)).unwrap();
     {
         let mut c = server_creator.lock().unwrap();
         // The server will check the compiler. Pretend it's GCC.
@@ -221,11 +355,26 @@ fn test_server_compile() {
     let mut stderr = Cursor::new(Vec::new());
     let path = Some(f.paths);
     let mut core = Core::new().unwrap();
-    assert_eq!(0, do_compile(client_creator.clone(), &mut core, conn, exe, cmdline, cwd, path, &mut stdout, &mut stderr).unwrap());
+    assert_eq!(0, do_compile(client_creator.clone(), &mut core, conn, exe, cmdline, cwd, path, None, &mut stdout, &mut stderr).unwrap());
     // Make sure we ran the mock processes.
     assert_eq!(0, server_creator.lock().unwrap().children.len());
     assert_eq!(STDOUT, stdout.into_inner().as_slice());
     assert_eq!(STDERR, stderr.into_inner().as_slice());
+    // The miss is broken down by compiler and by extension.
+    let stats = request_stats(connect_to_server(&ServerAddress::Tcp(port)).unwrap()).unwrap();
+    let breakdowns = stats.get_breakdowns().iter()
+        .map(|b| ((b.get_kind().to_owned(), b.get_value().to_owned()), breakdown_stats_map(b.clone())))
+        .collect::<HashMap<_, _>>();
+    for key in &[("compiler", "gcc"), ("extension", "c")] {
+        let stats = &breakdowns[&(key.0.to_owned(), key.1.to_owned())];
+        assert_eq!(&CacheStat::Count(1), stats.get("Cache misses").unwrap());
+        assert_eq!(&CacheStat::Count(0), stats.get("Cache hits").unwrap());
+    }
+    // The preprocessor and compiler runs were timed.
+    let stats = cache_stats_map(stats);
+    assert_eq!(&CacheStat::Histogram(1), stats.get("Preprocessor run latency").unwrap());
+    assert_eq!(&CacheStat::Histogram(1), stats.get("Compiler run latency").unwrap());
+    assert_eq!(&CacheStat::Histogram(1), stats.get("Cache read miss latency").unwrap());
     // Shut down the server.
     sender.send(ServerMessage::Shutdown).ok().unwrap();
     // Ensure that it shuts down.
@@ -15,7 +15,8 @@
 #[cfg(unix)]
 use libc;
 use mock_command::*;
-use protocol::CacheStats;
+use protobuf::RepeatedField;
+use protocol::{CacheStatistic, CacheStats, CacheStatsBreakdown};
 use std::collections::HashMap;
 use std::env;
 use std::ffi::OsString;
@@ -173,10 +174,20 @@ pub enum CacheStat {
     Count(u64),
     String(String),
     Size(u64),
+    Duration(u64),
+    Histogram(u64),
 }
 
 pub fn cache_stats_map(mut stats: CacheStats) -> HashMap<String, CacheStat> {
-    stats.take_stats().into_iter().map(|mut s| (s.take_name(), if s.has_count() { CacheStat::Count(s.get_count()) } else if s.has_size() { CacheStat::Size(s.get_size()) } else { CacheStat::String(s.take_str()) })).collect()
+    cache_statistics_map(stats.take_stats())
+}
+
+pub fn breakdown_stats_map(mut breakdown: CacheStatsBreakdown) -> HashMap<String, CacheStat> {
+    cache_statistics_map(breakdown.take_stats())
+}
+
+fn cache_statistics_map(stats: RepeatedField<CacheStatistic>) -> HashMap<String, CacheStat> {
+    stats.into_iter().map(|mut s| (s.take_name(), if s.has_count() { CacheStat::Count(s.get_count()) } else if s.has_size() { CacheStat::Size(s.get_size()) } else if s.has_duration() { CacheStat::Duration(s.get_duration()) } else if s.has_histogram() { CacheStat::Histogram(s.get_histogram().get_count()) } else { CacheStat::String(s.take_str()) })).collect()
 }
 
 pub fn empty_output() -> process::Output {
@@ -0,0 +1,217 @@
+// Copyright 2017 Mozilla Foundation
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A log of the compile requests the server handles, one JSON object per
+//! line, for analyzing builds offline.
+//!
+//! When the log grows past its maximum size, it is renamed with a `.1`
+//! suffix, older logs are shifted along to `.2` and so on, and a new log
+//! is started.
+
+use chrono::{DateTime, UTC};
+use serde_json::{self, Map, Value};
+use std::cell::RefCell;
+use std::ffi::OsString;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::time::Duration;
+
+/// The number of old logs to keep around.
+const ROTATED_LOGS: u32 = 3;
+
+/// A compile request, as it's written to the log.
+pub struct TraceRecord {
+    /// When the request arrived.
+    pub timestamp: DateTime<UTC>,
+    pub cwd: String,
+    /// The path of the compiler.
+    pub compiler: String,
+    pub output_file: String,
+    /// The hash key, if the request got as far as computing one.
+    pub key: Option<String>,
+    /// The `CompileResult` variant.
+    pub result: &'static str,
+    /// The `MissType`, for cache misses.
+    pub miss_type: Option<&'static str>,
+    /// How long it took to respond to the request.
+    pub duration: Duration,
+    /// How long the cache lookup took.
+    pub cache_read_duration: Option<Duration>,
+    /// How long writing the result to the cache took.
+    pub cache_write_duration: Option<Duration>,
+}
+
+impl TraceRecord {
+    fn to_json(&self) -> Value {
+        fn secs(d: Duration) -> Value {
+            Value::F64(d.as_secs() as f64 + d.subsec_nanos() as f64 / 1e9)
+        }
+        fn opt<T, F: Fn(T) -> Value>(v: Option<T>, f: F) -> Value {
+            v.map_or(Value::Null, f)
+        }
+        let mut object = Map::new();
+        object.insert("timestamp".to_owned(), Value::String(self.timestamp.to_rfc3339()));
+        object.insert("cwd".to_owned(), Value::String(self.cwd.clone()));
+        object.insert("compiler".to_owned(), Value::String(self.compiler.clone()));
+        object.insert("output_file".to_owned(), Value::String(self.output_file.clone()));
+        object.insert("key".to_owned(), opt(self.key.clone(), Value::String));
+        object.insert("result".to_owned(), Value::String(self.result.to_owned()));
+        object.insert("miss_type".to_owned(), opt(self.miss_type, |m| Value::String(m.to_owned())));
+        object.insert("duration".to_owned(), secs(self.duration));
+        object.insert("cache_read_duration".to_owned(), opt(self.cache_read_duration, secs));
+        object.insert("cache_write_duration".to_owned(), opt(self.cache_write_duration, secs));
+        Value::Object(object)
+    }
+}
+
+struct LogFile {
+    path: PathBuf,
+    file: File,
+    /// The size of `file` so far.
+    size: u64,
+    /// The size past which the log is rotated.
+    max_size: u64,
+}
+
+impl LogFile {
+    /// Start a new log in place of the current one, shifting the old logs
+    /// along.
+    fn rotate(&mut self) -> io::Result<()> {
+        for i in (1..ROTATED_LOGS).rev() {
+            match fs::rename(rotated_path(&self.path, i), rotated_path(&self.path, i + 1)) {
+                Err(ref e) if e.kind() == io::ErrorKind::NotFound => {}
+                res => res?,
+            }
+        }
+        fs::rename(&self.path, rotated_path(&self.path, 1))?;
+        self.file = open(&self.path)?;
+        self.size = 0;
+        Ok(())
+    }
+}
+
+/// The path of the `n`th most recent old log.
+fn rotated_path(path: &Path, n: u32) -> PathBuf {
+    let mut name = OsString::from(path.as_os_str());
+    name.push(format!(".{}", n));
+    PathBuf::from(name)
+}
+
+fn open(path: &Path) -> io::Result<File> {
+    OpenOptions::new().create(true).append(true).open(path)
+}
+
+/// A log of compile requests, shared between the tasks that write to it.
+#[derive(Clone)]
+pub struct TraceLog {
+    inner: Rc<RefCell<LogFile>>,
+}
+
+impl TraceLog {
+    /// Append to the log at `path`, rotating it once it grows past
+    /// `max_size` bytes.
+    pub fn open(path: &Path, max_size: u64) -> io::Result<TraceLog> {
+        let file = open(path)?;
+        let size = file.metadata()?.len();
+        Ok(TraceLog {
+            inner: Rc::new(RefCell::new(LogFile {
+                path: path.to_owned(),
+                file: file,
+                size: size,
+                max_size: max_size,
+            })),
+        })
+    }
+
+    /// Write `record` to the log.
+    pub fn write(&self, record: &TraceRecord) -> io::Result<()> {
+        let mut line = serde_json::to_string(&record.to_json())
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+        line.push('\n');
+        let mut log = self.inner.borrow_mut();
+        if log.size > 0 && log.size + line.len() as u64 > log.max_size {
+            log.rotate()?;
+        }
+        log.file.write_all(line.as_bytes())?;
+        log.size += line.len() as u64;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io::Read;
+    use tempdir::TempDir;
+
+    fn record(output_file: &str) -> TraceRecord {
+        TraceRecord {
+            timestamp: UTC::now(),
+            cwd: "/build".to_owned(),
+            compiler: "/usr/bin/cc".to_owned(),
+            output_file: output_file.to_owned(),
+            key: Some("abcd".to_owned()),
+            result: "CacheMiss",
+            miss_type: Some("Normal"),
+            duration: Duration::from_millis(1500),
+            cache_read_duration: Some(Duration::from_millis(10)),
+            cache_write_duration: None,
+        }
+    }
+
+    fn read_lines(path: &Path) -> Vec<Value> {
+        let mut contents = String::new();
+        File::open(path).unwrap().read_to_string(&mut contents).unwrap();
+        contents.lines().map(|l| serde_json::from_str(l).unwrap()).collect()
+    }
+
+    #[test]
+    fn test_trace_log() {
+        let td = TempDir::new("sccache").unwrap();
+        let path = td.path().join("trace.log");
+        let log = TraceLog::open(&path, 1024).unwrap();
+        log.write(&record("foo.o")).unwrap();
+        let lines = read_lines(&path);
+        assert_eq!(1, lines.len());
+        let line = &lines[0];
+        assert_eq!(Some("foo.o"), line.find("output_file").and_then(|v| v.as_str()));
+        assert_eq!(Some("CacheMiss"), line.find("result").and_then(|v| v.as_str()));
+        assert_eq!(Some(1.5), line.find("duration").and_then(|v| v.as_f64()));
+        assert_eq!(Some(&Value::Null), line.find("cache_write_duration"));
+    }
+
+    #[test]
+    fn test_trace_log_rotation() {
+        let td = TempDir::new("sccache").unwrap();
+        let path = td.path().join("trace.log");
+        let line_len = serde_json::to_string(&record("0.o").to_json()).unwrap().len() as u64 + 1;
+        // Two records fit in each log.
+        let log = TraceLog::open(&path, line_len * 2).unwrap();
+        for i in 0..9 {
+            log.write(&record(&format!("{}.o", i))).unwrap();
+        }
+        let outputs = |path: &Path| {
+            read_lines(path).iter()
+                .map(|l| l.find("output_file").and_then(|v| v.as_str()).unwrap().to_owned())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(vec!("8.o"), outputs(&path));
+        assert_eq!(vec!("6.o", "7.o"), outputs(&rotated_path(&path, 1)));
+        assert_eq!(vec!("4.o", "5.o"), outputs(&rotated_path(&path, 2)));
+        assert_eq!(vec!("2.o", "3.o"), outputs(&rotated_path(&path, 3)));
+        assert!(!rotated_path(&path, 4).exists());
+    }
+}